@@ -0,0 +1,114 @@
+use std::{fs, io::Read, path::Path};
+
+use super::{sha256_hex, verify_checksum, ArtifactError};
+
+/// Name of the directory under [`std::env::temp_dir`] where downloaded artifacts are cached.
+const CACHE_DIR: &str = "cw-it-artifacts";
+
+/// Downloads the wasm file at `url`, verifying it against the expected `sha256` digest if given.
+///
+/// Downloaded files are cached in a temp dir keyed by the expected hash, or by the hash of the
+/// url if no checksum is given, so that repeated test runs don't download the same file again.
+pub fn download_wasm(url: &str, sha256: Option<&str>) -> Result<Vec<u8>, ArtifactError> {
+    download_wasm_cached(url, sha256, &std::env::temp_dir().join(CACHE_DIR))
+}
+
+fn download_wasm_cached(url: &str, sha256: Option<&str>, cache_dir: &Path) -> Result<Vec<u8>, ArtifactError> {
+    let cache_key = match sha256 {
+        Some(expected) => expected.to_lowercase(),
+        None => sha256_hex(url.as_bytes()),
+    };
+    let cache_path = cache_dir.join(format!("{}.wasm", cache_key));
+
+    // Use the cached file if it is present and still matches the expected checksum
+    if let Ok(bytes) = fs::read(&cache_path) {
+        if verify_checksum(&bytes, sha256).is_ok() {
+            return Ok(bytes);
+        }
+    }
+
+    let bytes = fetch(url)?;
+    verify_checksum(&bytes, sha256)?;
+
+    fs::create_dir_all(cache_dir)?;
+    fs::write(&cache_path, &bytes)?;
+
+    Ok(bytes)
+}
+
+fn fetch(url: &str) -> Result<Vec<u8>, ArtifactError> {
+    if let Some(path) = url.strip_prefix("file://") {
+        return Ok(fs::read(path)?);
+    }
+
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| ArtifactError::DownloadError(format!("failed to download {}: {}", url, e)))?;
+    let mut bytes = vec![];
+    response.into_reader().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNTER_WASM: &str = "artifacts/counter.wasm";
+
+    fn file_url() -> String {
+        format!("file://{}", fs::canonicalize(COUNTER_WASM).unwrap().display())
+    }
+
+    fn temp_cache_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("cw-it-download-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn download_and_cache_with_checksum() {
+        let expected_bytes = fs::read(COUNTER_WASM).unwrap();
+        let checksum = sha256_hex(&expected_bytes);
+        let cache_dir = temp_cache_dir("checksum");
+
+        let bytes = download_wasm_cached(&file_url(), Some(&checksum), &cache_dir).unwrap();
+        assert_eq!(bytes, expected_bytes);
+
+        // The file should now be cached under its hash and served from the cache
+        let cache_path = cache_dir.join(format!("{}.wasm", checksum));
+        assert_eq!(fs::read(&cache_path).unwrap(), expected_bytes);
+        let bytes = download_wasm_cached("file:///does/not/exist.wasm", Some(&checksum), &cache_dir).unwrap();
+        assert_eq!(bytes, expected_bytes);
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn download_without_checksum() {
+        let cache_dir = temp_cache_dir("no-checksum");
+        let url = file_url();
+
+        let bytes = download_wasm_cached(&url, None, &cache_dir).unwrap();
+        assert_eq!(bytes, fs::read(COUNTER_WASM).unwrap());
+        assert!(cache_dir.join(format!("{}.wasm", sha256_hex(url.as_bytes()))).exists());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn download_checksum_mismatch() {
+        let cache_dir = temp_cache_dir("mismatch");
+        let wrong_checksum = sha256_hex(b"not the counter contract");
+
+        let err = download_wasm_cached(&file_url(), Some(&wrong_checksum), &cache_dir).unwrap_err();
+        match err {
+            ArtifactError::ChecksumMismatch { expected, actual } => {
+                assert_eq!(expected, wrong_checksum);
+                assert_eq!(actual, sha256_hex(&fs::read(COUNTER_WASM).unwrap()));
+            }
+            e => panic!("Unexpected error: {}", e),
+        }
+        // Nothing should be cached on a mismatch
+        assert!(!cache_dir.exists());
+    }
+}
@@ -5,6 +5,14 @@ use std::{
 };
 use thiserror::Error;
 
+#[cfg(feature = "gzip")]
+use {flate2::read::GzDecoder, std::io::Read};
+#[cfg(feature = "checksum")]
+use {
+    sha2::{Digest, Sha256},
+    std::{collections::HashMap, path::Path},
+};
+
 #[cfg(feature = "multi-test")]
 use {cw_multi_test::Contract, cosmwasm_std::Empty};
 
@@ -14,14 +22,22 @@ use self::on_chain::{download_wasm_from_code_id, download_wasm_from_contract_add
 #[cfg(feature = "chain-download")]
 mod on_chain;
 
+#[cfg(feature = "download")]
+mod download;
+
 /// Enum to represent the different ways to get a contract artifact, i.e a contract wasm file.
 #[cw_serde]
 pub enum Artifact {
     /// A path to a local wasm file.
     Local(String),
-    /// A url to download the wasm file from.
-    #[cfg(feature = "url-download")]
-    Url(String),
+    /// A path to a local wasm file together with the expected hex encoded sha256 digest of the file.
+    /// Loading the artifact fails if the file does not match the digest, e.g. for stale build artifacts.
+    #[cfg(feature = "checksum")]
+    LocalChecked { path: String, sha256: String },
+    /// A url to download the wasm file from, optionally together with the expected hex encoded
+    /// sha256 digest of the file. Downloaded files are cached in a temp dir.
+    #[cfg(feature = "download")]
+    Url { url: String, sha256: Option<String> },
     /// An RPC endpoint to download the artifact from, together with a code id.
     /// Downloads the wasm mapping to the code id from the chain.
     #[cfg(feature = "chain-download")]
@@ -94,6 +110,18 @@ pub enum ArtifactError {
     #[error("{0}")]
     Generic(String),
 
+    #[cfg(feature = "gzip")]
+    #[error("Invalid wasm: {0}")]
+    InvalidWasm(String),
+
+    #[cfg(feature = "checksum")]
+    #[error("Checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
+    #[cfg(feature = "download")]
+    #[error("Download error: {0}")]
+    DownloadError(String),
+
     #[cfg(feature = "chain-download")]
     #[error("Decode error: {0}")]
     DecodeError(String),
@@ -110,13 +138,113 @@ impl From<prost::DecodeError> for ArtifactError {
     }
 }
 
+/// Magic bytes at the start of a gzip file.
+#[cfg(feature = "gzip")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+/// Magic bytes at the start of a wasm file (`\0asm`).
+#[cfg(feature = "gzip")]
+const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+
+/// Decompresses `bytes` if they are gzip compressed, checking that the result is valid wasm.
+/// Uncompressed bytes are returned as is.
+#[cfg(feature = "gzip")]
+fn decompress_if_gzipped(bytes: Vec<u8>) -> Result<Vec<u8>, ArtifactError> {
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return Ok(bytes);
+    }
+
+    let mut wasm = vec![];
+    GzDecoder::new(bytes.as_slice())
+        .read_to_end(&mut wasm)
+        .map_err(|e| ArtifactError::InvalidWasm(format!("failed to decompress gzipped artifact: {}", e)))?;
+    if !wasm.starts_with(&WASM_MAGIC) {
+        return Err(ArtifactError::InvalidWasm(
+            "decompressed artifact does not start with the wasm magic bytes".to_string(),
+        ));
+    }
+    Ok(wasm)
+}
+
+/// Returns the lowercase hex encoded sha256 digest of `bytes`.
+#[cfg(feature = "checksum")]
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Verifies that the sha256 digest of `bytes` matches the expected hex encoded digest, if any.
+#[cfg(feature = "checksum")]
+pub(crate) fn verify_checksum(bytes: &[u8], sha256: Option<&str>) -> Result<(), ArtifactError> {
+    if let Some(expected) = sha256 {
+        let actual = sha256_hex(bytes);
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(ArtifactError::ChecksumMismatch {
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// A lockfile mapping contract names to the expected hex encoded sha256 digest of their wasm
+/// file. Used to make sure a test suite runs against the exact artifacts it was written for,
+/// rather than stale build output.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg(feature = "checksum")]
+pub struct ChecksumLockfile {
+    /// Map of **contract name -> sha256 digest**.
+    pub checksums: HashMap<String, String>,
+}
+
+#[cfg(feature = "checksum")]
+impl ChecksumLockfile {
+    /// Reads a lockfile from `path`. See [`ChecksumLockfile::parse`] for the format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ArtifactError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parses a lockfile in the `<sha256>  <name>.wasm` format of the `checksums.txt` written by
+    /// rust-optimizer, i.e. one contract per line. The `.wasm` extension is stripped from the
+    /// names and empty lines are ignored.
+    pub fn parse(contents: &str) -> Result<Self, ArtifactError> {
+        let checksums = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+                [sha256, file] => Ok((file.trim_end_matches(".wasm").to_string(), sha256.to_lowercase())),
+                _ => Err(ArtifactError::Generic(format!("Invalid lockfile line: {}", line))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { checksums })
+    }
+
+    /// Verifies that `wasm` matches the checksum of the contract `name`. Fails if the lockfile
+    /// has no checksum for the contract.
+    pub fn verify(&self, name: &str, wasm: &[u8]) -> Result<(), ArtifactError> {
+        let expected = self
+            .checksums
+            .get(name)
+            .ok_or_else(|| ArtifactError::Generic(format!("No checksum for contract {} in lockfile", name)))?;
+        verify_checksum(wasm, Some(expected))
+    }
+}
+
 impl Artifact {
-    /// Return the wasm byte code for the artifact.
+    /// Return the wasm byte code for the artifact. With the `gzip` feature, gzip compressed
+    /// artifacts are transparently decompressed.
     pub fn get_wasm_byte_code(&self) -> Result<Vec<u8>, ArtifactError> {
-        match self {
+        let bytes: Result<Vec<u8>, ArtifactError> = match self {
             Artifact::Local(path) => Ok(fs::read(path)?),
-            #[cfg(feature = "url-download")]
-            Artifact::Url(_url) => todo!(),
+            #[cfg(feature = "checksum")]
+            Artifact::LocalChecked { path, sha256 } => {
+                let bytes = fs::read(path)?;
+                verify_checksum(&bytes, Some(sha256))?;
+                Ok(bytes)
+            }
+            #[cfg(feature = "download")]
+            Artifact::Url { url, sha256 } => download::download_wasm(url, sha256.as_deref()),
             #[cfg(feature = "chain-download")]
             Artifact::ChainCodeId {
                 rpc_endpoint,
@@ -127,7 +255,11 @@ impl Artifact {
                 rpc_endpoint,
                 contract_address,
             } => download_wasm_from_contract_address(rpc_endpoint, contract_address),
-        }
+        };
+        let bytes = bytes?;
+        #[cfg(feature = "gzip")]
+        let bytes = decompress_if_gzipped(bytes)?;
+        Ok(bytes)
     }
 }
 
@@ -142,6 +274,96 @@ mod tests {
         assert_eq!(format!("{:?}", contract_type), "Artifact(Local(\"foo\"))");
     }
 
+    #[cfg(any(feature = "checksum", feature = "gzip"))]
+    const COUNTER_WASM: &str = "artifacts/counter.wasm";
+
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn local_checked_matching_checksum() {
+        let expected = fs::read(COUNTER_WASM).unwrap();
+        let artifact = Artifact::LocalChecked {
+            path: COUNTER_WASM.to_string(),
+            sha256: sha256_hex(&expected).to_uppercase(),
+        };
+        assert_eq!(artifact.get_wasm_byte_code().unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn local_checked_mismatching_checksum() {
+        let wrong = sha256_hex(b"stale artifact");
+        let artifact = Artifact::LocalChecked {
+            path: COUNTER_WASM.to_string(),
+            sha256: wrong.clone(),
+        };
+        match artifact.get_wasm_byte_code().unwrap_err() {
+            ArtifactError::ChecksumMismatch { expected, actual } => {
+                assert_eq!(expected, wrong);
+                assert_eq!(actual, sha256_hex(&fs::read(COUNTER_WASM).unwrap()));
+            }
+            e => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn lockfile_parse_and_verify() {
+        let wasm = fs::read(COUNTER_WASM).unwrap();
+        let checksum = sha256_hex(&wasm);
+        let lockfile = ChecksumLockfile::parse(&format!("{}  counter.wasm\n\n{}  other.wasm\n", checksum, sha256_hex(b"other"))).unwrap();
+
+        assert_eq!(lockfile.checksums.len(), 2);
+        assert_eq!(lockfile.checksums["counter"], checksum);
+        lockfile.verify("counter", &wasm).unwrap();
+        assert!(matches!(
+            lockfile.verify("other", &wasm),
+            Err(ArtifactError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(lockfile.verify("missing", &wasm), Err(ArtifactError::Generic(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn lockfile_invalid_line() {
+        let err = ChecksumLockfile::parse("not a valid line").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid lockfile line: not a valid line");
+    }
+
+    #[cfg(feature = "gzip")]
+    fn write_gzipped(name: &str, bytes: &[u8]) -> String {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("cw-it-{}-{}.wasm.gz", name, std::process::id()));
+        let mut encoder = GzEncoder::new(fs::File::create(&path).unwrap(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn gzipped_artifact_loads_identically() {
+        let raw = Artifact::Local(COUNTER_WASM.to_string()).get_wasm_byte_code().unwrap();
+        let path = write_gzipped("counter", &raw);
+
+        let gzipped = Artifact::Local(path.clone()).get_wasm_byte_code().unwrap();
+        assert_eq!(gzipped, raw);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn gzipped_non_wasm_artifact_errors() {
+        let path = write_gzipped("not-wasm", b"definitely not wasm");
+
+        let err = Artifact::Local(path.clone()).get_wasm_byte_code().unwrap_err();
+        assert!(matches!(err, ArtifactError::InvalidWasm(_)));
+
+        fs::remove_file(path).unwrap();
+    }
+
     #[cfg(feature = "multi-test")]
     mod multi_test {
         use cw_multi_test::ContractWrapper;
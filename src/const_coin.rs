@@ -13,6 +13,34 @@ impl ConstCoin {
             amount: Uint128::new(amount),
         }
     }
+
+    /// Returns a coin of the same denom with `amount` added, or `None` on overflow.
+    pub const fn checked_add(self, amount: u128) -> Option<Self> {
+        match self.amount.u128().checked_add(amount) {
+            Some(amount) => Some(Self::new(amount, self.denom)),
+            None => None,
+        }
+    }
+
+    /// Returns a coin of the same denom with `amount` subtracted, or `None` on underflow.
+    pub const fn checked_sub(self, amount: u128) -> Option<Self> {
+        match self.amount.u128().checked_sub(amount) {
+            Some(amount) => Some(Self::new(amount, self.denom)),
+            None => None,
+        }
+    }
+
+    /// Returns a coin of the same denom with the amount multiplied by `numerator / denominator`,
+    /// rounded down. Returns `None` if the multiplication overflows or `denominator` is zero.
+    pub const fn scaled(self, numerator: u128, denominator: u128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        match self.amount.u128().checked_mul(numerator) {
+            Some(amount) => Some(Self::new(amount / denominator, self.denom)),
+            None => None,
+        }
+    }
 }
 
 impl From<ConstCoin> for Coin {
@@ -30,11 +58,60 @@ impl From<&ConstCoin> for Coin {
     }
 }
 
+/// A fixed set of [`ConstCoin`]s of distinct denoms, e.g. an initial balance defined as a constant.
+/// The coins must be given in strictly ascending order of their denoms, which guarantees that the
+/// denoms are unique and the coins are in the order the SDK expects. This is checked on
+/// construction, so defining a constant with duplicate or unordered denoms fails to compile.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ConstCoins<const N: usize>([ConstCoin; N]);
+
+impl<const N: usize> ConstCoins<N> {
+    pub const fn new(coins: [ConstCoin; N]) -> Self {
+        let mut i = 1;
+        while i < N {
+            assert!(
+                denom_lt(coins[i - 1].denom, coins[i].denom),
+                "denoms of ConstCoins must be unique and in ascending order"
+            );
+            i += 1;
+        }
+        Self(coins)
+    }
+
+    pub const fn as_slice(&self) -> &[ConstCoin] {
+        &self.0
+    }
+
+    /// Returns the coins as [`Coin`]s, for example to pass them to `init_account`.
+    pub fn to_vec(&self) -> Vec<Coin> {
+        self.0.iter().map(Into::into).collect()
+    }
+}
+
+impl<const N: usize> From<ConstCoins<N>> for Vec<Coin> {
+    fn from(coins: ConstCoins<N>) -> Self {
+        coins.to_vec()
+    }
+}
+
+/// Returns true if `a` sorts strictly before `b`, comparing bytewise like [`str::cmp`].
+const fn denom_lt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{Coin, Uint128};
 
-    use crate::const_coin::ConstCoin;
+    use crate::const_coin::{ConstCoin, ConstCoins};
 
     #[test]
     fn new() {
@@ -43,6 +120,69 @@ mod tests {
         assert_eq!(coin.amount, Uint128::new(100_000_000_000_000_000u128));
     }
 
+    #[test]
+    fn arithmetic() {
+        const INITIAL: ConstCoin = ConstCoin::new(1_000, "uatom");
+        const HALF: Option<ConstCoin> = INITIAL.scaled(1, 2);
+
+        assert_eq!(HALF, Some(ConstCoin::new(500, "uatom")));
+        assert_eq!(INITIAL.checked_add(500), Some(ConstCoin::new(1_500, "uatom")));
+        assert_eq!(INITIAL.checked_sub(1_000), Some(ConstCoin::new(0, "uatom")));
+        assert_eq!(INITIAL.scaled(3, 2), Some(ConstCoin::new(1_500, "uatom")));
+        // Rounds down
+        assert_eq!(INITIAL.scaled(1, 3), Some(ConstCoin::new(333, "uatom")));
+    }
+
+    #[test]
+    fn arithmetic_overflow() {
+        let max = ConstCoin::new(u128::MAX, "uatom");
+
+        assert_eq!(max.checked_add(1), None);
+        assert_eq!(ConstCoin::new(1, "uatom").checked_sub(2), None);
+        assert_eq!(max.scaled(2, 2), None);
+        assert_eq!(max.scaled(1, 0), None);
+        assert_eq!(max.scaled(1, 1), Some(max));
+    }
+
+    #[test]
+    #[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+    fn const_coins_init_account() {
+        use crate::helpers::bank_all_balances_query;
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::{CwItRunner, DEFAULT_ADDRESS_PREFIX};
+        use test_tube::Account;
+
+        const INITIAL_BALANCE: ConstCoins<3> = ConstCoins::new([
+            ConstCoin::new(1_000, "uatom"),
+            ConstCoin::new(2_000, "uosmo"),
+            ConstCoin::new(3_000, "uusd"),
+        ]);
+
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let account = runner.init_account(&INITIAL_BALANCE.to_vec()).unwrap();
+
+        let balances = bank_all_balances_query(&runner, account.address(), None)
+            .unwrap()
+            .balances
+            .into_iter()
+            .map(|c| Coin::new(c.amount.parse().unwrap(), c.denom))
+            .collect::<Vec<_>>();
+        assert_eq!(balances, Vec::<Coin>::from(INITIAL_BALANCE));
+        assert_eq!(INITIAL_BALANCE.as_slice()[1], ConstCoin::new(2_000, "uosmo"));
+    }
+
+    #[test]
+    #[should_panic(expected = "denoms of ConstCoins must be unique and in ascending order")]
+    fn const_coins_duplicate_denom() {
+        ConstCoins::new([ConstCoin::new(1, "uatom"), ConstCoin::new(2, "uatom")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "denoms of ConstCoins must be unique and in ascending order")]
+    fn const_coins_unordered_denoms() {
+        ConstCoins::new([ConstCoin::new(1, "uosmo"), ConstCoin::new(2, "uatom")]);
+    }
+
     #[test]
     fn test_into_coin() {
         let const_coin = ConstCoin::new(100_000_000_000_000_000u128, "uatom");
@@ -1,14 +1,21 @@
 use anyhow::Error;
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
 use cosmrs::proto::tendermint::v0_37::abci::ResponseDeliverTx;
 use cosmrs::Any;
 use cosmwasm_std::{Coin, Timestamp};
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
 use prost::Message;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use test_tube::runner::result::{RunnerExecuteResult, RunnerResult};
 use test_tube::runner::Runner;
 use test_tube::BaseApp;
 use test_tube::{Module, SigningAccount, Wasm};
 
+use crate::helpers::{
+    execute_contract, fund_from_new_account, migrate_contract, query_account_info, query_code_hash, query_denom_creation_fee,
+    query_denom_metadata, query_is_contract,
+};
+use crate::traits::CwItResponse;
 use crate::{traits::CwItRunner, ContractType};
 
 #[cfg(feature = "multi-test")]
@@ -84,7 +91,7 @@ impl CoreumTestApp {
     }
 
     /// Simulate transaction execution and return gas info
-    pub fn simulate_tx<I>(&self, msgs: I, signer: &SigningAccount) -> RunnerResult<cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo>
+    pub fn simulate_tx<I>(&self, msgs: I, signer: &SigningAccount) -> RunnerResult<GasInfo>
     where
         I: IntoIterator<Item = cosmrs::Any>,
     {
@@ -160,20 +167,134 @@ impl CwItRunner<'_> for CoreumTestApp {
         Ok(())
     }
 
+    fn increase_blocks(&self, blocks: u64) -> Result<(), Error> {
+        // Every time increase finalizes a new block, so increasing by zero seconds yields an empty block
+        for _ in 0..blocks {
+            CoreumTestApp::increase_time(self, 0);
+        }
+        Ok(())
+    }
+
     fn query_block_time_nanos(&self) -> u64 {
         self.get_block_time_nanos() as u64
     }
+
+    fn query_block_height(&self) -> Result<u64, Error> {
+        Ok(self.get_block_height() as u64)
+    }
+
+    fn migrate_contract(&self, contract: &str, new_code_id: u64, msg: &[u8], signer: &SigningAccount) -> Result<CwItResponse, Error> {
+        Ok(migrate_contract(self, contract, new_code_id, msg, signer)?)
+    }
+
+    fn query_code_hash(&self, code_id: u64) -> Result<Vec<u8>, Error> {
+        Ok(query_code_hash(self, code_id)?)
+    }
+
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, Error> {
+        Ok(query_denom_creation_fee(self)?)
+    }
+
+    fn is_contract(&self, address: &str) -> Result<bool, Error> {
+        Ok(query_is_contract(self, address)?)
+    }
+
+    fn account_info(&self, address: &str) -> Result<(u64, u64), Error> {
+        Ok(query_account_info(self, address)?)
+    }
+
+    fn execute_contract<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, Error> {
+        Ok(execute_contract(self, contract, msg, funds, signer)?)
+    }
+
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        funds: &[Coin],
+        admin: Option<&str>,
+        label: &str,
+        signer: &SigningAccount,
+    ) -> Result<String, Error> {
+        let res = Wasm::new(self).instantiate(code_id, msg, admin, Some(label), funds, signer)?;
+        Ok(res.data.address)
+    }
+
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, contract: &str, query: &Q) -> Result<R, Error> {
+        Ok(Wasm::new(self).query(contract, query)?)
+    }
+
+    fn query_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, Error> {
+        Ok(query_denom_metadata(self, denom)?)
+    }
+
+    fn fund_account(&self, address: &str, coins: &[Coin]) -> Result<(), Error> {
+        fund_from_new_account(self, address, coins, Coin::new(1_000_000_000_000, "ucore"))
+    }
+
+    fn simulate(&self, msgs: Vec<cosmrs::Any>, signer: &SigningAccount) -> Result<GasInfo, Error> {
+        Ok(self.simulate_tx(msgs, signer)?)
+    }
+
+    fn fee_denom(&self) -> Result<&str, Error> {
+        Ok("ucore")
+    }
+
+    fn chain_id(&self) -> Result<String, Error> {
+        Ok(CHAIN_ID.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::artifact::Artifact;
     use cosmwasm_std::Coin;
+    use test_tube::Account;
 
     use super::*;
 
     const TEST_ARTIFACT: &str = "artifacts/counter.wasm";
 
+    #[derive(serde::Serialize)]
+    struct InstantiateMsg {
+        count: i32,
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Increment {},
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum QueryMsg {
+        GetCount {},
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct GetCountResponse {
+        count: i32,
+    }
+
+    /// Stores the counter artifact and instantiates it with a count of zero, returning the funded
+    /// admin of the contract and its address.
+    fn setup_counter(app: &CoreumTestApp) -> (SigningAccount, String) {
+        let admin = app.init_account(&[Coin::new(1000000000000, "ucore")]).unwrap();
+        let code_id = app
+            .store_code(ContractType::Artifact(Artifact::Local(TEST_ARTIFACT.to_string())), &admin)
+            .unwrap();
+        let contract: String = crate::helpers::instantiate_contract(app, &admin, code_id, &InstantiateMsg { count: 0 }).unwrap();
+
+        (admin, contract)
+    }
+
     #[test]
     fn coreum_test_app_store_code() {
         let app = CoreumTestApp::new();
@@ -201,6 +322,20 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_fee_denom() {
+        let app = CoreumTestApp::new();
+
+        assert_eq!(CwItRunner::fee_denom(&app).unwrap(), "ucore");
+    }
+
+    #[test]
+    fn test_chain_id() {
+        let app = CoreumTestApp::new();
+
+        assert_eq!(CwItRunner::chain_id(&app).unwrap(), "coreum-mainnet-1");
+    }
+
     #[test]
     fn test_increase_time() {
         let app = CoreumTestApp::new();
@@ -209,4 +344,139 @@ mod tests {
         CwItRunner::increase_time(&app, 69).unwrap();
         assert_eq!(app.get_block_time_nanos(), time + 69000000000);
     }
+
+    #[test]
+    fn test_set_block_time() {
+        let app = CoreumTestApp::new();
+
+        let target = app.query_block_time_nanos() / 1_000_000_000 + 3600;
+        CwItRunner::set_block_time(&app, target).unwrap();
+        assert_eq!(app.query_block_time_nanos() / 1_000_000_000, target);
+
+        CwItRunner::set_block_time(&app, target - 1).unwrap_err();
+    }
+
+    #[test]
+    fn test_increase_blocks() {
+        let app = CoreumTestApp::new();
+
+        let height = app.query_block_height().unwrap();
+        CwItRunner::increase_blocks(&app, 100).unwrap();
+        assert_eq!(app.query_block_height().unwrap(), height + 100);
+    }
+
+    #[test]
+    fn test_is_contract() {
+        let app = CoreumTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        assert!(CwItRunner::is_contract(&app, &contract).unwrap());
+        assert!(!CwItRunner::is_contract(&app, &admin.address()).unwrap());
+        assert!(CwItRunner::is_contract(&app, "invalid").is_err());
+    }
+
+    #[test]
+    fn test_execute_contract() {
+        let app = CoreumTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        let res = CwItRunner::execute_contract(&app, &contract, &ExecuteMsg::Increment {}, &[], &admin).unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "wasm"));
+
+        let count: GetCountResponse = Wasm::new(&app).query(&contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 1);
+    }
+
+    #[test]
+    fn test_simulate() {
+        use osmosis_std::types::cosmwasm::wasm::v1::MsgExecuteContract;
+
+        let app = CoreumTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        let msg = MsgExecuteContract {
+            sender: admin.address(),
+            contract: contract.clone(),
+            msg: cosmwasm_std::to_json_vec(&ExecuteMsg::Increment {}).unwrap(),
+            funds: vec![],
+        };
+        let any = cosmrs::Any {
+            type_url: MsgExecuteContract::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+        let gas_info = CwItRunner::simulate(&app, vec![any], &admin).unwrap();
+        assert!(gas_info.gas_used > 0);
+
+        // The simulation does not change any state
+        let count: GetCountResponse = Wasm::new(&app).query(&contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 0);
+    }
+
+    #[test]
+    fn test_instantiate_contract() {
+        let app = CoreumTestApp::new();
+        let admin = app.init_account(&[Coin::new(1000000000000, "ucore")]).unwrap();
+        let code_id = app
+            .store_code(ContractType::Artifact(Artifact::Local(TEST_ARTIFACT.to_string())), &admin)
+            .unwrap();
+
+        let contract = CwItRunner::instantiate_contract(
+            &app,
+            code_id,
+            &InstantiateMsg { count: 7 },
+            &[],
+            Some(&admin.address()),
+            "counter",
+            &admin,
+        )
+        .unwrap();
+        assert!(contract.starts_with("core"));
+
+        let count: GetCountResponse = Wasm::new(&app).query(&contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 7);
+    }
+
+    #[test]
+    fn test_query_wasm_smart() {
+        let app = CoreumTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        let res: GetCountResponse = CwItRunner::query_wasm_smart(&app, &contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(res, GetCountResponse { count: 0 });
+
+        let err = CwItRunner::query_wasm_smart::<_, GetCountResponse>(&app, &admin.address(), &QueryMsg::GetCount {}).unwrap_err();
+        assert!(err.downcast_ref::<test_tube::RunnerError>().is_some());
+    }
+
+    #[test]
+    fn test_account_info() {
+        let app = CoreumTestApp::new();
+        let sender = app.init_account(&[Coin::new(1000000000000, "ucore")]).unwrap();
+        let recipient = app.init_account(&[]).unwrap();
+        let address = sender.address();
+
+        let (account_number, sequence) = CwItRunner::account_info(&app, &address).unwrap();
+        crate::helpers::bank_send(&app, &sender, &recipient.address(), vec![Coin::new(100, "ucore")]).unwrap();
+
+        assert_eq!(CwItRunner::account_info(&app, &address).unwrap(), (account_number, sequence + 1));
+        assert!(CwItRunner::account_info(&app, "core1unknown").is_err());
+    }
+
+    #[test]
+    fn test_denom_creation_fee() {
+        let app = CoreumTestApp::new();
+
+        let fee = app.denom_creation_fee().unwrap().unwrap();
+        assert_eq!(fee.denom, "ucore");
+        assert!(!fee.amount.is_zero());
+    }
+
+    #[test]
+    fn test_query_block_height() {
+        let app = CoreumTestApp::new();
+
+        let height = app.query_block_height().unwrap();
+        CwItRunner::increase_time(&app, 69).unwrap();
+        assert!(app.query_block_height().unwrap() > height);
+    }
 }
@@ -10,4 +10,6 @@ pub enum CwItError {
     RpcError(#[from] cosmrs::rpc::error::Error),
     #[error("{0}")]
     AnyhowError(#[from] anyhow::Error),
+    #[error("{0}")]
+    StdError(#[from] cosmwasm_std::StdError),
 }
@@ -1,19 +1,40 @@
 use std::env;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
 use std::{collections::HashMap, str::FromStr};
 
-use cosmwasm_std::{Coin, StdError, StdResult, Uint128};
+#[cfg(feature = "coreum")]
+use coreum_wasm_sdk::types::coreum::asset::ft::v1::{
+    QueryParamsRequest as AssetFtQueryParamsRequest, QueryParamsResponse as AssetFtQueryParamsResponse,
+};
+use cosmwasm_std::{from_json, to_json_string, Coin, StdError, StdResult, Uint128};
+use osmosis_std::types::cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse};
 use osmosis_std::types::cosmos::bank::v1beta1::{
-    MsgSend, MsgSendResponse, QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest,
+    Metadata, MsgSend, MsgSendResponse, QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryDenomMetadataRequest,
+    QueryDenomMetadataResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
 };
 use osmosis_std::types::cosmos::base::query::v1beta1::PageRequest;
 use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+use osmosis_std::types::cosmwasm::wasm::v1::{
+    MsgMigrateContract, MsgMigrateContractResponse, QueryCodeRequest, QueryCodeResponse, QueryContractInfoRequest,
+    QueryContractInfoResponse,
+};
+#[cfg(not(feature = "coreum"))]
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+    QueryParamsRequest as TokenFactoryQueryParamsRequest, QueryParamsResponse as TokenFactoryQueryParamsResponse,
+};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
-use test_tube::{Account, Module, Runner, RunnerExecuteResult, RunnerResult, SigningAccount};
-use test_tube::{Bank, Wasm};
+use test_tube::{Account, ExecuteResponse, Module, Runner, RunnerExecuteResult, RunnerResult, SigningAccount};
+use test_tube::{Bank, FeeSetting, Wasm};
 
 use crate::error::CwItError;
-use crate::traits::CwItRunner;
+use crate::traits::{CwItResponse, CwItRunner};
+#[cfg(feature = "checksum")]
+use crate::ChecksumLockfile;
 use crate::{ArtifactError, ContractType};
 
 #[cfg(not(feature = "coreum"))]
@@ -48,6 +69,47 @@ pub fn upload_wasm_files<'a, R: CwItRunner<'a>>(
         .collect()
 }
 
+/// Stores all `artifacts` and returns a map of **name -> code_id**.
+///
+/// Contracts are stored in ascending order of their names, so that the same set of artifacts
+/// always gets the same code ids regardless of the iteration order of the map.
+pub fn store_artifacts<'a, R: CwItRunner<'a>>(
+    runner: &'a R,
+    artifacts: HashMap<String, ContractType>,
+    signer: &SigningAccount,
+) -> Result<HashMap<String, u64>, anyhow::Error> {
+    let mut artifacts = artifacts.into_iter().collect::<Vec<_>>();
+    artifacts.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    artifacts
+        .into_iter()
+        .map(|(name, contract)| {
+            let code_id = runner.store_code(contract, signer)?;
+            Ok((name, code_id))
+        })
+        .collect()
+}
+
+/// Stores all `artifacts` like [`store_artifacts`], after verifying the wasm file of every
+/// [`ContractType::Artifact`] against its checksum in `lockfile`. Nothing is stored if any of the
+/// artifacts is missing from the lockfile or does not match its checksum. Multi-test contracts
+/// have no wasm file and are stored without verification.
+#[cfg(feature = "checksum")]
+pub fn store_codes<'a, R: CwItRunner<'a>>(
+    runner: &'a R,
+    artifacts: HashMap<String, ContractType>,
+    lockfile: &ChecksumLockfile,
+    signer: &SigningAccount,
+) -> Result<HashMap<String, u64>, anyhow::Error> {
+    for (name, contract) in &artifacts {
+        if let ContractType::Artifact(artifact) = contract {
+            lockfile.verify(name, &artifact.get_wasm_byte_code()?)?;
+        }
+    }
+
+    store_artifacts(runner, artifacts, signer)
+}
+
 /// Instantiates the liquidity helper contract
 pub fn instantiate_contract_with_funds<'a, R, M, S>(
     app: &'a R,
@@ -85,6 +147,137 @@ where
     instantiate_contract_with_funds(app, admin, code_id, instantite_msg, &[])
 }
 
+/// Migrates a contract by executing a `MsgMigrateContract` on the runner. `msg` must be the JSON
+/// encoded migrate message.
+pub fn migrate_contract<'a, R>(
+    runner: &'a R,
+    contract: &str,
+    new_code_id: u64,
+    msg: &[u8],
+    signer: &SigningAccount,
+) -> RunnerResult<CwItResponse>
+where
+    R: Runner<'a>,
+{
+    let res = runner.execute::<_, MsgMigrateContractResponse>(
+        MsgMigrateContract {
+            sender: signer.address(),
+            contract: contract.to_string(),
+            code_id: new_code_id,
+            msg: msg.to_vec(),
+        },
+        MsgMigrateContract::TYPE_URL,
+        signer,
+    )?;
+
+    Ok(CwItResponse {
+        events: res.events,
+        data: (!res.data.data.is_empty()).then(|| res.data.data.into()),
+    })
+}
+
+/// Executes the JSON encoded `msg` on `contract`, sending along `funds`, and returns the emitted
+/// events and the data set by the contract.
+pub fn execute_contract<'a, R, M>(
+    runner: &'a R,
+    contract: &str,
+    msg: &M,
+    funds: &[Coin],
+    signer: &SigningAccount,
+) -> RunnerResult<CwItResponse>
+where
+    R: Runner<'a>,
+    M: Serialize,
+{
+    let res = Wasm::new(runner).execute(contract, msg, funds, signer)?;
+
+    Ok(CwItResponse {
+        events: res.events,
+        data: (!res.data.data.is_empty()).then(|| res.data.data.into()),
+    })
+}
+
+/// Returns the sha256 hash of the wasm code stored under `code_id`, as reported by the chain.
+pub fn query_code_hash<'a>(runner: &'a impl Runner<'a>, code_id: u64) -> RunnerResult<Vec<u8>> {
+    let res = runner.query::<_, QueryCodeResponse>("/cosmwasm.wasm.v1.Query/Code", &QueryCodeRequest { code_id })?;
+    res.code_info
+        .map(|info| info.data_hash)
+        .ok_or_else(|| test_tube::RunnerError::QueryError {
+            msg: format!("no code info found for code id {}", code_id),
+        })
+}
+
+/// Returns true if `address` has contract info on the chain, i.e. if it is the address of a contract.
+/// Errors other than the contract not being found, e.g. for an invalid address, are returned.
+pub fn query_is_contract<'a>(runner: &'a impl Runner<'a>, address: &str) -> RunnerResult<bool> {
+    let res = runner.query::<_, QueryContractInfoResponse>(
+        "/cosmwasm.wasm.v1.Query/ContractInfo",
+        &QueryContractInfoRequest {
+            address: address.to_string(),
+        },
+    );
+    match res {
+        Ok(res) => Ok(res.contract_info.is_some()),
+        Err(test_tube::RunnerError::QueryError { msg }) if msg.contains("not found") => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the account number and sequence of `address`, as stored in the auth module.
+pub fn query_account_info<'a>(runner: &'a impl Runner<'a>, address: &str) -> RunnerResult<(u64, u64)> {
+    let account = runner
+        .query::<_, QueryAccountResponse>(
+            "/cosmos.auth.v1beta1.Query/Account",
+            &QueryAccountRequest {
+                address: address.to_string(),
+            },
+        )?
+        .account
+        .ok_or_else(|| test_tube::RunnerError::QueryError {
+            msg: format!("no account found for address {}", address),
+        })?;
+    let account = <BaseAccount as prost::Message>::decode(account.value.as_slice()).map_err(test_tube::DecodeError::ProtoDecodeError)?;
+
+    Ok((account.account_number, account.sequence))
+}
+
+/// Returns the bank metadata of `denom`, or `None` if no metadata is set for it.
+pub fn query_denom_metadata<'a>(runner: &'a impl Runner<'a>, denom: &str) -> RunnerResult<Option<Metadata>> {
+    let res = runner.query::<_, QueryDenomMetadataResponse>(
+        "/cosmos.bank.v1beta1.Query/DenomMetadata",
+        &QueryDenomMetadataRequest { denom: denom.to_string() },
+    );
+    match res {
+        Ok(res) => Ok(res.metadata),
+        // The bank module errors for denoms without metadata
+        Err(test_tube::RunnerError::QueryError { msg }) if msg.contains("not found") => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the fee charged for creating a denom, as configured in the chain's token factory params
+/// (the `issue_fee` of the assetft module on Coreum), or `None` if creating denoms is free.
+pub fn query_denom_creation_fee<'a>(runner: &'a impl Runner<'a>) -> RunnerResult<Option<Coin>> {
+    #[cfg(not(feature = "coreum"))]
+    let fee = runner
+        .query::<_, TokenFactoryQueryParamsResponse>("/osmosis.tokenfactory.v1beta1.Query/Params", &TokenFactoryQueryParamsRequest {})?
+        .params
+        .and_then(|params| params.denom_creation_fee.into_iter().next())
+        .map(|c| (c.amount, c.denom));
+    #[cfg(feature = "coreum")]
+    let fee = runner
+        .query::<_, AssetFtQueryParamsResponse>("/coreum.asset.ft.v1.Query/Params", &AssetFtQueryParamsRequest {})?
+        .params
+        .and_then(|params| params.issue_fee)
+        .map(|c| (c.amount, c.denom));
+
+    let Some((amount, denom)) = fee else {
+        return Ok(None);
+    };
+    let amount = Uint128::from_str(&amount).map_err(|e| test_tube::RunnerError::QueryError { msg: e.to_string() })?;
+    Ok((!amount.is_zero()).then_some(Coin { denom, amount }))
+}
+
 /// Uploads a wasm file to the chain and returns the code_id
 pub fn upload_wasm_file<'a, R: CwItRunner<'a>>(runner: &'a R, signer: &SigningAccount, contract: ContractType) -> Result<u64, CwItError> {
     let error_msg = format!("Failed to upload wasm file: {:?}", contract);
@@ -112,6 +305,161 @@ pub fn bank_all_balances_query<'a>(
         .map_err(|_| StdError::generic_err("Bank all balances query failed"))
 }
 
+/// Queries the total supply of `denom`.
+pub fn query_supply_of<'a>(runner: &'a impl Runner<'a>, denom: &str) -> RunnerResult<Uint128> {
+    let amount = runner
+        .query::<_, QuerySupplyOfResponse>(
+            "/cosmos.bank.v1beta1.Query/SupplyOf",
+            &QuerySupplyOfRequest { denom: denom.to_string() },
+        )?
+        .amount
+        .map(|c| c.amount)
+        .unwrap_or_default();
+    if amount.is_empty() {
+        return Ok(Uint128::zero());
+    }
+    Uint128::from_str(&amount).map_err(|e| test_tube::RunnerError::QueryError { msg: e.to_string() })
+}
+
+/// Asserts that running `f` changes the total supply of `denom` by exactly `expected_delta`, e.g.
+/// `1000` for a mint of 1000 tokens or `-500` for a burn of 500 tokens. Returns the result of `f`.
+pub fn assert_supply_delta<'a, T>(runner: &'a impl Runner<'a>, denom: &str, expected_delta: i128, f: impl FnOnce() -> T) -> T {
+    let supply = || {
+        query_supply_of(runner, denom)
+            .unwrap_or_else(|e| panic!("Failed to query supply of {}: {}", denom, e))
+            .u128() as i128
+    };
+
+    let before = supply();
+    let result = f();
+    let delta = supply() - before;
+    assert_eq!(
+        delta, expected_delta,
+        "Supply of {} changed by {}, expected a change of {}",
+        denom, delta, expected_delta
+    );
+    result
+}
+
+/// Asserts the balances of several accounts at once, given as **(address, denom, amount)**
+/// triples. All mismatches are reported together rather than only the first one, so a failing
+/// settlement shows the full picture.
+pub fn assert_balances_map<'a>(runner: &'a impl Runner<'a>, expected: &[(&str, &str, u128)]) {
+    let mismatches = expected
+        .iter()
+        .filter_map(|(address, denom, amount)| {
+            let actual = bank_balance_query(runner, address.to_string(), denom.to_string())
+                .unwrap_or_else(|e| panic!("Failed to query balance of {}: {}", address, e));
+            (actual.u128() != *amount).then(|| format!("{} has {}{}, expected {}{}", address, actual, denom, amount, denom))
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        mismatches.is_empty(),
+        "{} of {} balances do not match:\n  {}",
+        mismatches.len(),
+        expected.len(),
+        mismatches.join("\n  ")
+    );
+}
+
+/// Asserts the balances of `address`, tolerating the fees the account paid. Balances in the
+/// [`CwItRunner::fee_denom`] of `runner` may be up to `fee_tolerance` lower than expected, all
+/// other denoms must match exactly. Like with [`assert_balances_map`], all mismatches are reported
+/// together.
+pub fn assert_balances_ignoring_fees<'a, R: CwItRunner<'a>>(runner: &'a R, address: &str, expected: &[Coin], fee_tolerance: u128) {
+    let fee_denom = runner
+        .fee_denom()
+        .unwrap_or_else(|e| panic!("Failed to query the fee denom: {}", e));
+    let mismatches = expected
+        .iter()
+        .filter_map(|coin| {
+            let actual = bank_balance_query(runner, address.to_string(), coin.denom.clone())
+                .unwrap_or_else(|e| panic!("Failed to query balance of {}: {}", address, e));
+            if coin.denom != fee_denom {
+                return (actual != coin.amount).then(|| format!("{} has {}{}, expected {}", address, actual, coin.denom, coin));
+            }
+            let paid = coin.amount.checked_sub(actual).ok();
+            match paid {
+                Some(paid) if paid.u128() <= fee_tolerance => None,
+                _ => Some(format!(
+                    "{} has {}{}, expected {} minus fees of at most {}{}",
+                    address, actual, coin.denom, coin, fee_tolerance, coin.denom
+                )),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        mismatches.is_empty(),
+        "{} of {} balances do not match:\n  {}",
+        mismatches.len(),
+        expected.len(),
+        mismatches.join("\n  ")
+    );
+}
+
+/// Name of the environment variable that makes [`assert_balances_match_golden`] write the
+/// current balances to the golden file instead of comparing against it.
+pub const UPDATE_GOLDEN_ENV: &str = "CW_IT_UPDATE_GOLDEN";
+
+/// Asserts that the balances of `address` match the golden file at `path`, which contains them as
+/// a JSON list of coins ordered by denom. If the [`UPDATE_GOLDEN_ENV`] environment variable is
+/// set, the golden file is (re)written with the current balances instead.
+pub fn assert_balances_match_golden<'a>(runner: &'a impl Runner<'a>, address: &str, path: impl AsRef<Path>) {
+    assert_balances_match_golden_with(runner, address, path, env::var_os(UPDATE_GOLDEN_ENV).is_some())
+}
+
+/// Like [`assert_balances_match_golden`], but (re)writes the golden file if `update` is true
+/// rather than reading the [`UPDATE_GOLDEN_ENV`] environment variable.
+pub fn assert_balances_match_golden_with<'a>(runner: &'a impl Runner<'a>, address: &str, path: impl AsRef<Path>, update: bool) {
+    let path = path.as_ref();
+    let mut balances = bank_all_balances_query(runner, address.to_string(), None)
+        .unwrap_or_else(|e| panic!("Failed to query balances of {}: {}", address, e))
+        .balances
+        .into_iter()
+        .map(|c| Coin::new(Uint128::from_str(&c.amount).unwrap().u128(), c.denom))
+        .collect::<Vec<_>>();
+    balances.sort_by(|a, b| a.denom.cmp(&b.denom));
+    let actual = coins_to_pretty_json(&balances);
+
+    if update {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        std::fs::write(path, &actual).unwrap_or_else(|e| panic!("Failed to write golden file {}: {}", path.display(), e));
+        return;
+    }
+
+    let golden = std::fs::read(path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to read golden file {}: {}. Set {} to create it",
+            path.display(),
+            e,
+            UPDATE_GOLDEN_ENV
+        )
+    });
+    let expected: Vec<Coin> = from_json(golden).unwrap_or_else(|e| panic!("Golden file {} is not a list of coins: {}", path.display(), e));
+    assert!(
+        balances == expected,
+        "Balances of {} do not match golden file {}:\n  actual:   {}\n  expected: {}\nSet {} to update it",
+        address,
+        path.display(),
+        to_json_string(&balances).unwrap(),
+        to_json_string(&expected).unwrap(),
+        UPDATE_GOLDEN_ENV
+    );
+}
+
+/// Serializes `coins` as a JSON list with one coin per line, so golden files diff nicely.
+fn coins_to_pretty_json(coins: &[Coin]) -> String {
+    if coins.is_empty() {
+        return "[]\n".to_string();
+    }
+    let coins = coins.iter().map(|c| to_json_string(c).unwrap()).collect::<Vec<_>>();
+    format!("[\n  {}\n]\n", coins.join(",\n  "))
+}
+
 pub fn bank_send<'a>(
     runner: &'a impl Runner<'a>,
     sender: &SigningAccount,
@@ -135,6 +483,470 @@ pub fn bank_send<'a>(
     )
 }
 
+/// Funds `address` with `coins` by sending them from a newly created account, which additionally
+/// receives `fee` to pay for the transaction.
+pub(crate) fn fund_from_new_account<'a>(
+    runner: &'a impl CwItRunner<'a>,
+    address: &str,
+    coins: &[Coin],
+    fee: Coin,
+) -> Result<(), anyhow::Error> {
+    let mut initial_balance = coins.to_vec();
+    match initial_balance.iter_mut().find(|c| c.denom == fee.denom) {
+        Some(coin) => coin.amount += fee.amount,
+        None => initial_balance.push(fee),
+    }
+
+    let funder = runner.init_account(&initial_balance)?;
+    bank_send(runner, &funder, address, coins.to_vec())?;
+    Ok(())
+}
+
+/// The gas limit of transactions signed by accounts returned from [`skip_simulation`]. This is
+/// generous enough for any single transaction of a test.
+pub const FIXED_GAS_LIMIT: u64 = 20_000_000;
+
+/// Returns `account` set up to pay the fixed `fee` for [`FIXED_GAS_LIMIT`] gas. Test-tube runners
+/// only simulate a transaction to estimate its gas, so transactions signed by the returned account
+/// skip the simulation. This speeds up large test suites, at the cost of inaccurate gas usage and
+/// fees. Other accounts are unaffected and keep simulating their transactions.
+pub fn skip_simulation(account: SigningAccount, fee: Coin) -> SigningAccount {
+    account.with_fee_setting(FeeSetting::Custom {
+        amount: fee,
+        gas_limit: FIXED_GAS_LIMIT,
+    })
+}
+
+/// Asserts that the NFT `nft_id` of class `class_id` does not exist, e.g. after it has been burned.
+///
+/// The NFT is queried through the cosmos nft module's stargate query. The not found error differs
+/// between a real Coreum chain (`nft does not exist`) and the multi-test TokenFactory
+/// (`NFT not found`), so both are accepted.
+#[cfg(feature = "coreum")]
+pub fn assert_nft_not_found<'a>(runner: &'a impl Runner<'a>, class_id: &str, nft_id: &str) {
+    use coreum_wasm_sdk::types::cosmos::nft::v1beta1::{QueryNftRequest, QueryNftResponse};
+
+    let res = runner.query::<_, QueryNftResponse>(
+        "/cosmos.nft.v1beta1.Query/NFT",
+        &QueryNftRequest {
+            class_id: class_id.to_string(),
+            id: nft_id.to_string(),
+        },
+    );
+    match res {
+        Ok(res) => panic!("Expected NFT {}/{} to not exist, but found {:?}", class_id, nft_id, res.nft),
+        Err(err) => {
+            let msg = err.to_string().to_lowercase();
+            assert!(
+                msg.contains("not found") || msg.contains("does not exist"),
+                "Expected a not found error for NFT {}/{}, got {}",
+                class_id,
+                nft_id,
+                err
+            );
+        }
+    }
+}
+
+/// Returns the denom the Osmosis TokenFactory creates for `subdenom` when created by `creator`.
+#[cfg(not(feature = "coreum"))]
+pub fn factory_denom(creator: &str, subdenom: &str) -> String {
+    format!("factory/{}/{}", creator, subdenom)
+}
+
+/// Asserts that `response` of a `MsgCreateDenom` contains a `create_denom` event whose
+/// `new_token_denom` matches the denom computed by [`factory_denom`]. Returns the created denom.
+#[cfg(not(feature = "coreum"))]
+pub fn assert_created_denom<R>(response: &ExecuteResponse<R>, creator: &str, subdenom: &str) -> String {
+    let created = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "create_denom")
+        .flat_map(|e| e.attributes.iter())
+        .find(|a| a.key == "new_token_denom")
+        .map(|a| a.value.clone())
+        .expect("Expected a create_denom event with a new_token_denom attribute");
+
+    let expected = factory_denom(creator, subdenom);
+    assert_eq!(
+        created, expected,
+        "Created denom {} does not match expected denom {}",
+        created, expected
+    );
+    created
+}
+
+/// Runs a denom of the Osmosis TokenFactory through its whole lifecycle as a smoke test of the
+/// token factory of `runner`: `creator` creates the denom with `subdenom`, mints 1_000_000 of it,
+/// transfers 400_000 to a new account and burns 100_000, asserting the balances and supply after
+/// every step. `creator` must hold enough to pay the denom creation fee and the gas. Returns the
+/// denom.
+///
+/// Not available with the `coreum` feature, as Coreum issues tokens through its asset ft module,
+/// whose `MsgIssue` mints the initial supply and takes the features of the token, rather than
+/// through a token factory.
+#[cfg(not(feature = "coreum"))]
+pub fn assert_token_lifecycle<'a, R: CwItRunner<'a>>(runner: &'a R, creator: &SigningAccount, subdenom: &str) -> String {
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+        MsgBurn, MsgBurnResponse, MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgMintResponse,
+    };
+
+    let proto_coin = |amount: u128, denom: &str| ProtoCoin {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+    };
+    let assert_state = |step: &str, denom: &str, expected: &[(&str, u128)], supply: u128| {
+        for (address, amount) in expected {
+            let balance = bank_balance_query(runner, address.to_string(), denom.to_string()).unwrap();
+            assert_eq!(balance, Uint128::new(*amount), "Unexpected balance of {} after {}", address, step);
+        }
+        assert_eq!(
+            query_supply_of(runner, denom).unwrap(),
+            Uint128::new(supply),
+            "Unexpected supply of {} after {}",
+            denom,
+            step
+        );
+    };
+
+    let res = runner
+        .execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: creator.address(),
+                subdenom: subdenom.to_string(),
+            },
+            MsgCreateDenom::TYPE_URL,
+            creator,
+        )
+        .unwrap_or_else(|e| panic!("Failed to create denom: {}", e));
+    let denom = assert_created_denom(&res, &creator.address(), subdenom);
+    assert_state("create", &denom, &[(&creator.address(), 0)], 0);
+
+    runner
+        .execute::<_, MsgMintResponse>(
+            MsgMint {
+                sender: creator.address(),
+                amount: Some(proto_coin(1_000_000, &denom)),
+                mint_to_address: creator.address(),
+            },
+            MsgMint::TYPE_URL,
+            creator,
+        )
+        .unwrap_or_else(|e| panic!("Failed to mint {}: {}", denom, e));
+    assert_state("mint", &denom, &[(&creator.address(), 1_000_000)], 1_000_000);
+
+    let recipient = runner.init_account(&[]).unwrap();
+    bank_send(runner, creator, &recipient.address(), vec![Coin::new(400_000, &denom)])
+        .unwrap_or_else(|e| panic!("Failed to transfer {}: {}", denom, e));
+    assert_state(
+        "transfer",
+        &denom,
+        &[(&creator.address(), 600_000), (&recipient.address(), 400_000)],
+        1_000_000,
+    );
+
+    runner
+        .execute::<_, MsgBurnResponse>(
+            MsgBurn {
+                sender: creator.address(),
+                amount: Some(proto_coin(100_000, &denom)),
+                burn_from_address: creator.address(),
+            },
+            MsgBurn::TYPE_URL,
+            creator,
+        )
+        .unwrap_or_else(|e| panic!("Failed to burn {}: {}", denom, e));
+    assert_state(
+        "burn",
+        &denom,
+        &[(&creator.address(), 500_000), (&recipient.address(), 400_000)],
+        900_000,
+    );
+
+    denom
+}
+
+/// Asserts that `response` of a `MsgInstantiateContract` contains an `instantiate` event whose
+/// `code_id` attribute matches `code_id`, verifying that the expected code was instantiated.
+/// Returns the address of the instantiated contract.
+pub fn assert_instantiated<R>(response: &ExecuteResponse<R>, code_id: u64) -> String {
+    let event = response
+        .events
+        .iter()
+        .find(|e| e.ty == "instantiate")
+        .expect("Expected an instantiate event");
+    let attribute = |key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.clone())
+            .unwrap_or_else(|| panic!("Expected an instantiate event with a {} attribute", key))
+    };
+
+    let instantiated = attribute("code_id");
+    assert_eq!(
+        instantiated,
+        code_id.to_string(),
+        "Instantiated code id {} does not match expected code id {}",
+        instantiated,
+        code_id
+    );
+    attribute("_contract_address")
+}
+
+/// Asserts that the `data` of `response` decodes to the `expected` proto response.
+pub fn assert_response_data_proto<R, T>(response: &ExecuteResponse<R>, expected: &T)
+where
+    T: prost::Message + Default + PartialEq + Debug,
+{
+    let decoded = T::decode(response.raw_data.as_slice())
+        .unwrap_or_else(|e| panic!("Failed to decode response data as {}: {}", std::any::type_name::<T>(), e));
+    assert_eq!(&decoded, expected, "Response data does not match expected proto response");
+}
+
+/// Asserts that one of the events of `response` has an `amount` attribute containing the coin
+/// `{amount}{denom}`, in the format the SDK emits coins in. Attributes holding several comma
+/// separated coins are matched per coin.
+pub fn assert_amount_emitted<R>(response: &ExecuteResponse<R>, denom: &str, amount: impl Into<Uint128>) {
+    let expected = format!("{}{}", amount.into(), denom);
+    let emitted = response
+        .events
+        .iter()
+        .flat_map(|e| e.attributes.iter())
+        .filter(|a| a.key == "amount")
+        .map(|a| a.value.as_str())
+        .collect::<Vec<_>>();
+
+    assert!(
+        emitted.iter().any(|value| value.split(',').any(|coin| coin.trim() == expected)),
+        "Expected an event with amount {}, but only found amounts {:?}",
+        expected,
+        emitted
+    );
+}
+
+/// Asserts that one of the events of type `event_type` in `response` contains all `expected`
+/// **key -> value** attributes, in any order. Other attributes of the event are ignored.
+pub fn assert_event_attrs_unordered<R>(response: &ExecuteResponse<R>, event_type: &str, expected: &[(&str, &str)]) {
+    let events = response.events.iter().filter(|e| e.ty == event_type).collect::<Vec<_>>();
+
+    assert!(
+        events.iter().any(|event| {
+            expected
+                .iter()
+                .all(|(key, value)| event.attributes.iter().any(|a| a.key == *key && a.value == *value))
+        }),
+        "Expected an event of type {} with attributes {:?}, but found {:?}",
+        event_type,
+        expected,
+        events
+    );
+}
+
+/// Asserts that `response` of a contract execution contains exactly one `execute` event, i.e. the
+/// executed contract did not dispatch any messages to other contracts, and that all `wasm` events
+/// were emitted by that contract.
+pub fn assert_no_subcalls<R>(response: &ExecuteResponse<R>) {
+    let contract_address = |event: &cosmwasm_std::Event| {
+        event
+            .attributes
+            .iter()
+            .find(|a| a.key == "_contract_address")
+            .map(|a| a.value.clone())
+    };
+
+    let executed = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "execute")
+        .filter_map(contract_address)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        executed.len(),
+        1,
+        "Expected exactly one executed contract, but found {:?}",
+        executed
+    );
+
+    let emitting = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .filter_map(contract_address)
+        .filter(|address| *address != executed[0])
+        .collect::<Vec<_>>();
+    assert!(
+        emitting.is_empty(),
+        "Expected only {} to emit wasm events, but {:?} did as well",
+        executed[0],
+        emitting
+    );
+}
+
+/// Asserts that a transaction whose `response` is given succeeded even though one of its
+/// submessages failed, because `contract` caught the failure in a `reply_on_error` handler and
+/// emitted it in a `wasm` event attribute `key`. The emitted value must contain `expected_error`.
+/// Note that real chains redact the errors passed to replies to their code, e.g.
+/// `codespace: wasm, code: 5`, so the full message is only available on multi-test.
+pub fn assert_reply_error_caught<R>(response: &ExecuteResponse<R>, contract: &str, key: &str, expected_error: &str) {
+    let caught = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .filter(|e| e.attributes.iter().any(|a| a.key == "_contract_address" && a.value == contract))
+        .flat_map(|e| e.attributes.iter())
+        .filter(|a| a.key == key)
+        .map(|a| a.value.as_str())
+        .collect::<Vec<_>>();
+
+    assert!(
+        caught.iter().any(|err| err.contains(expected_error)),
+        "Expected {} to catch an error containing \"{}\", but it caught {:?}",
+        contract,
+        expected_error,
+        caught
+    );
+}
+
+/// Asserts that the reply handler of `contract` was not called during the transaction whose
+/// `response` is given, e.g. because it dispatched its submessages with `ReplyOn::Never`. Replies
+/// are detected by the `reply` events the chain emits for every call of a reply handler.
+pub fn assert_reply_not_called<R>(response: &ExecuteResponse<R>, contract: &str) {
+    let replies = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "reply")
+        .filter(|e| e.attributes.iter().any(|a| a.key == "_contract_address" && a.value == contract))
+        .count();
+
+    assert!(
+        replies == 0,
+        "Expected the reply handler of {} not to be called, but it was called {} times",
+        contract,
+        replies
+    );
+}
+
+/// Queries `contract` with `msg` and asserts that the decoded response equals `expected`. On a
+/// mismatch both values are pretty printed, so the difference is easy to spot.
+pub fn assert_query_eq<'a, M, R>(runner: &'a impl Runner<'a>, contract: &str, msg: &M, expected: R)
+where
+    M: Serialize,
+    R: DeserializeOwned + PartialEq + Debug,
+{
+    let actual: R = Wasm::new(runner)
+        .query(contract, msg)
+        .unwrap_or_else(|e| panic!("Failed to query contract {}: {}", contract, e));
+    assert!(
+        actual == expected,
+        "Query result does not match expected value\n  actual: {:#?}\nexpected: {:#?}",
+        actual,
+        expected
+    );
+}
+
+/// Queries `contract` with `query_msg`, migrates it to `migrate_code_id` with `migrate_msg` and
+/// queries it again, asserting that the response is unchanged. Returns the response.
+pub fn assert_state_preserved<'a, R, Q, M, T>(
+    runner: &'a R,
+    contract: &str,
+    query_msg: &Q,
+    migrate_code_id: u64,
+    migrate_msg: &M,
+    signer: &SigningAccount,
+) -> T
+where
+    R: CwItRunner<'a>,
+    Q: Serialize,
+    M: Serialize,
+    T: DeserializeOwned + PartialEq + Debug + Clone,
+{
+    assert_state_migrated(
+        runner,
+        contract,
+        query_msg,
+        migrate_code_id,
+        migrate_msg,
+        signer,
+        std::convert::identity,
+    )
+}
+
+/// Like [`assert_state_preserved`], but asserts that the response after the migration equals
+/// `expected` applied to the response before it, for migrations that transform the state.
+pub fn assert_state_migrated<'a, R, Q, M, T>(
+    runner: &'a R,
+    contract: &str,
+    query_msg: &Q,
+    migrate_code_id: u64,
+    migrate_msg: &M,
+    signer: &SigningAccount,
+    expected: impl FnOnce(T) -> T,
+) -> T
+where
+    R: CwItRunner<'a>,
+    Q: Serialize,
+    M: Serialize,
+    T: DeserializeOwned + PartialEq + Debug + Clone,
+{
+    let query = || -> T {
+        runner
+            .query_wasm_smart(contract, query_msg)
+            .unwrap_or_else(|e| panic!("Failed to query contract {}: {}", contract, e))
+    };
+
+    let before = query();
+    let msg = cosmwasm_std::to_json_vec(migrate_msg).unwrap();
+    runner
+        .migrate_contract(contract, migrate_code_id, &msg, signer)
+        .unwrap_or_else(|e| panic!("Failed to migrate contract {} to code id {}: {}", contract, migrate_code_id, e));
+    let after = query();
+
+    let expected = expected(before.clone());
+    assert!(
+        after == expected,
+        "State of {} was not preserved by the migration to code id {}\n  before: {:#?}\n   after: {:#?}\nexpected: {:#?}",
+        contract,
+        migrate_code_id,
+        before,
+        after,
+        expected
+    );
+    after
+}
+
+/// Executes `msg` on `contract` with a zero amount of `denom` attached as funds, and asserts that
+/// the deposit is rejected without changing the balance of the contract. The zero coin is passed
+/// on as is rather than being dropped, so depending on the runner the deposit is rejected either
+/// by the chain or by the contract itself. Returns the error, so the reason can be checked.
+pub fn assert_zero_deposit_rejected<'a, M>(
+    runner: &'a impl Runner<'a>,
+    contract: &str,
+    msg: &M,
+    denom: &str,
+    signer: &SigningAccount,
+) -> test_tube::RunnerError
+where
+    M: Serialize,
+{
+    let balance_before = bank_balance_query(runner, contract.to_string(), denom.to_string()).unwrap();
+
+    let err = match Wasm::new(runner).execute(contract, msg, &[Coin::new(0, denom)], signer) {
+        Ok(_) => panic!("Expected a deposit of 0{} to {} to be rejected, but it succeeded", denom, contract),
+        Err(e) => e,
+    };
+
+    let balance_after = bank_balance_query(runner, contract.to_string(), denom.to_string()).unwrap();
+    assert_eq!(
+        balance_before, balance_after,
+        "Balance of {} in {} changed after a rejected zero deposit",
+        denom, contract
+    );
+
+    err
+}
+
 pub fn get_current_working_dir() -> String {
     let res = env::current_dir();
     match res {
@@ -143,6 +955,80 @@ pub fn get_current_working_dir() -> String {
     }
 }
 
+/// Runs `f` and returns its result, panicking with a clear message if it does not finish within
+/// `duration`. Useful to find operations that hang or are unexpectedly slow in CI.
+///
+/// `f` runs on a spawned thread, so a hanging `f` can't block the test past the deadline. As the
+/// thread can't be interrupted, it is left running in the background once the timeout is hit.
+/// Panics inside `f` are propagated to the caller.
+pub fn with_timeout<T, F>(duration: Duration, f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // The receiver is gone once the deadline has passed, so the result can be dropped
+        let _ = tx.send(f());
+    });
+
+    match rx.recv_timeout(duration) {
+        Ok(res) => res,
+        Err(RecvTimeoutError::Timeout) => panic!("Operation timed out after {:?}", duration),
+        // The sender is dropped without sending if `f` panicked
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("the result is sent before the thread finishes"),
+        },
+    }
+}
+
+/// Accumulates the gas used across several executions, to budget the gas of a whole user flow
+/// rather than single messages. Only the test-tube runners report the gas used by an execution,
+/// so this is of little use with the multi-test runner.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasMeter {
+    steps: Vec<(String, u64)>,
+}
+
+impl GasMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the gas used by `response` under `label` and returns it.
+    pub fn record<R>(&mut self, label: impl Into<String>, response: &ExecuteResponse<R>) -> u64 {
+        let gas_used = response.gas_info.gas_used;
+        self.steps.push((label.into(), gas_used));
+        gas_used
+    }
+
+    /// Returns the label and gas used of every recorded execution, in order of recording.
+    pub fn steps(&self) -> &[(String, u64)] {
+        &self.steps
+    }
+
+    /// Returns the total gas used by all recorded executions.
+    pub fn total(&self) -> u64 {
+        self.steps.iter().map(|(_, gas)| gas).sum()
+    }
+
+    /// Asserts that the total gas used is within `budget`, printing the gas used by every step
+    /// if it isn't.
+    pub fn assert_within_budget(&self, budget: u64) {
+        assert!(self.total() <= budget, "Gas budget of {} exceeded:\n{}", budget, self);
+    }
+}
+
+impl Display for GasMeter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (label, gas) in &self.steps {
+            writeln!(f, "{}: {}", label, gas)?;
+        }
+        write!(f, "total: {}", self.total())
+    }
+}
+
 /// An enum to choose which type of unwrap to use. When using `Unwrap::Err`, the
 /// result must be an `Err` or the test will panic. If the result contains an
 /// `Err`, the test will pass only if the error message contains the provided
@@ -191,3 +1077,888 @@ fn test_unwrap_panic() {
     let res: Result<u32, &str> = Err("random");
     Unwrap::Err("test").unwrap(res);
 }
+
+#[test]
+fn test_with_timeout() {
+    assert_eq!(with_timeout(Duration::from_secs(5), || 1 + 1), 2);
+}
+
+#[test]
+fn test_with_timeout_panics_on_timeout() {
+    let start = std::time::Instant::now();
+    let err = std::panic::catch_unwind(|| {
+        with_timeout(Duration::from_millis(10), || loop {
+            thread::sleep(Duration::from_millis(100));
+        })
+    })
+    .unwrap_err();
+
+    assert_eq!(err.downcast_ref::<String>().unwrap(), "Operation timed out after 10ms");
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+#[should_panic(expected = "inner panic")]
+fn test_with_timeout_propagates_panic() {
+    with_timeout(Duration::from_secs(5), || panic!("inner panic"));
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_store_artifacts() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+
+    let artifacts: HashMap<String, ContractType> = [
+        ("counter_b".to_string(), ContractType::MultiTestContract(counter::contract())),
+        ("counter_a".to_string(), ContractType::MultiTestContract(counter::contract())),
+    ]
+    .into_iter()
+    .collect();
+    let code_ids = store_artifacts(&runner, artifacts, &signer).unwrap();
+
+    assert_eq!(code_ids.len(), 2);
+    assert_ne!(code_ids["counter_a"], code_ids["counter_b"]);
+    // Artifacts are stored in order of their names
+    assert!(code_ids["counter_a"] < code_ids["counter_b"]);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", feature = "checksum", not(feature = "coreum")))]
+fn test_store_codes_checksum_mismatch() {
+    use crate::artifact::Artifact;
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+
+    let stale_checksum = "0".repeat(64);
+    let lockfile = ChecksumLockfile::parse(&format!("{}  counter.wasm", stale_checksum)).unwrap();
+    let artifacts: HashMap<String, ContractType> = [
+        (
+            "counter".to_string(),
+            ContractType::Artifact(Artifact::Local("artifacts/counter.wasm".to_string())),
+        ),
+        ("counter_mt".to_string(), ContractType::MultiTestContract(counter::contract())),
+    ]
+    .into_iter()
+    .collect();
+
+    let err = store_codes(&runner, artifacts, &lockfile, &signer).unwrap_err();
+    match err.downcast::<ArtifactError>().unwrap() {
+        ArtifactError::ChecksumMismatch { expected, .. } => assert_eq!(expected, stale_checksum),
+        e => panic!("Unexpected error: {}", e),
+    }
+    // Nothing is stored when an artifact does not match its checksum
+    assert!(runner.app.borrow().wrap().query_wasm_code_info(1).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", feature = "coreum"))]
+fn test_assert_nft_not_found() {
+    use coreum_wasm_sdk::types::coreum::asset::nft::v1::{
+        MsgBurn, MsgBurnResponse, MsgIssueClass, MsgIssueClassResponse, MsgMint, MsgMintResponse,
+    };
+
+    use crate::multi_test::{modules::TokenFactory, MultiTestRunner};
+
+    let runner = MultiTestRunner::new_with_stargate("core", TokenFactory::default());
+    let issuer = runner.init_default_account().unwrap();
+    let class_id = format!("nftclass-{}", issuer.address());
+
+    runner
+        .execute::<_, MsgIssueClassResponse>(
+            MsgIssueClass {
+                issuer: issuer.address(),
+                name: "My NFT Class".to_string(),
+                symbol: "NFTCLASS".to_string(),
+                ..MsgIssueClass::default()
+            },
+            MsgIssueClass::TYPE_URL,
+            &issuer,
+        )
+        .unwrap();
+    runner
+        .execute::<_, MsgMintResponse>(
+            MsgMint {
+                sender: issuer.address(),
+                class_id: class_id.clone(),
+                id: "nft1".to_string(),
+                recipient: issuer.address(),
+                ..MsgMint::default()
+            },
+            MsgMint::TYPE_URL,
+            &issuer,
+        )
+        .unwrap();
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| assert_nft_not_found(&runner, &class_id, "nft1")));
+    assert!(res.is_err(), "minted NFT should be found");
+
+    runner
+        .execute::<_, MsgBurnResponse>(
+            MsgBurn {
+                sender: issuer.address(),
+                class_id: class_id.clone(),
+                id: "nft1".to_string(),
+                ..MsgBurn::default()
+            },
+            MsgBurn::TYPE_URL,
+            &issuer,
+        )
+        .unwrap();
+
+    assert_nft_not_found(&runner, &class_id, "nft1");
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_created_denom() {
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse};
+
+    use crate::multi_test::{modules::TokenFactory, MultiTestRunner};
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+    let creator = runner.init_default_account().unwrap();
+
+    let res = runner
+        .execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: creator.address(),
+                subdenom: "mydenom".to_string(),
+            },
+            MsgCreateDenom::TYPE_URL,
+            &creator,
+        )
+        .unwrap();
+
+    let denom = assert_created_denom(&res, &creator.address(), "mydenom");
+    assert_eq!(denom, res.data.new_token_denom);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_response_data_proto() {
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse};
+
+    use crate::multi_test::{modules::TokenFactory, MultiTestRunner};
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+    let creator = runner.init_default_account().unwrap();
+
+    let res = runner
+        .execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: creator.address(),
+                subdenom: "mydenom".to_string(),
+            },
+            MsgCreateDenom::TYPE_URL,
+            &creator,
+        )
+        .unwrap();
+
+    assert_response_data_proto(
+        &res,
+        &MsgCreateDenomResponse {
+            new_token_denom: factory_denom(&creator.address(), "mydenom"),
+        },
+    );
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_token_lifecycle() {
+    use crate::multi_test::{modules::TokenFactory, MultiTestRunner};
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+    let creator = runner.init_account(&[Coin::new(100_000_000, DEFAULT_COIN_DENOM)]).unwrap();
+
+    let denom = assert_token_lifecycle(&runner, &creator, "lifecycle");
+    assert_eq!(denom, factory_denom(&creator.address(), "lifecycle"));
+}
+
+#[test]
+#[should_panic(expected = "Response data does not match expected proto response")]
+fn test_assert_response_data_proto_mismatch() {
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgCreateDenomResponse;
+
+    let res = ExecuteResponse::<MsgCreateDenomResponse> {
+        data: Default::default(),
+        raw_data: prost::Message::encode_to_vec(&MsgCreateDenomResponse {
+            new_token_denom: "factory/creator/mydenom".to_string(),
+        }),
+        events: vec![],
+        gas_info: Default::default(),
+    };
+    assert_response_data_proto(
+        &res,
+        &MsgCreateDenomResponse {
+            new_token_denom: "factory/creator/other".to_string(),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "does not match expected denom")]
+#[cfg(not(feature = "coreum"))]
+fn test_assert_created_denom_mismatch() {
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![cosmwasm_std::Event::new("create_denom").add_attribute("new_token_denom", "factory/creator/other")],
+        gas_info: Default::default(),
+    };
+    assert_created_denom(&res, "creator", "mydenom");
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_instantiated() {
+    use osmosis_std::types::cosmwasm::wasm::v1::{MsgInstantiateContract, MsgInstantiateContractResponse};
+
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    runner
+        .store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+        .unwrap();
+    let code_id = runner
+        .store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+        .unwrap();
+
+    let res = runner
+        .execute::<_, MsgInstantiateContractResponse>(
+            MsgInstantiateContract {
+                sender: signer.address(),
+                admin: signer.address(),
+                code_id,
+                label: "counter".to_string(),
+                msg: cosmwasm_std::to_json_vec(&counter::InstantiateMsg { count: 0 }).unwrap(),
+                funds: vec![],
+            },
+            MsgInstantiateContract::TYPE_URL,
+            &signer,
+        )
+        .unwrap();
+
+    let address = assert_instantiated(&res, code_id);
+    assert!(!address.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "does not match expected code id")]
+fn test_assert_instantiated_mismatch() {
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![cosmwasm_std::Event::new("instantiate")
+            .add_attribute("_contract_address", "contract")
+            .add_attribute("code_id", "1")],
+        gas_info: Default::default(),
+    };
+    assert_instantiated(&res, 2);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_query_eq() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let contract = counter::deploy_test_counter(&runner, &signer).unwrap().address;
+
+    Wasm::new(&runner)
+        .execute(&contract, &counter::ExecuteMsg::Increment {}, &[], &signer)
+        .unwrap();
+
+    assert_query_eq(
+        &runner,
+        &contract,
+        &counter::QueryMsg::GetCount {},
+        counter::GetCountResponse { count: 1 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Query result does not match expected value")]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_query_eq_mismatch() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let contract = counter::deploy_test_counter(&runner, &signer).unwrap().address;
+
+    assert_query_eq(
+        &runner,
+        &contract,
+        &counter::QueryMsg::GetCount {},
+        counter::GetCountResponse { count: 1 },
+    );
+}
+
+#[test]
+fn test_assert_amount_emitted() {
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![
+            cosmwasm_std::Event::new("coin_received").add_attribute("amount", "100uosmo,2500uion"),
+            cosmwasm_std::Event::new("transfer").add_attribute("amount", "42uatom"),
+        ],
+        gas_info: Default::default(),
+    };
+    assert_amount_emitted(&res, "uion", 2500u128);
+    assert_amount_emitted(&res, "uatom", 42u128);
+}
+
+#[test]
+#[should_panic(expected = "Expected an event with amount 100uatom")]
+fn test_assert_amount_emitted_missing() {
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![cosmwasm_std::Event::new("transfer").add_attribute("amount", "1100uatom")],
+        gas_info: Default::default(),
+    };
+    assert_amount_emitted(&res, "uatom", 100u128);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_supply_delta() {
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+        MsgBurn, MsgBurnResponse, MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgMintResponse,
+    };
+
+    use crate::multi_test::{modules::TokenFactory, MultiTestRunner};
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+    let creator = runner.init_default_account().unwrap();
+    let denom = runner
+        .execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: creator.address(),
+                subdenom: "mydenom".to_string(),
+            },
+            MsgCreateDenom::TYPE_URL,
+            &creator,
+        )
+        .unwrap()
+        .data
+        .new_token_denom;
+    let coin = |amount: u128| Some(Coin::new(amount, &denom).into());
+
+    assert_supply_delta(&runner, &denom, 1000, || {
+        runner
+            .execute::<_, MsgMintResponse>(
+                MsgMint {
+                    sender: creator.address(),
+                    amount: coin(1000),
+                    mint_to_address: creator.address(),
+                },
+                MsgMint::TYPE_URL,
+                &creator,
+            )
+            .unwrap()
+    });
+    assert_supply_delta(&runner, &denom, -500, || {
+        runner
+            .execute::<_, MsgBurnResponse>(
+                MsgBurn {
+                    sender: creator.address(),
+                    amount: coin(500),
+                    burn_from_address: creator.address(),
+                },
+                MsgBurn::TYPE_URL,
+                &creator,
+            )
+            .unwrap()
+    });
+    assert_eq!(query_supply_of(&runner, &denom).unwrap(), Uint128::new(500));
+}
+
+#[test]
+#[should_panic(expected = "changed by 0, expected a change of 1")]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_supply_delta_mismatch() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    assert_supply_delta(&runner, "uosmo", 1, || ());
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_event_attrs_unordered() {
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgMintResponse};
+
+    use crate::multi_test::{modules::TokenFactory, MultiTestRunner};
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+    let creator = runner.init_default_account().unwrap();
+    let denom = runner
+        .execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: creator.address(),
+                subdenom: "mydenom".to_string(),
+            },
+            MsgCreateDenom::TYPE_URL,
+            &creator,
+        )
+        .unwrap()
+        .data
+        .new_token_denom;
+
+    let res = runner
+        .execute::<_, MsgMintResponse>(
+            MsgMint {
+                sender: creator.address(),
+                amount: Some(Coin::new(1000, &denom).into()),
+                mint_to_address: creator.address(),
+            },
+            MsgMint::TYPE_URL,
+            &creator,
+        )
+        .unwrap();
+
+    assert_event_attrs_unordered(
+        &res,
+        "tf_mint",
+        &[("amount", "1000"), ("denom", &denom), ("sender", &creator.address())],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Expected an event of type transfer with attributes")]
+fn test_assert_event_attrs_unordered_split_across_events() {
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![
+            cosmwasm_std::Event::new("transfer").add_attribute("recipient", "alice"),
+            cosmwasm_std::Event::new("transfer").add_attribute("amount", "100uosmo"),
+        ],
+        gas_info: Default::default(),
+    };
+    // Both attributes must be on the same event
+    assert_event_attrs_unordered(&res, "transfer", &[("amount", "100uosmo"), ("recipient", "alice")]);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_zero_deposit_rejected() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::vault;
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let code_id = runner
+        .store_code(ContractType::MultiTestContract(vault::contract()), &signer)
+        .unwrap();
+    let contract: String = instantiate_contract(
+        &runner,
+        &signer,
+        code_id,
+        &vault::InstantiateMsg {
+            denom: DEFAULT_COIN_DENOM.to_string(),
+        },
+    )
+    .unwrap();
+    let deposited = |amount: u128| {
+        assert_query_eq(
+            &runner,
+            &contract,
+            &vault::QueryMsg::Deposited { address: signer.address() },
+            Uint128::new(amount),
+        )
+    };
+
+    assert_zero_deposit_rejected(&runner, &contract, &vault::ExecuteMsg::Deposit {}, DEFAULT_COIN_DENOM, &signer);
+    deposited(0);
+
+    // A positive deposit is accepted
+    Wasm::new(&runner)
+        .execute(
+            &contract,
+            &vault::ExecuteMsg::Deposit {},
+            &[Coin::new(100, DEFAULT_COIN_DENOM)],
+            &signer,
+        )
+        .unwrap();
+    deposited(100);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_state_preserved() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let v1 = counter::deploy_test_counter(&runner, &signer).unwrap();
+    v1.reset(5, &signer).unwrap();
+    let v2 = runner
+        .store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+        .unwrap();
+    let contract = v1.address;
+
+    let res: counter::GetCountResponse = assert_state_preserved(
+        &runner,
+        &contract,
+        &counter::QueryMsg::GetCount {},
+        v2,
+        &counter::MigrateMsg { count: None },
+        &signer,
+    );
+    assert_eq!(res.count, 5);
+
+    let res: counter::GetCountResponse = assert_state_migrated(
+        &runner,
+        &contract,
+        &counter::QueryMsg::GetCount {},
+        v2,
+        &counter::MigrateMsg { count: Some(10) },
+        &signer,
+        |before| counter::GetCountResponse { count: before.count * 2 },
+    );
+    assert_eq!(res.count, 10);
+}
+
+#[test]
+#[should_panic(expected = "was not preserved by the migration")]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_state_preserved_changed() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let v1 = counter::deploy_test_counter(&runner, &signer).unwrap();
+    v1.reset(5, &signer).unwrap();
+    let v2 = runner
+        .store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+        .unwrap();
+    let contract = v1.address;
+
+    assert_state_preserved::<_, _, _, counter::GetCountResponse>(
+        &runner,
+        &contract,
+        &counter::QueryMsg::GetCount {},
+        v2,
+        &counter::MigrateMsg { count: Some(0) },
+        &signer,
+    );
+}
+
+#[cfg(test)]
+fn response_with_gas(gas_used: u64) -> ExecuteResponse<MsgSendResponse> {
+    ExecuteResponse {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![],
+        gas_info: cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo {
+            gas_wanted: gas_used,
+            gas_used,
+        },
+    }
+}
+
+#[test]
+fn test_gas_meter() {
+    let mut meter = GasMeter::new();
+    assert_eq!(meter.record("first", &response_with_gas(100)), 100);
+    meter.record("second", &response_with_gas(250));
+
+    assert_eq!(meter.total(), 350);
+    assert_eq!(meter.steps(), &[("first".to_string(), 100), ("second".to_string(), 250)]);
+    assert_eq!(meter.to_string(), "first: 100\nsecond: 250\ntotal: 350");
+    meter.assert_within_budget(350);
+}
+
+#[test]
+#[should_panic(expected = "Gas budget of 300 exceeded")]
+fn test_gas_meter_over_budget() {
+    let mut meter = GasMeter::new();
+    meter.record("first", &response_with_gas(200));
+    meter.record("second", &response_with_gas(200));
+    meter.assert_within_budget(300);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_no_subcalls() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let contract = counter::deploy_test_counter(&runner, &signer).unwrap().address;
+
+    let res = Wasm::new(&runner)
+        .execute(&contract, &counter::ExecuteMsg::Increment {}, &[], &signer)
+        .unwrap();
+
+    assert_no_subcalls(&res);
+}
+
+#[test]
+#[should_panic(expected = "Expected exactly one executed contract")]
+fn test_assert_no_subcalls_nested() {
+    let execute = |contract: &str| cosmwasm_std::Event::new("execute").add_attribute("_contract_address", contract);
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![execute("contract"), execute("other")],
+        gas_info: Default::default(),
+    };
+    assert_no_subcalls(&res);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_reply_error_caught() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::{reply_catcher, vault};
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+
+    let vault_code_id = runner
+        .store_code(ContractType::MultiTestContract(vault::contract()), &signer)
+        .unwrap();
+    let vault: String = instantiate_contract(
+        &runner,
+        &signer,
+        vault_code_id,
+        &vault::InstantiateMsg {
+            denom: DEFAULT_COIN_DENOM.to_string(),
+        },
+    )
+    .unwrap();
+    let catcher_code_id = runner
+        .store_code(ContractType::MultiTestContract(reply_catcher::contract()), &signer)
+        .unwrap();
+    let catcher: String = instantiate_contract(&runner, &signer, catcher_code_id, &reply_catcher::InstantiateMsg {}).unwrap();
+
+    // The deposit without funds fails, but the failure is caught so the tx succeeds
+    let deposit = cosmwasm_std::WasmMsg::Execute {
+        contract_addr: vault.clone(),
+        msg: cosmwasm_std::to_json_binary(&vault::ExecuteMsg::Deposit {}).unwrap(),
+        funds: vec![],
+    };
+    let res = Wasm::new(&runner)
+        .execute(&catcher, &reply_catcher::ExecuteMsg::Dispatch { msg: deposit }, &[], &signer)
+        .unwrap();
+
+    assert_reply_error_caught(
+        &res,
+        &catcher,
+        reply_catcher::CAUGHT_ERROR_ATTR,
+        "Deposit amount must be greater than zero",
+    );
+    let caught: Vec<String> = Wasm::new(&runner)
+        .query(&catcher, &reply_catcher::QueryMsg::CaughtErrors {})
+        .unwrap();
+    assert_eq!(caught.len(), 1);
+    assert!(caught[0].contains("Deposit amount must be greater than zero"), "{}", caught[0]);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_reply_not_called() {
+    use cosmwasm_std::ReplyOn;
+
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::{counter, reply_catcher};
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let signer = runner.init_default_account().unwrap();
+    let counter = counter::deploy_test_counter(&runner, &signer).unwrap().address;
+    let catcher_code_id = runner
+        .store_code(ContractType::MultiTestContract(reply_catcher::contract()), &signer)
+        .unwrap();
+    let catcher: String = instantiate_contract(&runner, &signer, catcher_code_id, &reply_catcher::InstantiateMsg {}).unwrap();
+
+    let dispatch = |reply_on: ReplyOn| {
+        let increment = cosmwasm_std::WasmMsg::Execute {
+            contract_addr: counter.clone(),
+            msg: cosmwasm_std::to_json_binary(&counter::ExecuteMsg::Increment {}).unwrap(),
+            funds: vec![],
+        };
+        Wasm::new(&runner)
+            .execute(
+                &catcher,
+                &reply_catcher::ExecuteMsg::DispatchWith { msg: increment, reply_on },
+                &[],
+                &signer,
+            )
+            .unwrap()
+    };
+    let replies = || -> u64 { Wasm::new(&runner).query(&catcher, &reply_catcher::QueryMsg::Replies {}).unwrap() };
+
+    let res = dispatch(ReplyOn::Never);
+    assert_reply_not_called(&res, &catcher);
+    assert_eq!(replies(), 0);
+
+    // A reply that does fire is detected
+    let res = dispatch(ReplyOn::Always);
+    assert_eq!(replies(), 1);
+    let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| assert_reply_not_called(&res, &catcher))).unwrap_err();
+    assert!(err.downcast_ref::<String>().unwrap().contains("it was called 1 times"));
+}
+
+#[test]
+#[should_panic(expected = "Expected catcher to catch an error containing")]
+fn test_assert_reply_error_caught_nothing_caught() {
+    let res = ExecuteResponse::<MsgSendResponse> {
+        data: MsgSendResponse {},
+        raw_data: vec![],
+        events: vec![cosmwasm_std::Event::new("wasm")
+            .add_attribute("_contract_address", "catcher")
+            .add_attribute("action", "dispatch")],
+        gas_info: Default::default(),
+    };
+    assert_reply_error_caught(&res, "catcher", "caught_error", "Deposit amount must be greater than zero");
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_balances_map() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let payer = runner
+        .init_account(&[Coin::new(1_000_000_000, DEFAULT_COIN_DENOM), Coin::new(1000, "uusd")])
+        .unwrap();
+    let alice = runner.init_account(&[]).unwrap();
+    let bob = runner.init_account(&[]).unwrap();
+
+    bank_send(&runner, &payer, &alice.address(), vec![Coin::new(300, "uusd")]).unwrap();
+    bank_send(&runner, &payer, &bob.address(), vec![Coin::new(200, "uusd")]).unwrap();
+
+    assert_balances_map(
+        &runner,
+        &[
+            (&payer.address(), "uusd", 500),
+            (&alice.address(), "uusd", 300),
+            (&bob.address(), "uusd", 200),
+        ],
+    );
+
+    // Only the intentional mismatch is reported
+    let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        assert_balances_map(
+            &runner,
+            &[
+                (&payer.address(), "uusd", 500),
+                (&alice.address(), "uusd", 300),
+                (&bob.address(), "uusd", 250),
+            ],
+        )
+    }))
+    .unwrap_err();
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert!(msg.contains("1 of 3 balances do not match"), "{}", msg);
+    assert!(msg.contains(&format!("{} has 200uusd, expected 250uusd", bob.address())), "{}", msg);
+    assert!(!msg.contains(&alice.address()), "{}", msg);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_balances_ignoring_fees() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let account = runner
+        .init_account(&[Coin::new(1000, DEFAULT_COIN_DENOM), Coin::new(1000, "uusd")])
+        .unwrap();
+
+    // Lower balances in the fee denom are tolerated, as long as they are within the tolerance
+    assert_balances_ignoring_fees(
+        &runner,
+        &account.address(),
+        &[Coin::new(1100, DEFAULT_COIN_DENOM), Coin::new(1000, "uusd")],
+        100,
+    );
+}
+
+#[test]
+#[should_panic(expected = "has 1000uusd, expected 1001uusd")]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_balances_ignoring_fees_exact_non_fee_denom() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let account = runner.init_account(&[Coin::new(1000, "uusd")]).unwrap();
+
+    assert_balances_ignoring_fees(&runner, &account.address(), &[Coin::new(1001, "uusd")], 100);
+}
+
+#[test]
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+fn test_assert_balances_match_golden() {
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    let account = runner
+        .init_account(&[Coin::new(1000, "uusd"), Coin::new(500, DEFAULT_COIN_DENOM)])
+        .unwrap();
+    let path = env::temp_dir()
+        .join(format!("cw-it-golden-{}", std::process::id()))
+        .join("balances.json");
+
+    // Write the golden file, then assert against it
+    assert_balances_match_golden_with(&runner, &account.address(), &path, true);
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        format!(
+            "[\n  {{\"denom\":\"{}\",\"amount\":\"500\"}},\n  {{\"denom\":\"uusd\",\"amount\":\"1000\"}}\n]\n",
+            DEFAULT_COIN_DENOM
+        )
+    );
+    assert_balances_match_golden_with(&runner, &account.address(), &path, false);
+
+    // Drift from the golden balances is caught
+    let other = runner.init_account(&[]).unwrap();
+    bank_send(&runner, &account, &other.address(), vec![Coin::new(1, "uusd")]).unwrap();
+    let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        assert_balances_match_golden_with(&runner, &account.address(), &path, false)
+    }))
+    .unwrap_err();
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert!(msg.contains("do not match golden file"), "{}", msg);
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
@@ -16,9 +16,14 @@ pub use osmosis_test_app::WhitelistForceUnlock;
 pub mod coreum_test_app;
 
 #[cfg(feature = "multi-test")]
-#[cfg(test)]
+#[cfg(any(test, all(feature = "test-helpers", not(feature = "coreum"))))]
 mod test_helpers;
 
+/// A counter contract fixture for tests using the multi-test runner.
+#[cfg(all(feature = "test-helpers", not(feature = "coreum")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-helpers")))]
+pub use test_helpers::counter::{deploy_test_counter, CounterClient};
+
 #[cfg(feature = "multi-test")]
 pub mod multi_test;
 
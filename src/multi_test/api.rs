@@ -22,6 +22,11 @@ impl<'a, T: bech32::Checksum> MockApiBech<'a, T> {
             _phantom_data: std::marker::PhantomData,
         }
     }
+
+    /// Returns the bech32 prefix of the addresses of this `Api`.
+    pub fn prefix(&self) -> &'a str {
+        self.prefix
+    }
 }
 
 impl<'a, T: bech32::Checksum> Api for MockApiBech<'a, T> {
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use cosmwasm_std::Event;
+
+/// A change of a single key in the storage of the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateChange {
+    pub key: Vec<u8>,
+    /// The value before the execution, `None` if the key was added.
+    pub before: Option<Vec<u8>>,
+    /// The value after the execution, `None` if the key was removed.
+    pub after: Option<Vec<u8>>,
+}
+
+/// A report of a message executed with [`super::MultiTestRunner::debug_execute`], containing
+/// everything that is useful to find out why an execution failed or did something unexpected.
+/// The [`Display`] implementation prints all of it in a readable form.
+#[derive(Debug, Clone, Default)]
+pub struct DebugReport {
+    pub type_url: String,
+    /// The pretty printed message.
+    pub msg: String,
+    pub sender: String,
+    /// All events emitted by the execution. Empty if the execution failed.
+    pub events: Vec<Event>,
+    /// All storage changes of the execution, in ascending order of the keys. Empty if the
+    /// execution failed, as the state is reverted then.
+    pub state_diff: Vec<StateChange>,
+    /// The error of the execution followed by all its causes. Empty if the execution succeeded.
+    pub error_chain: Vec<String>,
+}
+
+impl DebugReport {
+    /// Returns true if the execution failed.
+    pub fn failed(&self) -> bool {
+        !self.error_chain.is_empty()
+    }
+}
+
+impl Display for DebugReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = if self.failed() { "failed" } else { "succeeded" };
+        writeln!(f, "Execution of {} sent by {} {}", self.type_url, self.sender, status)?;
+        writeln!(f, "message: {}", self.msg)?;
+
+        if self.failed() {
+            writeln!(f, "error chain:")?;
+            for (i, cause) in self.error_chain.iter().enumerate() {
+                writeln!(f, "  {}: {}", i, cause)?;
+            }
+        }
+
+        writeln!(f, "events:")?;
+        for event in &self.events {
+            let attributes = event
+                .attributes
+                .iter()
+                .map(|a| format!("{}={}", a.key, a.value))
+                .collect::<Vec<_>>();
+            writeln!(f, "  {} {}", event.ty, attributes.join(" "))?;
+        }
+
+        writeln!(f, "state diff:")?;
+        for change in &self.state_diff {
+            let value = |value: &Option<Vec<u8>>| match value {
+                Some(value) => value.escape_ascii().to_string(),
+                None => "<none>".to_string(),
+            };
+            writeln!(
+                f,
+                "  {}: {} -> {}",
+                change.key.escape_ascii(),
+                value(&change.before),
+                value(&change.after)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the changes between two copies of the storage of the app.
+pub(crate) fn diff_storage(before: &BTreeMap<Vec<u8>, Vec<u8>>, after: &BTreeMap<Vec<u8>, Vec<u8>>) -> Vec<StateChange> {
+    let mut keys = before.keys().chain(after.keys()).collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| StateChange {
+            key: key.clone(),
+            before: before.get(key).cloned(),
+            after: after.get(key).cloned(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_storage_changes() {
+        let before = BTreeMap::from([(b"removed".to_vec(), b"1".to_vec()), (b"updated".to_vec(), b"1".to_vec())]);
+        let after = BTreeMap::from([(b"added".to_vec(), b"2".to_vec()), (b"updated".to_vec(), b"2".to_vec())]);
+
+        let changes = diff_storage(&before, &after);
+        assert_eq!(
+            changes,
+            vec![
+                StateChange {
+                    key: b"added".to_vec(),
+                    before: None,
+                    after: Some(b"2".to_vec()),
+                },
+                StateChange {
+                    key: b"removed".to_vec(),
+                    before: Some(b"1".to_vec()),
+                    after: None,
+                },
+                StateChange {
+                    key: b"updated".to_vec(),
+                    before: Some(b"1".to_vec()),
+                    after: Some(b"2".to_vec()),
+                },
+            ]
+        );
+    }
+}
@@ -98,9 +98,109 @@ macro_rules! create_contract_wrappers_with_reply {
     }};
 }
 
+/// Like [`create_contract_wrappers`], but additionally attaches the `migrate` entry point of
+/// each contract. Shorthand for `create_contract_wrappers_full!` with `=> [migrate]`.
+#[macro_export]
+macro_rules! create_contract_wrappers_with_migrate {
+    ( $( $name:expr ),* ) => {
+        $crate::create_contract_wrappers_full!( $( $name => [migrate] ),* )
+    };
+}
+
+/// Like [`create_contract_wrappers`], but additionally attaches the `reply`, `sudo` and `migrate`
+/// entry points listed after each contract, e.g.
+/// `create_contract_wrappers_full!("astroport_factory" => [reply, migrate], "astroport_pair")`.
+#[cfg(not(feature = "coreum"))]
+#[macro_export]
+macro_rules! create_contract_wrappers_full {
+    ( $( $name:expr $( => [ $( $entry:ident ),* ] )? ),* ) => {{
+        use std::collections::HashMap;
+        use cw_multi_test::{ContractWrapper, Contract};
+        use cosmwasm_std::Empty;
+        vec![
+            $(
+                {
+
+                    paste::paste! {
+                      use[<$name>]::contract::{execute, instantiate, query $( $( , $entry )* )?};
+                    }
+                    let wrapper = ContractWrapper::new_with_empty(
+                        execute,
+                        instantiate,
+                        query,
+                    );
+                    $( $( let wrapper = paste::paste! { wrapper.[<with_ $entry>]($entry) }; )* )?
+                    ($name.to_string(), Box::new(wrapper) as Box<dyn Contract<Empty, Empty>>)
+                }
+            ),*
+        ].into_iter().collect::<HashMap<String,Box<dyn Contract<Empty, Empty>>>>()
+    }};
+}
+
+/// Like [`create_contract_wrappers`], but additionally attaches the `reply`, `sudo` and `migrate`
+/// entry points listed after each contract, e.g.
+/// `create_contract_wrappers_full!("astroport_factory" => [reply, migrate], "astroport_pair")`.
+#[cfg(feature = "coreum")]
+#[macro_export]
+macro_rules! create_contract_wrappers_full {
+    ( $( $name:expr $( => [ $( $entry:ident ),* ] )? ),* ) => {{
+        use std::collections::HashMap;
+        use cw_multi_test::{ContractWrapper, Contract};
+        use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries};
+        vec![
+            $(
+                {
+
+                    paste::paste! {
+                      use[<$name>]::contract::{execute, instantiate, query $( $( , $entry )* )?};
+                    }
+                    let wrapper = ContractWrapper::<_, _, _, _, _, _, CoreumMsg, CoreumQueries>::new_with_empty(
+                        execute,
+                        instantiate,
+                        query,
+                    );
+                    $( $( let wrapper = paste::paste! { wrapper.[<with_ $entry _empty>]($entry) }; )* )?
+                    ($name.to_string(), Box::new(wrapper) as Box<dyn Contract<CoreumMsg, CoreumQueries>>)
+                }
+            ),*
+        ].into_iter().collect::<HashMap<String,Box<dyn Contract<CoreumMsg, CoreumQueries>>>>()
+    }};
+}
+
 #[cfg(feature = "astroport")]
 #[cfg(test)]
 mod tests {
+    /// A contract exposing all entry points [`create_contract_wrappers_full`] can attach.
+    mod full_contract {
+        pub mod contract {
+            use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult};
+
+            pub fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+                Ok(Response::new())
+            }
+
+            pub fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+                Ok(Response::new())
+            }
+
+            pub fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+                Ok(Binary::default())
+            }
+
+            pub fn reply(_deps: DepsMut, _env: Env, _msg: Reply) -> StdResult<Response> {
+                Ok(Response::new())
+            }
+
+            pub fn sudo(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+                Ok(Response::new().add_attribute("action", "sudo"))
+            }
+
+            pub fn migrate(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+                Ok(Response::new().add_attribute("action", "migrate"))
+            }
+        }
+    }
+
     #[test]
     fn test_create_contract_wrappers_macro() {
         let contract_wrappers =
@@ -116,4 +216,102 @@ mod tests {
 
         assert_eq!(contract_wrappers.len(), 2);
     }
+
+    #[test]
+    fn test_create_contract_wrappers_full_macro() {
+        use cosmwasm_std::testing::{mock_dependencies, mock_env};
+        use cosmwasm_std::{to_json_vec, Empty};
+
+        let contract_wrappers = create_contract_wrappers_full!(
+            "full_contract" => [reply, sudo, migrate],
+            "astroport_factory"
+        );
+        assert_eq!(contract_wrappers.len(), 2);
+
+        // The attached entry points are called instead of erroring as missing
+        let contract = &contract_wrappers["full_contract"];
+        let mut deps = mock_dependencies();
+        let msg = to_json_vec(&Empty {}).unwrap();
+        let res = contract.sudo(deps.as_mut(), mock_env(), msg.clone()).unwrap();
+        assert_eq!(res.attributes[0].value, "sudo");
+        let res = contract.migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(res.attributes[0].value, "migrate");
+    }
+}
+
+#[cfg(not(feature = "coreum"))]
+#[cfg(test)]
+mod migrate_tests {
+    use cosmwasm_std::{to_json_binary, Addr, Empty, WasmMsg};
+    use cw_multi_test::{App, Executor};
+
+    /// A contract storing a version, which is bumped on every migration.
+    mod migratable {
+        pub mod contract {
+            use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult};
+            use cw_storage_plus::Item;
+
+            const VERSION: Item<u32> = Item::new("version");
+
+            pub fn instantiate(deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+                VERSION.save(deps.storage, &1)?;
+                Ok(Response::new())
+            }
+
+            pub fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+                Ok(Response::new())
+            }
+
+            pub fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+                to_json_binary(&VERSION.load(deps.storage)?)
+            }
+
+            pub fn reply(_deps: DepsMut, _env: Env, _msg: cosmwasm_std::Reply) -> StdResult<Response> {
+                Ok(Response::new())
+            }
+
+            pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+                VERSION.update(deps.storage, |version| -> StdResult<_> { Ok(version + 1) })?;
+                Ok(Response::new())
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_contract_wrappers_with_migrate_macro() {
+        let mut contract_wrappers = create_contract_wrappers_with_migrate!("migratable");
+        // The maps of the different variants can be combined
+        contract_wrappers.extend(
+            create_contract_wrappers_with_reply!("migratable")
+                .into_iter()
+                .map(|(name, contract)| (format!("{}_with_reply", name), contract)),
+        );
+        assert_eq!(contract_wrappers.len(), 2);
+
+        let mut app = App::default();
+        let admin = Addr::unchecked("admin");
+        let code_id = app.store_code(contract_wrappers.remove("migratable").unwrap());
+        let contract = app
+            .instantiate_contract(code_id, admin.clone(), &Empty {}, &[], "migratable", Some(admin.to_string()))
+            .unwrap();
+
+        app.migrate_contract(admin.clone(), contract.clone(), &Empty {}, code_id).unwrap();
+        let version: u32 = app.wrap().query_wasm_smart(&contract, &Empty {}).unwrap();
+        assert_eq!(version, 2);
+
+        // Without a migrate entry point the migration fails
+        let code_id = app.store_code(contract_wrappers.remove("migratable_with_reply").unwrap());
+        let err = app
+            .execute(
+                admin,
+                WasmMsg::Migrate {
+                    contract_addr: contract.to_string(),
+                    new_code_id: code_id,
+                    msg: to_json_binary(&Empty {}).unwrap(),
+                }
+                .into(),
+            )
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("migrate"), "{:#}", err);
+    }
 }
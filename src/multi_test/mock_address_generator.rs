@@ -1,17 +1,201 @@
 use anyhow::Result as AnyResult;
-use cosmwasm_std::{Addr, Api, StdError, Storage};
+use bech32::{Bech32, Hrp};
+use cosmwasm_std::{Addr, Api, CanonicalAddr, StdError, Storage};
 use cw_multi_test::AddressGenerator;
+use sha2::{Digest, Sha256};
 
-#[derive(Clone)]
+/// An [`AddressGenerator`] for multi-test apps generating the legacy, human readable
+/// `contract_<code_id>_<instance_id>` addresses. See [`Bech32AddressGenerator`] for proper bech32
+/// addresses.
+#[derive(Clone, Default)]
 pub struct MockAddressGenerator;
 
+impl MockAddressGenerator {
+    /// Returns the address a contract instantiated with `instantiate2` will get, derived from the
+    /// `code_id`, the canonical `creator` address and the `salt`. The result is stable across runs.
+    pub fn predict_contract_address(code_id: u64, creator: &[u8], salt: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(code_id.to_be_bytes());
+        hasher.update(creator);
+        hasher.update(salt);
+        let hash = hasher.finalize();
+
+        // Only use the first 16 bytes to keep the address within the length limits of the mock api
+        let hex = hash[..16].iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        format!("contract_{}", hex)
+    }
+
+    /// Returns `raw` as address if `api` accepts it. Apis enforcing bech32 addresses, like the
+    /// [`super::api::MockApiBech32`] of the [`super::MultiTestRunner`], reject it, so the address
+    /// is derived from the sha256 hash of `raw` and humanized by the `api` instead. This way the
+    /// address uses the prefix of the `api` and is still stable across runs.
+    fn to_addr(api: &dyn Api, raw: String) -> AnyResult<Addr> {
+        if api.addr_validate(&raw).is_ok() {
+            return Ok(Addr::unchecked(raw));
+        }
+
+        let canonical = CanonicalAddr::from(Sha256::digest(raw.as_bytes()).to_vec());
+        let addr = api
+            .addr_humanize(&canonical)
+            .map_err(|e| StdError::generic_err(format!("invalid generated addr: {}", e)))?;
+        Ok(addr)
+    }
+}
+
 impl AddressGenerator for MockAddressGenerator {
     fn contract_address(&self, api: &dyn Api, _storage: &mut dyn Storage, code_id: u64, instance_id: u64) -> AnyResult<Addr> {
         // Same basic pattern the old generator used:
         let raw = format!("contract_{}_{}", code_id, instance_id);
-        api.addr_validate(&raw)
+        Self::to_addr(api, raw)
+    }
+
+    fn predictable_contract_address(
+        &self,
+        api: &dyn Api,
+        _storage: &mut dyn Storage,
+        code_id: u64,
+        _instance_id: u64,
+        _checksum: &[u8],
+        creator: &CanonicalAddr,
+        salt: &[u8],
+    ) -> AnyResult<Addr> {
+        let raw = Self::predict_contract_address(code_id, creator.as_slice(), salt);
+        Self::to_addr(api, raw)
+    }
+}
+
+/// An [`AddressGenerator`] for multi-test apps generating proper bech32 addresses, which also
+/// validate on chains enforcing bech32 addresses. Used by the [`super::MultiTestRunner`].
+#[derive(Clone)]
+pub struct Bech32AddressGenerator {
+    /// The human readable part of the generated addresses.
+    hrp: String,
+}
+
+impl Bech32AddressGenerator {
+    /// Creates a generator for bech32 addresses with the human readable part `hrp`.
+    pub fn new(hrp: &str) -> Self {
+        Self { hrp: hrp.to_string() }
+    }
+
+    /// Returns the bech32 address with the human readable part `hrp` of the contract with the
+    /// given `code_id` and `instance_id`. It is derived from the sha256 hash of both ids, so it
+    /// is stable across runs.
+    pub fn bech32_contract_address(hrp: &str, code_id: u64, instance_id: u64) -> AnyResult<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(code_id.to_be_bytes());
+        hasher.update(instance_id.to_be_bytes());
+
+        Ok(bech32::encode::<Bech32>(Hrp::parse(hrp)?, hasher.finalize().as_slice())?)
+    }
+}
+
+impl AddressGenerator for Bech32AddressGenerator {
+    fn contract_address(&self, api: &dyn Api, _storage: &mut dyn Storage, code_id: u64, instance_id: u64) -> AnyResult<Addr> {
+        let addr = Self::bech32_contract_address(&self.hrp, code_id, instance_id)?;
+        api.addr_validate(&addr)
             .map_err(|e| StdError::generic_err(format!("invalid generated addr: {}", e)))?;
+        Ok(Addr::unchecked(addr))
+    }
+
+    fn predictable_contract_address(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        code_id: u64,
+        instance_id: u64,
+        checksum: &[u8],
+        creator: &CanonicalAddr,
+        salt: &[u8],
+    ) -> AnyResult<Addr> {
+        MockAddressGenerator.predictable_contract_address(api, storage, code_id, instance_id, checksum, creator, salt)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "coreum"))]
+mod tests {
+    use bech32::primitives::decode::CheckedHrpstring;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{to_json_binary, CosmosMsg, Empty, WasmMsg};
+    use cw_multi_test::{BasicAppBuilder, Executor, WasmKeeper};
+
+    use crate::multi_test::api::MockApiBech32;
+    use crate::test_helpers::counter;
+
+    use super::*;
+
+    #[test]
+    fn instantiate2_uses_predictable_address() {
+        let wasm_keeper: WasmKeeper<Empty, Empty> = WasmKeeper::new().with_address_generator(MockAddressGenerator::default());
+        let mut app = BasicAppBuilder::<Empty, Empty>::new().with_wasm(wasm_keeper).build(|_, _, _| {});
+
+        let creator = Addr::unchecked("creator");
+        let code_id = app.store_code(counter::contract());
+        let salt = b"salty".to_vec();
+
+        let res = app
+            .execute(
+                creator.clone(),
+                CosmosMsg::Wasm(WasmMsg::Instantiate2 {
+                    admin: None,
+                    code_id,
+                    label: "counter".to_string(),
+                    msg: to_json_binary(&counter::InstantiateMsg { count: 0 }).unwrap(),
+                    funds: vec![],
+                    salt: salt.clone().into(),
+                }),
+            )
+            .unwrap();
+        let contract_addr = res
+            .events
+            .iter()
+            .find(|e| e.ty == "instantiate")
+            .and_then(|e| e.attributes.iter().find(|a| a.key == "_contract_address"))
+            .unwrap()
+            .value
+            .clone();
+
+        // Recompute the address independently of the generator
+        let canonical_creator = app.api().addr_canonicalize(creator.as_str()).unwrap();
+        let mut hasher = Sha256::new();
+        hasher.update(code_id.to_be_bytes());
+        hasher.update(canonical_creator.as_slice());
+        hasher.update(&salt);
+        let hex = hasher.finalize()[..16].iter().map(|b| format!("{:02x}", b)).collect::<String>();
+
+        assert_eq!(contract_addr, format!("contract_{}", hex));
+        assert_eq!(
+            contract_addr,
+            MockAddressGenerator::predict_contract_address(code_id, canonical_creator.as_slice(), &salt)
+        );
+    }
+
+    #[test]
+    fn bech32_contract_address() {
+        let api = MockApiBech32::new("osmo");
+        let mut storage = MockStorage::new();
+        let generator = Bech32AddressGenerator::new("osmo");
+
+        let first = generator.contract_address(&api, &mut storage, 1, 1).unwrap();
+        let second = generator.contract_address(&api, &mut storage, 1, 2).unwrap();
+
+        // The addresses only depend on the ids, so they are the same in every run
+        assert_eq!(first, "osmo12vk740ugw2wtgwv44ddfe4ymlxus5pueqnwqv30vm20y0nnngk5sml8rvn");
+        assert_eq!(second, "osmo133m9fm8a0v9kywuq8ch5uq4drnyyy780mlxhcnyjprkas8chuy2sc3xk2f");
+        for addr in [first, second] {
+            let decoded = CheckedHrpstring::new::<Bech32>(addr.as_str()).unwrap();
+            assert_eq!(decoded.hrp().to_string(), "osmo");
+            assert_eq!(decoded.byte_iter().count(), 32);
+        }
+    }
+
+    #[test]
+    fn legacy_contract_address() {
+        let api = cosmwasm_std::testing::MockApi::default();
+        let mut storage = MockStorage::new();
 
-        Ok(Addr::unchecked(raw))
+        let addr = MockAddressGenerator.contract_address(&api, &mut storage, 1, 2).unwrap();
+        assert_eq!(addr, "contract_1_2");
     }
 }
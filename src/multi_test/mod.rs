@@ -1,3 +1,4 @@
+mod debug;
 /// Helper macros to create multi test contract wrappers. For a crate with a
 /// `contract` module containing the entry point functions.
 pub mod macros;
@@ -6,9 +7,11 @@ pub mod macros;
 /// cosmos-sdk modules.
 pub mod modules;
 mod runner;
+mod storage_cache;
 
 pub mod mock_address_generator;
 pub mod api;
 
 pub use crate::create_contract_wrappers;
-pub use runner::MultiTestRunner;
+pub use debug::{DebugReport, StateChange};
+pub use runner::{MultiTestRunner, Snapshot};
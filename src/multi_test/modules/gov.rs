@@ -0,0 +1,331 @@
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, Api, Binary, BlockInfo, Empty, Event, Order, Querier, Storage};
+use cw_multi_test::{AppResponse, CosmosRouter, Module, Stargate, StargateMsg, StargateQuery};
+use cw_storage_plus::{Item, Map};
+use osmosis_std::types::cosmos::gov::v1beta1::{
+    MsgSubmitProposal, MsgSubmitProposalResponse, MsgVote, MsgVoteResponse, Proposal, ProposalStatus, QueryProposalRequest,
+    QueryProposalResponse, TallyResult, VoteOption,
+};
+use prost::Message;
+
+use crate::multi_test::modules::QUERY_GOV_PROPOSAL_PATH;
+
+/// Map of **proposal id -> proposal** for every proposal submitted through the [`Gov`] module.
+pub const PROPOSALS: Map<u64, GovProposal> = Map::new("gov/proposals");
+
+/// Map of **(proposal id, voter) -> vote option** for every vote cast through the [`Gov`] module.
+pub const VOTES: Map<(u64, &str), i32> = Map::new("gov/votes");
+
+/// The id of the last submitted proposal.
+const LAST_PROPOSAL_ID: Item<u64> = Item::new("gov/last_proposal_id");
+
+/// A proposal as stored by the [`Gov`] module.
+#[cw_serde]
+pub struct GovProposal {
+    pub proposer: String,
+    /// The type url of the proposal content, e.g. `/cosmos.gov.v1beta1.TextProposal`.
+    pub content_type_url: String,
+    /// The proto encoded proposal content.
+    pub content: Binary,
+    /// The [`ProposalStatus`] of the proposal.
+    pub status: i32,
+    pub tally: Option<ProposalTally>,
+}
+
+/// The number of votes cast per option when a proposal was tallied.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposalTally {
+    pub yes: u64,
+    pub abstain: u64,
+    pub no: u64,
+    pub no_with_veto: u64,
+}
+
+/// Tallies the votes of all proposals in their voting period. A proposal passes if it got more
+/// yes votes than no and no with veto votes combined, and is rejected otherwise. Every voter has
+/// the same weight, as there is no voting power in multi-test.
+///
+/// This is also what the `sudo` entry point of the [`Gov`] module does, which
+/// [`crate::multi_test::MultiTestRunner::sudo_stargate`] runs to end the voting period.
+pub fn tally_proposals(storage: &mut dyn Storage) -> AnyResult<()> {
+    let proposals = PROPOSALS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, mut proposal) in proposals {
+        if proposal.status != ProposalStatus::VotingPeriod as i32 {
+            continue;
+        }
+
+        let mut tally = ProposalTally::default();
+        for vote in VOTES.prefix(id).range(storage, None, None, Order::Ascending) {
+            let (_, option) = vote?;
+            match VoteOption::try_from(option) {
+                Ok(VoteOption::Yes) => tally.yes += 1,
+                Ok(VoteOption::Abstain) => tally.abstain += 1,
+                Ok(VoteOption::No) => tally.no += 1,
+                Ok(VoteOption::NoWithVeto) => tally.no_with_veto += 1,
+                _ => bail!("Invalid vote option {}", option),
+            }
+        }
+
+        proposal.status = if tally.yes > tally.no + tally.no_with_veto {
+            ProposalStatus::Passed as i32
+        } else {
+            ProposalStatus::Rejected as i32
+        };
+        proposal.tally = Some(tally);
+        PROPOSALS.save(storage, id, &proposal)?;
+    }
+
+    Ok(())
+}
+
+/// This is a struct that implements the [`cw_multi_test::Stargate`] trait to mimic the behavior
+/// of the cosmos-sdk gov module, so contracts submitting or querying proposals can be tested. It
+/// can be used as the extra module of [`super::unified_stargate::UnifiedStargate`].
+///
+/// Submitted proposals immediately enter their voting period and stay there until the votes are
+/// tallied with [`tally_proposals`], e.g. through the `sudo` entry point of the module. Deposits
+/// are not escrowed and proposal contents are never executed.
+#[derive(Clone, Default)]
+pub struct Gov;
+
+impl Gov {
+    fn submit_proposal(&self, storage: &mut dyn Storage, sender: Addr, value: Binary) -> AnyResult<AppResponse> {
+        let msg: MsgSubmitProposal = value.try_into()?;
+
+        if msg.proposer != sender.to_string() {
+            bail!("Invalid proposer. Proposer in msg must be same as sender of transaction.");
+        }
+        let content = msg.content.ok_or_else(|| anyhow!("missing proposal content"))?;
+
+        let proposal_id = LAST_PROPOSAL_ID.may_load(storage)?.unwrap_or_default() + 1;
+        LAST_PROPOSAL_ID.save(storage, &proposal_id)?;
+        PROPOSALS.save(
+            storage,
+            proposal_id,
+            &GovProposal {
+                proposer: msg.proposer.clone(),
+                content_type_url: content.type_url,
+                content: content.value.into(),
+                status: ProposalStatus::VotingPeriod as i32,
+                tally: None,
+            },
+        )?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("submit_proposal")
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("proposer", msg.proposer),
+        );
+        res.data = Some(MsgSubmitProposalResponse { proposal_id }.into());
+
+        Ok(res)
+    }
+
+    fn vote(&self, storage: &mut dyn Storage, sender: Addr, value: Binary) -> AnyResult<AppResponse> {
+        let msg: MsgVote = value.try_into()?;
+
+        if msg.voter != sender.to_string() {
+            bail!("Invalid voter. Voter in msg must be same as sender of transaction.");
+        }
+        match VoteOption::try_from(msg.option) {
+            Ok(VoteOption::Unspecified) | Err(_) => bail!("Invalid vote option {}", msg.option),
+            Ok(_) => {}
+        }
+
+        let Some(proposal) = PROPOSALS.may_load(storage, msg.proposal_id)? else {
+            bail!("Proposal {} does not exist", msg.proposal_id);
+        };
+        if proposal.status != ProposalStatus::VotingPeriod as i32 {
+            bail!("Proposal {} is not in its voting period", msg.proposal_id);
+        }
+
+        VOTES.save(storage, (msg.proposal_id, &msg.voter), &msg.option)?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("proposal_vote")
+                .add_attribute("proposal_id", msg.proposal_id.to_string())
+                .add_attribute("voter", msg.voter)
+                .add_attribute("option", msg.option.to_string()),
+        );
+        res.data = Some(MsgVoteResponse {}.into());
+
+        Ok(res)
+    }
+}
+
+impl Module for Gov {
+    type ExecT = StargateMsg;
+    type QueryT = StargateQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let StargateMsg { type_url, value, .. } = msg;
+
+        match type_url.as_str() {
+            MsgSubmitProposal::TYPE_URL => self.submit_proposal(storage, sender, value),
+            MsgVote::TYPE_URL => self.vote(storage, sender, value),
+            _ => bail!("Unknown message type {}", type_url),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request.path.as_str() {
+            QUERY_GOV_PROPOSAL_PATH => {
+                let req = QueryProposalRequest::decode(request.data.as_slice())?;
+                let Some(proposal) = PROPOSALS.may_load(storage, req.proposal_id)? else {
+                    bail!("Proposal {} does not exist", req.proposal_id);
+                };
+
+                // The content is left out, as `Any` can only be deserialized from JSON for known types
+                let resp = QueryProposalResponse {
+                    proposal: Some(Proposal {
+                        proposal_id: req.proposal_id,
+                        content: None,
+                        status: proposal.status,
+                        final_tally_result: proposal.tally.map(|tally| TallyResult {
+                            yes: tally.yes.to_string(),
+                            abstain: tally.abstain.to_string(),
+                            no: tally.no.to_string(),
+                            no_with_veto: tally.no_with_veto.to_string(),
+                        }),
+                        ..Default::default()
+                    }),
+                };
+
+                Ok(to_json_binary(&resp)?)
+            }
+            _ => Err(anyhow!("Unexpected stargate query: path={}, data={:?}", request.path, request.data)),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        tally_proposals(storage)?;
+        Ok(AppResponse::default())
+    }
+}
+
+impl Stargate for Gov {}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::QueryRequest;
+    use osmosis_std::shim::Any;
+    use osmosis_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use osmosis_std::types::cosmos::gov::v1beta1::TextProposal;
+    use test_tube::{Account, Runner};
+
+    use super::*;
+    use crate::multi_test::modules::QUERY_BALANCE_PATH;
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::{CwItRunner, DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    #[test]
+    fn submit_vote_and_tally() {
+        let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, Gov);
+        let proposer = runner.init_default_account().unwrap();
+        let voter = runner.init_default_account().unwrap();
+
+        let res = runner
+            .execute::<_, MsgSubmitProposalResponse>(
+                MsgSubmitProposal {
+                    content: Some(Any {
+                        type_url: TextProposal::TYPE_URL.to_string(),
+                        value: TextProposal {
+                            title: "Title".to_string(),
+                            description: "Description".to_string(),
+                        }
+                        .encode_to_vec(),
+                    }),
+                    initial_deposit: vec![],
+                    proposer: proposer.address(),
+                },
+                MsgSubmitProposal::TYPE_URL,
+                &proposer,
+            )
+            .unwrap();
+        let proposal_id = res.data.proposal_id;
+        assert_eq!(proposal_id, 1);
+
+        runner
+            .execute::<_, MsgVoteResponse>(
+                MsgVote {
+                    proposal_id,
+                    voter: voter.address(),
+                    option: VoteOption::Yes as i32,
+                },
+                MsgVote::TYPE_URL,
+                &voter,
+            )
+            .unwrap();
+
+        let query_proposal = || {
+            runner
+                .query::<_, QueryProposalResponse>(QUERY_GOV_PROPOSAL_PATH, &QueryProposalRequest { proposal_id })
+                .unwrap()
+                .proposal
+                .unwrap()
+        };
+        assert_eq!(query_proposal().status, ProposalStatus::VotingPeriod as i32);
+
+        // The UnifiedStargate still answers the bank queries not handled by the Gov module
+        let balance: QueryBalanceResponse = runner
+            .app
+            .borrow()
+            .wrap()
+            .query(&QueryRequest::Stargate {
+                path: QUERY_BALANCE_PATH.to_string(),
+                data: QueryBalanceRequest {
+                    address: voter.address(),
+                    denom: DEFAULT_COIN_DENOM.to_string(),
+                }
+                .encode_to_vec()
+                .into(),
+            })
+            .unwrap();
+        assert_eq!(balance.balance.unwrap().denom, DEFAULT_COIN_DENOM);
+
+        // The sudo entry point of the UnifiedStargate is passed on to the Gov module
+        runner.sudo_stargate().unwrap();
+
+        let proposal = query_proposal();
+        assert_eq!(proposal.status, ProposalStatus::Passed as i32);
+        assert_eq!(proposal.final_tally_result.unwrap().yes, "1");
+    }
+}
@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_string, Addr, Api, BankMsg, Binary, BlockInfo, CanonicalAddr, Coin, Empty, Event, Order, Querier, Storage, Uint128,
+};
+use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module, Stargate, StargateMsg, StargateQuery};
+use cw_storage_plus::{Item, Map};
+use osmosis_std::types::ibc::applications::transfer::v1::{MsgTransfer, MsgTransferResponse};
+use sha2::{Digest, Sha256};
+
+use crate::multi_test::modules::unified_stargate::{track_minted_denoms, SEND_ENABLED};
+
+/// The port all transfers are sent from and delivered to.
+pub const TRANSFER_PORT: &str = "transfer";
+
+/// The version of the ICS-20 fungible token transfer protocol, which the escrow addresses are
+/// derived from.
+const ICS20_VERSION: &str = "ics20-1";
+
+/// Map of **sequence -> packet** for every transfer that has been sent but not delivered yet.
+pub const PENDING_PACKETS: Map<u64, TransferPacket> = Map::new("ibc_transfer/pending_packets");
+
+/// The sequence of the last sent packet.
+const LAST_SEQUENCE: Item<u64> = Item::new("ibc_transfer/last_sequence");
+
+/// A sent transfer packet as stored by the [`IbcTransfer`] module. The fields mirror the
+/// `FungibleTokenPacketData` of ibc-go, plus the channels the packet travels through.
+#[cw_serde]
+pub struct TransferPacket {
+    pub source_channel: String,
+    pub destination_channel: String,
+    pub denom: String,
+    pub amount: Uint128,
+    pub sender: String,
+    pub receiver: String,
+    pub memo: String,
+}
+
+/// Returns the ibc voucher denom, i.e. `ibc/{hash}`, that `base_denom` gets when it is received
+/// through `port` and `channel`.
+pub fn ibc_denom(port: &str, channel: &str, base_denom: &str) -> String {
+    let hash = Sha256::digest(format!("{}/{}/{}", port, channel, base_denom).as_bytes());
+    format!("ibc/{}", hash.iter().map(|b| format!("{:02X}", b)).collect::<String>())
+}
+
+/// Returns the address of the account escrowing the coins sent through `port` and `channel`. As
+/// in ibc-go, these are the first 20 bytes of the SHA-256 hash of the ICS-20 version, a zero byte
+/// and `{port}/{channel}`.
+pub fn escrow_address(api: &dyn Api, port: &str, channel: &str) -> AnyResult<Addr> {
+    let mut hasher = Sha256::new();
+    hasher.update(ICS20_VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(format!("{}/{}", port, channel).as_bytes());
+    Ok(api.addr_humanize(&CanonicalAddr::from(&hasher.finalize()[..20]))?)
+}
+
+/// This is a struct that implements the [`cw_multi_test::Stargate`] trait to mimic the behavior
+/// of the ibc-go transfer module, so contracts sending `MsgTransfer` messages can be tested.
+///
+/// Sent coins are moved to the [`escrow_address`] of their source channel and the packet is stored
+/// until it is delivered by the `sudo` entry point of the module, e.g. through
+/// [`crate::multi_test::MultiTestRunner::sudo_stargate`]. Delivering simulates the counterparty
+/// chain on the same app: the receiver is credited the [`ibc_denom`] voucher of the sent coins on
+/// the counterparty channel. Acknowledgements, timeouts and unwinding of vouchers back to their
+/// source are not supported.
+#[derive(Clone)]
+pub struct IbcTransfer {
+    /// Map of **source channel -> counterparty channel** of every open channel.
+    pub channels: HashMap<String, String>,
+}
+
+impl IbcTransfer {
+    /// Creates a new IbcTransfer module without any open channels.
+    pub fn new() -> Self {
+        Self { channels: HashMap::new() }
+    }
+
+    /// Opens a channel from `source_channel` to `counterparty_channel`.
+    pub fn with_channel(mut self, source_channel: &str, counterparty_channel: &str) -> Self {
+        self.channels.insert(source_channel.to_string(), counterparty_channel.to_string());
+        self
+    }
+}
+
+impl Default for IbcTransfer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IbcTransfer {
+    fn transfer<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let msg: MsgTransfer = value.try_into()?;
+
+        if msg.sender != sender.to_string() {
+            bail!("Invalid sender. Sender in msg must be same as sender of transaction.");
+        }
+        if msg.source_port != TRANSFER_PORT {
+            bail!("Invalid source port {}, expected {}", msg.source_port, TRANSFER_PORT);
+        }
+        let Some(destination_channel) = self.channels.get(&msg.source_channel) else {
+            bail!(
+                "channel not found: port ID ({}) channel ID ({})",
+                msg.source_port,
+                msg.source_channel
+            );
+        };
+        if msg.receiver.is_empty() {
+            bail!("missing recipient address");
+        }
+        let token = msg.token.ok_or_else(|| anyhow!("missing token"))?;
+        let amount = Coin {
+            amount: Uint128::from_str(&token.amount)?,
+            denom: token.denom,
+        };
+        if amount.amount.is_zero() {
+            bail!("Invalid zero amount");
+        }
+        if SEND_ENABLED.may_load(storage, &amount.denom)? == Some(false) {
+            bail!("{} transfers are currently disabled", amount.denom);
+        }
+
+        let escrow = escrow_address(api, TRANSFER_PORT, &msg.source_channel)?;
+        let send_msg = BankMsg::Send {
+            to_address: escrow.to_string(),
+            amount: vec![amount.clone()],
+        };
+        router.execute(api, storage, block, sender, send_msg.into())?;
+
+        let sequence = LAST_SEQUENCE.may_load(storage)?.unwrap_or_default() + 1;
+        LAST_SEQUENCE.save(storage, &sequence)?;
+        let packet = TransferPacket {
+            source_channel: msg.source_channel,
+            destination_channel: destination_channel.clone(),
+            denom: amount.denom.clone(),
+            amount: amount.amount,
+            sender: msg.sender,
+            receiver: msg.receiver,
+            memo: msg.memo,
+        };
+        PENDING_PACKETS.save(storage, sequence, &packet)?;
+
+        let packet_data = to_json_string(&FungibleTokenPacketData::from(&packet))?;
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("send_packet")
+                .add_attribute("packet_data", packet_data)
+                .add_attribute(
+                    "packet_timeout_height",
+                    msg.timeout_height
+                        .map(|height| format!("{}-{}", height.revision_number, height.revision_height))
+                        .unwrap_or_else(|| "0-0".to_string()),
+                )
+                .add_attribute("packet_timeout_timestamp", msg.timeout_timestamp.to_string())
+                .add_attribute("packet_sequence", sequence.to_string())
+                .add_attribute("packet_src_port", TRANSFER_PORT)
+                .add_attribute("packet_src_channel", &packet.source_channel)
+                .add_attribute("packet_dst_port", TRANSFER_PORT)
+                .add_attribute("packet_dst_channel", &packet.destination_channel)
+                .add_attribute("packet_channel_ordering", "ORDER_UNORDERED"),
+        );
+        res.events.push(
+            Event::new("ibc_transfer")
+                .add_attribute("sender", &packet.sender)
+                .add_attribute("receiver", &packet.receiver)
+                .add_attribute("amount", amount.amount.to_string())
+                .add_attribute("denom", amount.denom)
+                .add_attribute("memo", packet.memo),
+        );
+        res.data = Some(MsgTransferResponse { sequence }.into());
+
+        Ok(res)
+    }
+
+    /// Delivers all pending packets, crediting each receiver the voucher of the sent coins.
+    fn deliver_packets<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let packets = PENDING_PACKETS
+            .range(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut res = AppResponse::default();
+        for (sequence, packet) in packets {
+            PENDING_PACKETS.remove(storage, sequence);
+
+            let voucher = Coin {
+                denom: ibc_denom(TRANSFER_PORT, &packet.destination_channel, &packet.denom),
+                amount: packet.amount,
+            };
+            let mint_msg = BankSudo::Mint {
+                to_address: packet.receiver.clone(),
+                amount: vec![voucher.clone()],
+            };
+            router.sudo(api, storage, block, mint_msg.into())?;
+            track_minted_denoms(storage, &[voucher.clone()])?;
+
+            res.events.push(
+                Event::new("recv_packet")
+                    .add_attribute("packet_sequence", sequence.to_string())
+                    .add_attribute("packet_src_port", TRANSFER_PORT)
+                    .add_attribute("packet_src_channel", packet.source_channel)
+                    .add_attribute("packet_dst_port", TRANSFER_PORT)
+                    .add_attribute("packet_dst_channel", packet.destination_channel),
+            );
+            res.events.push(
+                Event::new("fungible_token_packet")
+                    .add_attribute("receiver", packet.receiver)
+                    .add_attribute("denom", voucher.denom)
+                    .add_attribute("amount", voucher.amount.to_string())
+                    .add_attribute("success", "true"),
+            );
+        }
+
+        Ok(res)
+    }
+}
+
+/// The `FungibleTokenPacketData` that ibc-go puts in the `packet_data` attribute. Amounts are
+/// strings there, so it is built separately from [`TransferPacket`].
+#[cw_serde]
+struct FungibleTokenPacketData {
+    denom: String,
+    amount: String,
+    sender: String,
+    receiver: String,
+    memo: String,
+}
+
+impl From<&TransferPacket> for FungibleTokenPacketData {
+    fn from(packet: &TransferPacket) -> Self {
+        Self {
+            denom: packet.denom.clone(),
+            amount: packet.amount.to_string(),
+            sender: packet.sender.clone(),
+            receiver: packet.receiver.clone(),
+            memo: packet.memo.clone(),
+        }
+    }
+}
+
+impl Module for IbcTransfer {
+    type ExecT = StargateMsg;
+    type QueryT = StargateQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let StargateMsg { type_url, value, .. } = msg;
+
+        match type_url.as_str() {
+            MsgTransfer::TYPE_URL => self.transfer(api, storage, router, block, sender, value),
+            _ => bail!("Unknown message type {}", type_url),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        Err(anyhow!("Unexpected stargate query: path={}, data={:?}", request.path, request.data))
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        self.deliver_packets(api, storage, router, block)
+    }
+}
+
+impl Stargate for IbcTransfer {}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, CosmosMsg};
+    use cw_multi_test::{BasicAppBuilder, Executor};
+    use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use test_tube::{Account, Runner, SigningAccount};
+
+    use super::*;
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::{CwItRunner, DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    fn msg_transfer(sender: &SigningAccount, receiver: &SigningAccount, amount: u128) -> MsgTransfer {
+        MsgTransfer {
+            source_port: TRANSFER_PORT.to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Some(ProtoCoin {
+                denom: DEFAULT_COIN_DENOM.to_string(),
+                amount: amount.to_string(),
+            }),
+            sender: sender.address(),
+            receiver: receiver.address(),
+            timeout_height: None,
+            timeout_timestamp: 0,
+            memo: String::new(),
+        }
+    }
+
+    #[test]
+    fn transfer_and_deliver() {
+        let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, IbcTransfer::new().with_channel("channel-0", "channel-7"));
+        let accounts = runner.init_accounts(&[coin(1000, DEFAULT_COIN_DENOM)], 2).unwrap();
+        let (sender, receiver) = (&accounts[0], &accounts[1]);
+
+        let res = runner
+            .execute::<_, MsgTransferResponse>(msg_transfer(sender, receiver, 300), MsgTransfer::TYPE_URL, sender)
+            .unwrap();
+        assert_eq!(res.data.sequence, 1);
+
+        let send_packet = res.events.iter().find(|e| e.ty == "send_packet").unwrap();
+        let attr = |key: &str| send_packet.attributes.iter().find(|a| a.key == key).unwrap().value.clone();
+        assert_eq!(attr("packet_sequence"), "1");
+        assert_eq!(attr("packet_src_channel"), "channel-0");
+        assert_eq!(attr("packet_dst_channel"), "channel-7");
+
+        // The sent coins are escrowed until the packet is delivered
+        let escrow = escrow_address(runner.app.borrow().api(), TRANSFER_PORT, "channel-0").unwrap();
+        assert!(escrow.as_str().starts_with(DEFAULT_ADDRESS_PREFIX));
+        let balance = |address: &str, denom: &str| runner.app.borrow().wrap().query_balance(address, denom).unwrap().amount.u128();
+        let voucher_denom = ibc_denom(TRANSFER_PORT, "channel-7", DEFAULT_COIN_DENOM);
+        assert_eq!(balance(&sender.address(), DEFAULT_COIN_DENOM), 700);
+        assert_eq!(balance(escrow.as_str(), DEFAULT_COIN_DENOM), 300);
+        assert_eq!(balance(&receiver.address(), &voucher_denom), 0);
+
+        runner.sudo_stargate().unwrap();
+
+        assert_eq!(balance(&receiver.address(), &voucher_denom), 300);
+        assert!(voucher_denom.starts_with("ibc/") && voucher_denom.len() == 68);
+    }
+
+    #[test]
+    fn transfer_of_send_disabled_denom() {
+        let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, IbcTransfer::new().with_channel("channel-0", "channel-0"));
+        let accounts = runner.init_accounts(&[coin(1000, DEFAULT_COIN_DENOM)], 2).unwrap();
+        runner.set_send_enabled(DEFAULT_COIN_DENOM, false);
+
+        let err = runner
+            .execute::<_, MsgTransferResponse>(msg_transfer(&accounts[0], &accounts[1], 300), MsgTransfer::TYPE_URL, &accounts[0])
+            .unwrap_err();
+        assert!(err.to_string().contains("transfers are currently disabled"), "{}", err);
+    }
+
+    #[test]
+    fn transfer_over_unknown_channel() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(IbcTransfer::default())
+            .build(|_, _, _| {});
+
+        let err = app
+            .execute(
+                sender.clone(),
+                CosmosMsg::Stargate {
+                    type_url: MsgTransfer::TYPE_URL.to_string(),
+                    value: MsgTransfer {
+                        source_port: TRANSFER_PORT.to_string(),
+                        source_channel: "channel-1".to_string(),
+                        token: Some(ProtoCoin {
+                            denom: DEFAULT_COIN_DENOM.to_string(),
+                            amount: "1".to_string(),
+                        }),
+                        sender: sender.to_string(),
+                        receiver: "receiver".to_string(),
+                        ..Default::default()
+                    }
+                    .into(),
+                },
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("channel not found"));
+    }
+}
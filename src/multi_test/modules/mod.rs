@@ -1,22 +1,85 @@
+use anyhow::{anyhow, Result as AnyResult};
+use cosmwasm_std::Coin;
+
 pub mod unified_stargate;
 
+mod gov;
+pub use gov::{tally_proposals, Gov, GovProposal, ProposalTally, PROPOSALS, VOTES};
+
+#[cfg(feature = "ibc-transfer")]
+mod ibc_transfer;
+#[cfg(feature = "ibc-transfer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ibc-transfer")))]
+pub use ibc_transfer::{escrow_address, ibc_denom, IbcTransfer, TransferPacket, PENDING_PACKETS, TRANSFER_PORT};
+
+mod staking;
+pub use staking::{Staking, DELEGATIONS, STAKING_ESCROW};
+
 #[cfg(not(feature = "coreum"))]
 mod token_factory;
 #[cfg(feature = "coreum")]
 mod token_factory_coreum;
 
 #[cfg(not(feature = "coreum"))]
-pub use token_factory::TokenFactory;
+pub use token_factory::{
+    created_denoms, FeeDestination, QueryDenomCountRequest, QueryDenomCountResponse, QueryDenomExistsRequest, QueryDenomExistsResponse,
+    TokenFactories, TokenFactory,
+};
 #[cfg(feature = "coreum")]
-pub use token_factory_coreum::TokenFactory;
+pub use token_factory_coreum::{created_denoms, TokenFactory};
 
 #[cfg(feature = "coreum")]
-pub use token_factory_coreum::CoreumQueryModule;
+pub use token_factory_coreum::{assert_receivable, assert_spendable, transfer_deductions, CoreumQueryModule, TransferDeductions};
+
+#[cfg(not(feature = "coreum"))]
+use token_factory::coin_from_sdk_string;
+#[cfg(feature = "coreum")]
+use token_factory_coreum::coin_from_sdk_string;
+
+/// Parses a list of SDK coin strings such as `1000ucore` into coins, in the same order. Errors
+/// with the offending string if any of them is malformed.
+pub fn coins(sdk_strings: &[&str]) -> AnyResult<Vec<Coin>> {
+    sdk_strings
+        .iter()
+        .map(|sdk_string| coin_from_sdk_string(sdk_string).map_err(|e| anyhow!("{}: {}", e, sdk_string)))
+        .collect()
+}
 
 pub const QUERY_ALL_BALANCES_PATH: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
 pub const QUERY_BALANCE_PATH: &str = "/cosmos.bank.v1beta1.Query/Balance";
 pub const QUERY_SUPPLY_PATH: &str = "/cosmos.bank.v1beta1.Query/SupplyOf";
+pub const QUERY_TOTAL_SUPPLY_PATH: &str = "/cosmos.bank.v1beta1.Query/TotalSupply";
+pub const QUERY_SEND_ENABLED_PATH: &str = "/cosmos.bank.v1beta1.Query/SendEnabled";
+pub const QUERY_AUTH_ACCOUNT_PATH: &str = "/cosmos.auth.v1beta1.Query/Account";
 pub const QUERY_WASM_CONTRACT_SMART_PATH: &str = "/cosmwasm.wasm.v1.Query/SmartContractState";
 pub const QUERY_WASM_CONTRACT_RAW_PATH: &str = "/cosmwasm.wasm.v1.Query/RawContractState";
 pub const QUERY_WASM_CONTRACT_INFO_PATH: &str = "/cosmwasm.wasm.v1.Query/ContractInfo";
 pub const QUERY_WASM_CODE_INFO_PATH: &str = "/cosmwasm.wasm.v1.Query/CodeInfo";
+pub const QUERY_TOKENFACTORY_PARAMS_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/Params";
+pub const QUERY_TOKENFACTORY_DENOMS_FROM_CREATOR_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/DenomsFromCreator";
+pub const QUERY_TOKENFACTORY_BEFORE_SEND_HOOK_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/BeforeSendHookAddress";
+/// Not a query of the Osmosis TokenFactory, only answered by the mocked [`TokenFactory`].
+#[cfg(not(feature = "coreum"))]
+pub const QUERY_TOKENFACTORY_DENOM_COUNT_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/DenomCount";
+/// Not a query of the Osmosis TokenFactory, only answered by the mocked [`TokenFactory`].
+#[cfg(not(feature = "coreum"))]
+pub const QUERY_TOKENFACTORY_DENOM_EXISTS_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/DenomExists";
+pub const QUERY_GOV_PROPOSAL_PATH: &str = "/cosmos.gov.v1beta1.Query/Proposal";
+pub const QUERY_DELEGATOR_DELEGATIONS_PATH: &str = "/cosmos.staking.v1beta1.Query/DelegatorDelegations";
+pub const QUERY_VALIDATORS_PATH: &str = "/cosmos.staking.v1beta1.Query/Validators";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coins() {
+        assert_eq!(
+            coins(&["1000ucore", "50uatom"]).unwrap(),
+            vec![Coin::new(1000, "ucore"), Coin::new(50, "uatom")]
+        );
+
+        let err = coins(&["1000ucore", "50 uatom"]).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid sdk string: 50 uatom");
+    }
+}
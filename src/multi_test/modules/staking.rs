@@ -0,0 +1,437 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, Empty, Event, Order, Querier, Storage, Timestamp, Uint128,
+};
+use cw_multi_test::{AppResponse, CosmosRouter, Module, Stargate, StargateMsg, StargateQuery};
+use cw_storage_plus::{Item, Map};
+use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+use osmosis_std::types::cosmos::staking::v1beta1::{
+    BondStatus, Delegation, DelegationResponse, MsgDelegate, MsgDelegateResponse, MsgUndelegate, MsgUndelegateResponse,
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse, QueryValidatorsRequest, QueryValidatorsResponse, Validator,
+};
+use prost::Message;
+
+use crate::multi_test::modules::{QUERY_DELEGATOR_DELEGATIONS_PATH, QUERY_VALIDATORS_PATH};
+use crate::traits::DEFAULT_COIN_DENOM;
+
+/// The address of the module account holding all delegated and unbonding funds.
+pub const STAKING_ESCROW: &str = "bonded_tokens_pool";
+
+/// Map of **(delegator, validator) -> amount** of every delegation made through the [`Staking`] module.
+pub const DELEGATIONS: Map<(&str, &str), Uint128> = Map::new("staking/delegations");
+
+/// All undelegations that have not been paid out yet.
+const UNBONDINGS: Item<Vec<Unbonding>> = Item::new("staking/unbondings");
+
+#[cw_serde]
+struct Unbonding {
+    delegator: String,
+    amount: Coin,
+    completion_time: Timestamp,
+}
+
+/// This is a struct that implements the [`cw_multi_test::Stargate`] trait to mimic the behavior
+/// of the cosmos-sdk staking module for contracts sending staking messages as stargate messages
+/// or querying delegations through stargate queries.
+///
+/// Delegated funds are moved to the [`STAKING_ESCROW`] account. Undelegated funds are paid back
+/// once the unbonding period has passed, the next time the module handles a message or its `sudo`
+/// entry point is called. There are no rewards or slashing, and every validator has the same
+/// status regardless of its delegations.
+///
+/// In a [`crate::multi_test::MultiTestRunner`], the `MsgDelegate` and `MsgUndelegate` messages
+/// signed by accounts are executed by the multi-test staking keeper, unless they are passed to
+/// this module with [`crate::multi_test::MultiTestRunner::with_stargate_msgs`]. Unbondings are
+/// paid out whenever the block is advanced if the runner is configured with
+/// [`crate::multi_test::MultiTestRunner::with_sudo_on_block_advance`].
+#[derive(Clone)]
+pub struct Staking {
+    /// The operator addresses of the validators that can be delegated to.
+    validators: Vec<String>,
+    pub bond_denom: String,
+    /// The unbonding period in seconds.
+    pub unbonding_period: u64,
+}
+
+impl Staking {
+    /// Creates a new Staking module with a fixed set of `validators`.
+    pub fn new(validators: &[&str], bond_denom: &str, unbonding_period: u64) -> Self {
+        Self {
+            validators: validators.iter().map(|v| v.to_string()).collect(),
+            bond_denom: bond_denom.to_string(),
+            unbonding_period,
+        }
+    }
+
+    /// Returns the operator addresses of the validators that can be delegated to.
+    pub fn validators(&self) -> &[String] {
+        &self.validators
+    }
+}
+
+impl Default for Staking {
+    fn default() -> Self {
+        Self::new(&["validator1", "validator2", "validator3"], DEFAULT_COIN_DENOM, 21 * 24 * 60 * 60)
+    }
+}
+
+impl Staking {
+    fn validate_msg(&self, sender: &Addr, delegator: &str, validator: &str, amount: Option<ProtoCoin>) -> AnyResult<Coin> {
+        if delegator != sender.as_str() {
+            bail!("Invalid delegator. Delegator in msg must be same as sender of transaction.");
+        }
+        if !self.validators.iter().any(|v| v == validator) {
+            bail!("Validator {} does not exist", validator);
+        }
+        let amount = amount.ok_or_else(|| anyhow!("missing amount"))?;
+        if amount.denom != self.bond_denom {
+            bail!("Invalid coin denomination: got {}, expected {}", amount.denom, self.bond_denom);
+        }
+        let amount = Coin {
+            amount: Uint128::from_str(&amount.amount)?,
+            denom: amount.denom,
+        };
+        if amount.amount.is_zero() {
+            bail!("Invalid zero amount");
+        }
+        Ok(amount)
+    }
+
+    fn delegate<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let msg: MsgDelegate = value.try_into()?;
+        let amount = self.validate_msg(&sender, &msg.delegator_address, &msg.validator_address, msg.amount)?;
+
+        let send_msg = BankMsg::Send {
+            to_address: STAKING_ESCROW.to_string(),
+            amount: vec![amount.clone()],
+        };
+        router.execute(api, storage, block, sender, send_msg.into())?;
+        DELEGATIONS.update(
+            storage,
+            (msg.delegator_address.as_str(), msg.validator_address.as_str()),
+            |delegated| Ok::<_, anyhow::Error>(delegated.unwrap_or_default() + amount.amount),
+        )?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("delegate")
+                .add_attribute("validator", msg.validator_address)
+                .add_attribute("delegator", msg.delegator_address)
+                .add_attribute("amount", amount.to_string()),
+        );
+        res.data = Some(MsgDelegateResponse {}.into());
+
+        Ok(res)
+    }
+
+    fn undelegate(&self, storage: &mut dyn Storage, block: &BlockInfo, sender: Addr, value: Binary) -> AnyResult<AppResponse> {
+        let msg: MsgUndelegate = value.try_into()?;
+        let amount = self.validate_msg(&sender, &msg.delegator_address, &msg.validator_address, msg.amount)?;
+
+        let key = (msg.delegator_address.as_str(), msg.validator_address.as_str());
+        let delegated = DELEGATIONS.may_load(storage, key)?.unwrap_or_default();
+        if delegated < amount.amount {
+            bail!(
+                "invalid shares amount: {} delegated {}{} to {}, but tried to undelegate {}",
+                msg.delegator_address,
+                delegated,
+                amount.denom,
+                msg.validator_address,
+                amount
+            );
+        }
+        if delegated == amount.amount {
+            DELEGATIONS.remove(storage, key);
+        } else {
+            DELEGATIONS.save(storage, key, &(delegated - amount.amount))?;
+        }
+
+        let completion_time = block.time.plus_seconds(self.unbonding_period);
+        let mut unbondings = UNBONDINGS.may_load(storage)?.unwrap_or_default();
+        unbondings.push(Unbonding {
+            delegator: msg.delegator_address.clone(),
+            amount: amount.clone(),
+            completion_time,
+        });
+        UNBONDINGS.save(storage, &unbondings)?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("unbond")
+                .add_attribute("validator", msg.validator_address)
+                .add_attribute("delegator", msg.delegator_address)
+                .add_attribute("amount", amount.to_string())
+                .add_attribute("completion_time", completion_time.to_string()),
+        );
+        res.data = Some(
+            MsgUndelegateResponse {
+                completion_time: Some(osmosis_std::shim::Timestamp {
+                    seconds: completion_time.seconds() as i64,
+                    nanos: completion_time.subsec_nanos() as i32,
+                }),
+            }
+            .into(),
+        );
+
+        Ok(res)
+    }
+
+    /// Pays out all unbondings whose unbonding period has passed at `block`.
+    fn complete_unbondings<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+    ) -> AnyResult<()>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let (completed, pending): (Vec<_>, Vec<_>) = UNBONDINGS
+            .may_load(storage)?
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|unbonding| unbonding.completion_time <= block.time);
+        if completed.is_empty() {
+            return Ok(());
+        }
+
+        UNBONDINGS.save(storage, &pending)?;
+        for unbonding in completed {
+            let send_msg = BankMsg::Send {
+                to_address: unbonding.delegator,
+                amount: vec![unbonding.amount],
+            };
+            router.execute(api, storage, block, Addr::unchecked(STAKING_ESCROW), send_msg.into())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Module for Staking {
+    type ExecT = StargateMsg;
+    type QueryT = StargateQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let StargateMsg { type_url, value, .. } = msg;
+
+        self.complete_unbondings(api, storage, router, block)?;
+        match type_url.as_str() {
+            MsgDelegate::TYPE_URL => self.delegate(api, storage, router, block, sender, value),
+            MsgUndelegate::TYPE_URL => self.undelegate(storage, block, sender, value),
+            _ => bail!("Unknown message type {}", type_url),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request.path.as_str() {
+            QUERY_DELEGATOR_DELEGATIONS_PATH => {
+                let req = QueryDelegatorDelegationsRequest::decode(request.data.as_slice())?;
+                let delegation_responses = DELEGATIONS
+                    .prefix(&req.delegator_addr)
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| {
+                        let (validator, amount) = item?;
+                        Ok(DelegationResponse {
+                            delegation: Some(Delegation {
+                                delegator_address: req.delegator_addr.clone(),
+                                validator_address: validator,
+                                shares: format!("{}.000000000000000000", amount),
+                            }),
+                            balance: Some(ProtoCoin {
+                                denom: self.bond_denom.clone(),
+                                amount: amount.to_string(),
+                            }),
+                        })
+                    })
+                    .collect::<AnyResult<Vec<_>>>()?;
+
+                Ok(to_json_binary(&QueryDelegatorDelegationsResponse {
+                    delegation_responses,
+                    pagination: None,
+                })?)
+            }
+            QUERY_VALIDATORS_PATH => {
+                let _req = QueryValidatorsRequest::decode(request.data.as_slice())?;
+                let validators = self
+                    .validators
+                    .iter()
+                    .map(|operator_address| Validator {
+                        operator_address: operator_address.clone(),
+                        status: BondStatus::Bonded as i32,
+                        ..Default::default()
+                    })
+                    .collect();
+
+                Ok(to_json_binary(&QueryValidatorsResponse {
+                    validators,
+                    pagination: None,
+                })?)
+            }
+            _ => Err(anyhow!("Unexpected stargate query: path={}, data={:?}", request.path, request.data)),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        self.complete_unbondings(api, storage, router, block)?;
+        Ok(AppResponse::default())
+    }
+}
+
+impl Stargate for Staking {}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, CosmosMsg, QuerierWrapper, QueryRequest};
+    use cw_multi_test::{BasicAppBuilder, Executor};
+
+    use super::*;
+
+    #[test]
+    fn delegate_and_undelegate() {
+        let delegator = Addr::unchecked("delegator");
+        let staking = Staking::default();
+        let unbonding_period = staking.unbonding_period;
+
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(staking)
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &delegator, vec![coin(1000, DEFAULT_COIN_DENOM)])
+                    .unwrap();
+            });
+
+        let amount = |amount: u128| {
+            Some(ProtoCoin {
+                denom: DEFAULT_COIN_DENOM.to_string(),
+                amount: amount.to_string(),
+            })
+        };
+        let balance = |querier: QuerierWrapper| querier.query_balance(&delegator, DEFAULT_COIN_DENOM).unwrap().amount.u128();
+        let delegated = |querier: QuerierWrapper| -> Vec<(String, String)> {
+            let res: QueryDelegatorDelegationsResponse = querier
+                .query(&QueryRequest::Stargate {
+                    path: QUERY_DELEGATOR_DELEGATIONS_PATH.to_string(),
+                    data: QueryDelegatorDelegationsRequest {
+                        delegator_addr: delegator.to_string(),
+                        pagination: None,
+                    }
+                    .encode_to_vec()
+                    .into(),
+                })
+                .unwrap();
+            res.delegation_responses
+                .into_iter()
+                .map(|res| (res.delegation.unwrap().validator_address, res.balance.unwrap().amount))
+                .collect()
+        };
+
+        app.execute(
+            delegator.clone(),
+            CosmosMsg::Stargate {
+                type_url: MsgDelegate::TYPE_URL.to_string(),
+                value: MsgDelegate {
+                    delegator_address: delegator.to_string(),
+                    validator_address: "validator1".to_string(),
+                    amount: amount(600),
+                }
+                .into(),
+            },
+        )
+        .unwrap();
+        assert_eq!(balance(app.wrap()), 400);
+        assert_eq!(delegated(app.wrap()), vec![("validator1".to_string(), "600".to_string())]);
+
+        app.execute(
+            delegator.clone(),
+            CosmosMsg::Stargate {
+                type_url: MsgUndelegate::TYPE_URL.to_string(),
+                value: MsgUndelegate {
+                    delegator_address: delegator.to_string(),
+                    validator_address: "validator1".to_string(),
+                    amount: amount(200),
+                }
+                .into(),
+            },
+        )
+        .unwrap();
+        assert_eq!(delegated(app.wrap()), vec![("validator1".to_string(), "400".to_string())]);
+        // The undelegated funds are only returned after the unbonding period
+        assert_eq!(balance(app.wrap()), 400);
+
+        app.update_block(|block| block.time = block.time.plus_seconds(unbonding_period));
+        let block = app.block_info();
+        app.init_modules(|router, api, storage| {
+            let router: &_ = router;
+            router.stargate.sudo(api, storage, router, &block, Empty {})
+        })
+        .unwrap();
+        assert_eq!(balance(app.wrap()), 600);
+    }
+
+    #[test]
+    fn query_validators() {
+        let app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(Staking::new(&["valoper1", "valoper2"], DEFAULT_COIN_DENOM, 10))
+            .build(|_, _, _| {});
+
+        let res: QueryValidatorsResponse = app
+            .wrap()
+            .query(&QueryRequest::Stargate {
+                path: QUERY_VALIDATORS_PATH.to_string(),
+                data: QueryValidatorsRequest::default().encode_to_vec().into(),
+            })
+            .unwrap();
+        let validators = res.validators.into_iter().map(|v| v.operator_address).collect::<Vec<_>>();
+        assert_eq!(validators, vec!["valoper1", "valoper2"]);
+    }
+}
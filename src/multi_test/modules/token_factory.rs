@@ -2,20 +2,90 @@ use std::str::FromStr;
 
 use anyhow::{anyhow, bail, Result as AnyResult};
 use cosmwasm_std::{
-    from_json, Addr, Api, BankMsg, BankQuery, Binary, BlockInfo, Coin, Empty, Event, Querier, QueryRequest, Storage, SupplyResponse,
-    Uint128,
+    from_json, to_json_binary, Addr, Api, BalanceResponse, BankMsg, BankQuery, Binary, BlockInfo, Coin, Empty, Event, Order, Querier,
+    QueryRequest, StdResult, Storage, SupplyResponse, Uint128,
 };
+use cw_storage_plus::Map;
 use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
-    MsgBurn, MsgBurnResponse, MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgMintResponse,
+    MsgBurn, MsgBurnResponse, MsgCreateDenom, MsgCreateDenomResponse, MsgForceTransfer, MsgForceTransferResponse, MsgMint, MsgMintResponse,
+    MsgSetBeforeSendHook, MsgSetBeforeSendHookResponse, Params, QueryBeforeSendHookAddressRequest, QueryBeforeSendHookAddressResponse,
+    QueryDenomsFromCreatorRequest, QueryDenomsFromCreatorResponse, QueryParamsResponse,
 };
+use prost::Message;
 use regex::Regex;
 
 use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Executor, Module, Stargate, StargateMsg, StargateQuery};
 
+use crate::multi_test::modules::unified_stargate::track_minted_denoms;
+use crate::multi_test::modules::{
+    QUERY_TOKENFACTORY_BEFORE_SEND_HOOK_PATH, QUERY_TOKENFACTORY_DENOMS_FROM_CREATOR_PATH, QUERY_TOKENFACTORY_DENOM_COUNT_PATH,
+    QUERY_TOKENFACTORY_DENOM_EXISTS_PATH, QUERY_TOKENFACTORY_PARAMS_PATH,
+};
 use crate::traits::DEFAULT_COIN_DENOM;
 
 const DEFAULT_INIT: &str = constcat::concat!("10000000", DEFAULT_COIN_DENOM);
 
+/// Map of **denom -> admin** for every denom created through the TokenFactory.
+///
+/// The admin is initially the creator of the denom. The map is shared by all [`TokenFactory`]
+/// instances of an app, which only see the denoms starting with their own prefix.
+pub const DENOM_ADMINS: Map<&str, String> = Map::new("tokenfactory/denom_admins");
+
+/// Map of **(creator, denom) -> ()** for every denom created through the TokenFactory. Like
+/// [`DENOM_ADMINS`], every [`TokenFactory`] only sees the denoms starting with its own prefix.
+pub const CREATOR_DENOMS: Map<(&str, &str), Empty> = Map::new("tokenfactory/creator_denoms");
+
+/// Map of **creator -> denom prefix** of the factory the `MsgCreateDenom`s of the creator are
+/// routed to by [`TokenFactories`], see [`TokenFactories::route_creator`].
+pub const CREATOR_FACTORIES: Map<&str, String> = Map::new("tokenfactory/creator_factories");
+
+/// Map of **denom -> hook contract** for every denom a before-send hook is set for with
+/// `MsgSetBeforeSendHook`.
+pub const BEFORE_SEND_HOOKS: Map<&str, Addr> = Map::new("tokenfactory/before_send_hooks");
+
+/// Returns all denoms created through the TokenFactory, in ascending order.
+pub fn created_denoms(storage: &dyn Storage) -> AnyResult<Vec<String>> {
+    Ok(DENOM_ADMINS.keys(storage, None, None, Order::Ascending).collect::<Result<_, _>>()?)
+}
+
+/// Request of the [`QUERY_TOKENFACTORY_DENOM_COUNT_PATH`] query for the number of denoms created
+/// through the TokenFactory.
+#[derive(Clone, PartialEq, Eq, prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct QueryDenomCountRequest {}
+
+/// Response of the [`QUERY_TOKENFACTORY_DENOM_COUNT_PATH`] query.
+#[derive(Clone, PartialEq, Eq, prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct QueryDenomCountResponse {
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+}
+
+/// Request of the [`QUERY_TOKENFACTORY_DENOM_EXISTS_PATH`] query for whether `denom` was created
+/// through the TokenFactory.
+#[derive(Clone, PartialEq, Eq, prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct QueryDenomExistsRequest {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+}
+
+/// Response of the [`QUERY_TOKENFACTORY_DENOM_EXISTS_PATH`] query. A denom exists as soon as it
+/// is created, also while its supply is zero.
+#[derive(Clone, PartialEq, Eq, prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct QueryDenomExistsResponse {
+    #[prost(bool, tag = "1")]
+    pub exists: bool,
+}
+
+/// Where the TokenFactory sends the denom creation fee.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeeDestination<'a> {
+    /// The fee is burned, reducing the supply of the fee denom.
+    #[default]
+    Burn,
+    /// The fee is sent to the given address, e.g. the community pool or a fee collector.
+    Send(&'a str),
+}
+
 /// This is a struct that implements the [`cw_multi_test::Stargate`] trait to
 /// mimic the behavior of the Osmosis TokenFactory module.
 #[derive(Clone)]
@@ -25,6 +95,10 @@ pub struct TokenFactory<'a> {
     pub max_hrp_len: usize,
     pub max_creator_len: usize,
     pub denom_creation_fee: &'a str,
+    /// The maximum number of denoms a single creator may create. Unlimited if `None`.
+    pub max_denoms_per_creator: Option<u32>,
+    /// Where the denom creation fee goes. Burned by default.
+    pub fee_destination: FeeDestination<'a>,
 }
 
 impl<'a> TokenFactory<'a> {
@@ -42,8 +116,61 @@ impl<'a> TokenFactory<'a> {
             max_hrp_len,
             max_creator_len,
             denom_creation_fee,
+            max_denoms_per_creator: None,
+            fee_destination: FeeDestination::Burn,
         }
     }
+
+    /// Limits the number of denoms a single creator may create to `max_denoms`.
+    pub const fn with_max_denoms_per_creator(mut self, max_denoms: u32) -> Self {
+        self.max_denoms_per_creator = Some(max_denoms);
+        self
+    }
+
+    /// Sends the denom creation fee to `address` instead of burning it.
+    pub const fn with_fee_collector(mut self, address: &'a str) -> Self {
+        self.fee_destination = FeeDestination::Send(address);
+        self
+    }
+
+    /// Returns the configured denom creation fee as a [`Coin`], or `None` if creating denoms is free.
+    pub fn denom_creation_fee_coin(&self) -> Option<Coin> {
+        coin_from_sdk_string(self.denom_creation_fee)
+            .ok()
+            .filter(|fee| !fee.amount.is_zero())
+    }
+
+    /// Returns whether `denom` belongs to this factory, i.e. starts with its prefix.
+    fn owns_denom(&self, denom: &str) -> bool {
+        denom
+            .strip_prefix(self.module_denom_prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// Returns the admin of `denom`, or `None` if it was not created through this factory.
+    fn denom_admin(&self, storage: &dyn Storage, denom: &str) -> AnyResult<Option<String>> {
+        if !self.owns_denom(denom) {
+            return Ok(None);
+        }
+        Ok(DENOM_ADMINS.may_load(storage, denom)?)
+    }
+
+    /// Returns the number of denoms created through this factory.
+    fn denom_count(&self, storage: &dyn Storage) -> AnyResult<u64> {
+        let denoms = DENOM_ADMINS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(denoms.into_iter().filter(|denom| self.owns_denom(denom)).count() as u64)
+    }
+
+    /// Returns the denoms `creator` created through this factory, in ascending order.
+    fn creator_denoms(&self, storage: &dyn Storage, creator: &str) -> AnyResult<Vec<String>> {
+        let denoms = CREATOR_DENOMS
+            .prefix(creator)
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(denoms.into_iter().filter(|denom| self.owns_denom(denom)).collect())
+    }
 }
 
 impl Default for TokenFactory<'_> {
@@ -91,13 +218,43 @@ impl TokenFactory<'_> {
         let request = QueryRequest::Bank(BankQuery::Supply { denom: denom.clone() });
         let raw = router.query(api, storage, block, request)?;
         let supply: SupplyResponse = from_json(raw)?;
-        if !supply.amount.amount.is_zero() {
+        if !supply.amount.amount.is_zero() || DENOM_ADMINS.has(storage, &denom) {
             bail!("Subdenom already exists");
         }
 
-        // Charge denom creation fee
+        // Validate the creator has not reached the denom limit
+        if let Some(max_denoms) = self.max_denoms_per_creator {
+            let created = self.creator_denoms(storage, &msg.sender)?.len();
+            if created >= max_denoms as usize {
+                bail!("Creator has reached the maximum number of denoms: {}", max_denoms);
+            }
+        }
+
+        DENOM_ADMINS.save(storage, &denom, &msg.sender)?;
+        CREATOR_DENOMS.save(storage, (&msg.sender, &denom), &Empty {})?;
+
+        // Validate the sender holds the denom creation fee and charge it
         let fee = coin_from_sdk_string(self.denom_creation_fee)?;
-        let fee_msg = BankMsg::Burn { amount: vec![fee] };
+        let request = QueryRequest::Bank(BankQuery::Balance {
+            address: sender.to_string(),
+            denom: fee.denom.clone(),
+        });
+        let balance: BalanceResponse = from_json(router.query(api, storage, block, request)?)?;
+        if balance.amount.amount < fee.amount {
+            bail!(
+                "insufficient funds for denom creation fee: {} has {}, but needs {}",
+                sender,
+                balance.amount,
+                fee
+            );
+        }
+        let fee_msg = match self.fee_destination {
+            FeeDestination::Burn => BankMsg::Burn { amount: vec![fee] },
+            FeeDestination::Send(address) => BankMsg::Send {
+                to_address: address.to_string(),
+                amount: vec![fee],
+            },
+        };
         router.execute(api, storage, block, sender, fee_msg.into())?;
 
         let create_denom_response = MsgCreateDenomResponse {
@@ -107,7 +264,16 @@ impl TokenFactory<'_> {
         let mut res = AppResponse::default();
         res.events.push(
             Event::new("create_denom")
-                .add_attribute("creator", msg.sender)
+                .add_attribute("creator", msg.sender.clone())
+                .add_attribute("new_token_denom", denom.clone()),
+        );
+        // Also emit the denom on a `message` event, like the SDK does for every message, so generic
+        // event scrapers find it without knowing about the typed event.
+        res.events.push(
+            Event::new("message")
+                .add_attribute("action", "create_denom")
+                .add_attribute("module", "tokenfactory")
+                .add_attribute("sender", msg.sender)
                 .add_attribute("new_token_denom", denom),
         );
         res.data = Some(create_denom_response.into());
@@ -159,14 +325,16 @@ impl TokenFactory<'_> {
         };
 
         // Mint through BankKeeper sudo method
+        let coins = vec![Coin {
+            denom: denom.clone(),
+            amount,
+        }];
         let mint_msg = BankSudo::Mint {
             to_address: recipient.clone(),
-            amount: vec![Coin {
-                denom: denom.clone(),
-                amount,
-            }],
+            amount: coins.clone(),
         };
         router.sudo(api, storage, block, mint_msg.into())?;
+        track_minted_denoms(storage, &coins)?;
 
         let mut res = AppResponse::default();
         let data = MsgMintResponse {};
@@ -239,6 +407,115 @@ impl TokenFactory<'_> {
         Ok(res)
     }
 
+    pub fn force_transfer<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let msg: MsgForceTransfer = value.try_into()?;
+
+        let amount = msg.amount.clone().ok_or_else(|| anyhow!("missing amount"))?;
+        let denom = amount.denom;
+
+        // Validate sender is the admin of the denom
+        let Some(admin) = self.denom_admin(storage, &denom)? else {
+            bail!("Denom does not exist: {}", denom);
+        };
+        if admin != sender.to_string() {
+            bail!("Unauthorized force transfer. Not the admin of the denom.");
+        }
+        if sender.to_string() != msg.sender {
+            bail!("Invalid sender. Sender in msg must be same as sender of transaction.");
+        }
+
+        let amount = Uint128::from_str(&amount.amount)?;
+        if amount.is_zero() {
+            bail!("Invalid zero amount");
+        }
+
+        // Validate balance of the account funds are transferred from
+        let request = QueryRequest::Bank(BankQuery::Balance {
+            address: msg.transfer_from_address.clone(),
+            denom: denom.clone(),
+        });
+        let balance: BalanceResponse = from_json(router.query(api, storage, block, request)?)?;
+        if balance.amount.amount < amount {
+            bail!(
+                "insufficient funds: {} has {}, but needs {}{}",
+                msg.transfer_from_address,
+                balance.amount,
+                amount,
+                denom
+            );
+        }
+
+        // Move the funds by burning them from the source and minting them to the destination
+        let coins = vec![Coin {
+            denom: denom.clone(),
+            amount,
+        }];
+        let burn_msg = BankMsg::Burn { amount: coins.clone() };
+        router.execute(api, storage, block, Addr::unchecked(&msg.transfer_from_address), burn_msg.into())?;
+        let mint_msg = BankSudo::Mint {
+            to_address: msg.transfer_to_address.clone(),
+            amount: coins,
+        };
+        router.sudo(api, storage, block, mint_msg.into())?;
+
+        let mut res = AppResponse::default();
+        res.data = Some(MsgForceTransferResponse {}.into());
+        res.events.push(
+            Event::new("force_transfer")
+                .add_attribute("transfer_from_address", msg.transfer_from_address)
+                .add_attribute("transfer_to_address", msg.transfer_to_address)
+                .add_attribute("amount", format!("{}{}", amount, denom)),
+        );
+
+        Ok(res)
+    }
+
+    /// Sets the before-send hook contract of a denom, or clears it if the address is empty. Only
+    /// the hook address is stored, the hook is not called on transfers of the denom.
+    pub fn set_before_send_hook(&self, api: &dyn Api, storage: &mut dyn Storage, sender: Addr, value: Binary) -> AnyResult<AppResponse> {
+        let msg: MsgSetBeforeSendHook = value.try_into()?;
+
+        // Validate sender is the admin of the denom
+        let Some(admin) = self.denom_admin(storage, &msg.denom)? else {
+            bail!("Denom does not exist: {}", msg.denom);
+        };
+        if admin != sender.to_string() {
+            bail!("Unauthorized set before send hook. Not the admin of the denom.");
+        }
+        if sender.to_string() != msg.sender {
+            bail!("Invalid sender. Sender in msg must be same as sender of transaction.");
+        }
+
+        if msg.cosmwasm_address.is_empty() {
+            BEFORE_SEND_HOOKS.remove(storage, &msg.denom);
+        } else {
+            let hook = api.addr_validate(&msg.cosmwasm_address)?;
+            BEFORE_SEND_HOOKS.save(storage, &msg.denom, &hook)?;
+        }
+
+        let mut res = AppResponse::default();
+        res.data = Some(MsgSetBeforeSendHookResponse {}.into());
+        res.events.push(
+            Event::new("set_before_send_hook")
+                .add_attribute("denom", msg.denom)
+                .add_attribute("before_send_hook_address", msg.cosmwasm_address),
+        );
+
+        Ok(res)
+    }
+
     /// Shared internal handler for `CosmosMsg::Stargate`.
     fn handle_any<ExecC, QueryC>(
         &self,
@@ -258,6 +535,8 @@ impl TokenFactory<'_> {
             MsgCreateDenom::TYPE_URL => self.create_denom(api, storage, router, block, sender, value),
             MsgMint::TYPE_URL => self.mint(api, storage, router, block, sender, value),
             MsgBurn::TYPE_URL => self.burn(api, storage, router, block, sender, value),
+            MsgForceTransfer::TYPE_URL => self.force_transfer(api, storage, router, block, sender, value),
+            MsgSetBeforeSendHook::TYPE_URL => self.set_before_send_hook(api, storage, sender, value),
             _ => bail!("Unknown message type {}", type_url),
         }
     }
@@ -285,18 +564,55 @@ impl<'a> Module for TokenFactory<'a> {
         let StargateMsg { type_url, value, .. } = msg;
 
         self.handle_any(api, storage, router, block, sender, type_url, value)
-            .map_err(|e| anyhow!(e.to_string()))
     }
 
     fn query(
         &self,
         _api: &dyn Api,
-        _storage: &dyn Storage,
+        storage: &dyn Storage,
         _querier: &dyn Querier,
         _block: &BlockInfo,
         request: Self::QueryT,
     ) -> AnyResult<Binary> {
-        Err(anyhow!("Unexpected stargate query: path={}, data={:?}", request.path, request.data))
+        match request.path.as_str() {
+            QUERY_TOKENFACTORY_DENOMS_FROM_CREATOR_PATH => {
+                let req = QueryDenomsFromCreatorRequest::decode(request.data.as_slice())?;
+                let denoms = self.creator_denoms(storage, &req.creator)?;
+
+                Ok(to_json_binary(&QueryDenomsFromCreatorResponse { denoms })?)
+            }
+            QUERY_TOKENFACTORY_DENOM_COUNT_PATH => {
+                let count = self.denom_count(storage)?;
+
+                Ok(to_json_binary(&QueryDenomCountResponse { count })?)
+            }
+            QUERY_TOKENFACTORY_BEFORE_SEND_HOOK_PATH => {
+                let req = QueryBeforeSendHookAddressRequest::decode(request.data.as_slice())?;
+                let cosmwasm_address = BEFORE_SEND_HOOKS
+                    .may_load(storage, &req.denom)?
+                    .map(|hook| hook.to_string())
+                    .unwrap_or_default();
+
+                Ok(to_json_binary(&QueryBeforeSendHookAddressResponse { cosmwasm_address })?)
+            }
+            QUERY_TOKENFACTORY_DENOM_EXISTS_PATH => {
+                let req = QueryDenomExistsRequest::decode(request.data.as_slice())?;
+                let exists = self.denom_admin(storage, &req.denom)?.is_some();
+
+                Ok(to_json_binary(&QueryDenomExistsResponse { exists })?)
+            }
+            QUERY_TOKENFACTORY_PARAMS_PATH => {
+                let resp = QueryParamsResponse {
+                    params: Some(Params {
+                        denom_creation_fee: self.denom_creation_fee_coin().into_iter().map(Into::into).collect(),
+                        ..Default::default()
+                    }),
+                };
+
+                Ok(to_json_binary(&resp)?)
+            }
+            _ => Err(anyhow!("Unexpected stargate query: path={}, data={:?}", request.path, request.data)),
+        }
     }
 
     fn sudo<ExecC, QueryC>(
@@ -319,7 +635,176 @@ impl<'a> Module for TokenFactory<'a> {
 // Mark it as a Stargate module
 impl<'a> Stargate for TokenFactory<'a> {}
 
-fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
+/// Routes token factory messages between several [`TokenFactory`] instances with distinct denom
+/// prefixes, e.g. to simulate both an Osmosis style `factory/` module and a custom `denom/` one in
+/// the same app.
+///
+/// A `MsgCreateDenom` carries no denom yet, so it is routed by its sender: to the factory set for
+/// the creator with [`TokenFactories::route_creator`], or to the first factory otherwise. The
+/// message itself is passed on unchanged. All other messages are routed by the prefix of the denom
+/// they act on. The denoms from creator and denom count queries combine the denoms of all
+/// factories, and the denom exists query is routed by the prefix of the denom. Other queries are
+/// answered by the first factory.
+#[derive(Clone)]
+pub struct TokenFactories<'a> {
+    factories: Vec<TokenFactory<'a>>,
+}
+
+impl<'a> TokenFactories<'a> {
+    /// Creates a new router for the given factories. Panics if `factories` is empty or if two
+    /// factories share the same prefix.
+    pub fn new(factories: Vec<TokenFactory<'a>>) -> Self {
+        assert!(!factories.is_empty(), "At least one token factory is required");
+        for (i, factory) in factories.iter().enumerate() {
+            assert!(
+                factories[..i].iter().all(|f| f.module_denom_prefix != factory.module_denom_prefix),
+                "Duplicate token factory prefix {}",
+                factory.module_denom_prefix
+            );
+        }
+        Self { factories }
+    }
+
+    /// Returns the factories, in the order they were given to [`TokenFactories::new`].
+    pub fn factories(&self) -> &[TokenFactory<'a>] {
+        &self.factories
+    }
+
+    /// Routes the `MsgCreateDenom`s of `creator` to the factory with the denom `prefix`, e.g. with
+    /// `app.init_modules(|_, _, storage| TokenFactories::route_creator(storage, creator, "denom"))`.
+    pub fn route_creator(storage: &mut dyn Storage, creator: &str, prefix: &str) -> StdResult<()> {
+        CREATOR_FACTORIES.save(storage, creator, &prefix.to_string())
+    }
+
+    fn factory_for_denom(&self, denom: &str) -> AnyResult<&TokenFactory<'a>> {
+        let prefix = denom.split('/').next().unwrap_or_default();
+        self.factories
+            .iter()
+            .find(|factory| factory.module_denom_prefix == prefix)
+            .ok_or_else(|| anyhow!("No token factory for denom {}", denom))
+    }
+
+    /// Returns the factory the `MsgCreateDenom`s of `creator` are handled by.
+    fn factory_for_creator(&self, storage: &dyn Storage, creator: &str) -> AnyResult<&TokenFactory<'a>> {
+        match CREATOR_FACTORIES.may_load(storage, creator)? {
+            Some(prefix) => self
+                .factories
+                .iter()
+                .find(|factory| factory.module_denom_prefix == prefix)
+                .ok_or_else(|| anyhow!("No token factory with prefix {} for creator {}", prefix, creator)),
+            None => Ok(&self.factories[0]),
+        }
+    }
+}
+
+impl<'a> Module for TokenFactories<'a> {
+    type ExecT = StargateMsg;
+    type QueryT = StargateQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        let StargateMsg { type_url, value, .. } = msg;
+
+        let (factory, value) = match type_url.as_str() {
+            MsgCreateDenom::TYPE_URL => {
+                let msg: MsgCreateDenom = value.clone().try_into()?;
+                (self.factory_for_creator(storage, &msg.sender)?, value)
+            }
+            MsgMint::TYPE_URL => {
+                let msg: MsgMint = value.clone().try_into()?;
+                let denom = msg.amount.ok_or_else(|| anyhow!("missing amount"))?.denom;
+                (self.factory_for_denom(&denom)?, value)
+            }
+            MsgBurn::TYPE_URL => {
+                let msg: MsgBurn = value.clone().try_into()?;
+                let denom = msg.amount.ok_or_else(|| anyhow!("missing amount"))?.denom;
+                (self.factory_for_denom(&denom)?, value)
+            }
+            MsgForceTransfer::TYPE_URL => {
+                let msg: MsgForceTransfer = value.clone().try_into()?;
+                let denom = msg.amount.ok_or_else(|| anyhow!("missing amount"))?.denom;
+                (self.factory_for_denom(&denom)?, value)
+            }
+            MsgSetBeforeSendHook::TYPE_URL => {
+                let msg: MsgSetBeforeSendHook = value.clone().try_into()?;
+                (self.factory_for_denom(&msg.denom)?, value)
+            }
+            _ => bail!("Unknown message type {}", type_url),
+        };
+
+        factory.handle_any(api, storage, router, block, sender, type_url, value)
+    }
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request.path.as_str() {
+            QUERY_TOKENFACTORY_DENOMS_FROM_CREATOR_PATH => {
+                let req = QueryDenomsFromCreatorRequest::decode(request.data.as_slice())?;
+                let mut denoms = vec![];
+                for factory in &self.factories {
+                    denoms.extend(factory.creator_denoms(storage, &req.creator)?);
+                }
+                denoms.sort();
+
+                Ok(to_json_binary(&QueryDenomsFromCreatorResponse { denoms })?)
+            }
+            QUERY_TOKENFACTORY_DENOM_COUNT_PATH => {
+                let mut count = 0;
+                for factory in &self.factories {
+                    count += factory.denom_count(storage)?;
+                }
+
+                Ok(to_json_binary(&QueryDenomCountResponse { count })?)
+            }
+            QUERY_TOKENFACTORY_DENOM_EXISTS_PATH => {
+                let req = QueryDenomExistsRequest::decode(request.data.as_slice())?;
+                match self.factory_for_denom(&req.denom) {
+                    Ok(factory) => factory.query(api, storage, querier, block, request),
+                    Err(_) => Ok(to_json_binary(&QueryDenomExistsResponse { exists: false })?),
+                }
+            }
+            // Other queries are not specific to a denom, so they are answered by the first factory
+            _ => self.factories[0].query(api, storage, querier, block, request),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        Ok(AppResponse::default())
+    }
+}
+
+impl<'a> Stargate for TokenFactories<'a> {}
+
+pub(super) fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
     let denom_re = Regex::new(r"^[0-9]+[a-z]+$")?;
     let ibc_re = Regex::new(r"^[0-9]+(ibc|IBC)/[0-9A-F]{64}$")?;
     let factory_re = Regex::new(r"^[0-9]+factory/[0-9a-z]+/[0-9a-zA-Z]+$")?;
@@ -330,11 +815,12 @@ fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
 
     // Parse amount
     let re = Regex::new(r"[0-9]+")?;
-    let amount = re.find(sdk_string).unwrap().as_str();
-    let amount = Uint128::from_str(amount)?;
+    let amount_str = re.find(sdk_string).unwrap().as_str();
+    let amount = Uint128::from_str(amount_str)?;
 
-    // The denom is the rest of the string
-    let denom = sdk_string[amount.to_string().len()..].to_string();
+    // The denom is the rest of the string. Slice by the parsed digits rather than the formatted
+    // amount, as the two differ for amounts with leading zeros.
+    let denom = sdk_string[amount_str.len()..].to_string();
 
     Ok(Coin { denom, amount })
 }
@@ -342,8 +828,9 @@ fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::{BalanceResponse, Binary as StdBinary, CosmosMsg};
+    use cosmwasm_std::{Binary as StdBinary, CosmosMsg};
     use cw_multi_test::{BasicAppBuilder, Executor};
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::QueryParamsRequest;
     use test_case::test_case;
 
     const TOKEN_FACTORY: TokenFactory<'static> = TokenFactory::new("factory", 32, 16, 59 + 16, DEFAULT_INIT);
@@ -352,7 +839,7 @@ mod tests {
     #[test_case(Addr::unchecked("sen/der"), "subdenom", &[DEFAULT_INIT] => panics "creator address cannot contains" ; "invalid creator address")]
     #[test_case(Addr::unchecked("asdasdasdasdasdasdasdasdasdasdasdasdasdasdasd"), "subdenom", &[DEFAULT_INIT] => panics ; "creator address too long")]
     #[test_case(Addr::unchecked("sender"), "subdenom", &[DEFAULT_INIT, "100factory/sender/subdenom"] => panics "Subdenom already exists" ; "denom exists")]
-    #[test_case(Addr::unchecked("sender"), "subdenom", &[constcat::concat!("100000", DEFAULT_COIN_DENOM)] => panics "Cannot Sub" ; "insufficient funds for fee")]
+    #[test_case(Addr::unchecked("sender"), "subdenom", &[constcat::concat!("100000", DEFAULT_COIN_DENOM)] => panics "insufficient funds for denom creation fee" ; "insufficient funds for fee")]
     fn create_denom(sender: Addr, subdenom: &str, initial_coins: &[&str]) {
         let initial_coins = initial_coins.iter().map(|s| coin_from_sdk_string(s).unwrap()).collect::<Vec<_>>();
 
@@ -375,13 +862,16 @@ mod tests {
 
         let res = app.execute(sender.clone(), msg).unwrap();
 
+        let denom = format!("{}/{}/{}", TOKEN_FACTORY.module_denom_prefix, sender, subdenom);
         res.assert_event(
             &Event::new("create_denom")
                 .add_attribute("creator", sender.to_string())
-                .add_attribute(
-                    "new_token_denom",
-                    format!("{}/{}/{}", TOKEN_FACTORY.module_denom_prefix, sender, subdenom),
-                ),
+                .add_attribute("new_token_denom", &denom),
+        );
+        res.assert_event(
+            &Event::new("message")
+                .add_attribute("action", "create_denom")
+                .add_attribute("new_token_denom", &denom),
         );
 
         assert_eq!(
@@ -392,6 +882,404 @@ mod tests {
         );
     }
 
+    #[test]
+    fn query_params() {
+        let app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|_, _, _| {});
+
+        let res: QueryParamsResponse = app
+            .wrap()
+            .query(&QueryRequest::Stargate {
+                path: QUERY_TOKENFACTORY_PARAMS_PATH.to_string(),
+                data: QueryParamsRequest {}.into(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            res.params.unwrap().denom_creation_fee,
+            vec![coin_from_sdk_string(DEFAULT_INIT).unwrap().into()]
+        );
+    }
+
+    #[test]
+    fn query_denoms_from_creator() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(
+                        storage,
+                        &sender,
+                        vec![coin_from_sdk_string(constcat::concat!("100000000", DEFAULT_COIN_DENOM)).unwrap()],
+                    )
+                    .unwrap();
+            });
+
+        for subdenom in ["denom1", "denom2"] {
+            let msg = CosmosMsg::<Empty>::Stargate {
+                type_url: MsgCreateDenom::TYPE_URL.to_string(),
+                value: MsgCreateDenom {
+                    sender: sender.to_string(),
+                    subdenom: subdenom.to_string(),
+                }
+                .into(),
+            };
+            app.execute(sender.clone(), msg).unwrap();
+        }
+
+        let query_denoms = |creator: &str| -> Vec<String> {
+            let res: QueryDenomsFromCreatorResponse = app
+                .wrap()
+                .query(&QueryRequest::Stargate {
+                    path: QUERY_TOKENFACTORY_DENOMS_FROM_CREATOR_PATH.to_string(),
+                    data: QueryDenomsFromCreatorRequest {
+                        creator: creator.to_string(),
+                    }
+                    .into(),
+                })
+                .unwrap();
+            res.denoms
+        };
+
+        assert_eq!(
+            query_denoms("sender"),
+            vec!["factory/sender/denom1".to_string(), "factory/sender/denom2".to_string()]
+        );
+        assert!(query_denoms("other").is_empty());
+    }
+
+    #[test]
+    fn query_denom_count() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(
+                        storage,
+                        &sender,
+                        vec![coin_from_sdk_string(constcat::concat!("100000000", DEFAULT_COIN_DENOM)).unwrap()],
+                    )
+                    .unwrap();
+            });
+
+        let query_count = |app: &cw_multi_test::BasicApp| -> u64 {
+            let res: QueryDenomCountResponse = app
+                .wrap()
+                .query(&QueryRequest::Stargate {
+                    path: QUERY_TOKENFACTORY_DENOM_COUNT_PATH.to_string(),
+                    data: QueryDenomCountRequest {}.encode_to_vec().into(),
+                })
+                .unwrap();
+            res.count
+        };
+        assert_eq!(query_count(&app), 0);
+
+        for subdenom in ["denom1", "denom2", "denom3"] {
+            let msg = CosmosMsg::<Empty>::Stargate {
+                type_url: MsgCreateDenom::TYPE_URL.to_string(),
+                value: MsgCreateDenom {
+                    sender: sender.to_string(),
+                    subdenom: subdenom.to_string(),
+                }
+                .into(),
+            };
+            app.execute(sender.clone(), msg).unwrap();
+        }
+
+        assert_eq!(query_count(&app), 3);
+    }
+
+    #[test]
+    fn query_denom_exists() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string(DEFAULT_INIT).unwrap()])
+                    .unwrap();
+            });
+
+        let denom_exists = |app: &cw_multi_test::BasicApp, denom: &str| -> bool {
+            let res: QueryDenomExistsResponse = app
+                .wrap()
+                .query(&QueryRequest::Stargate {
+                    path: QUERY_TOKENFACTORY_DENOM_EXISTS_PATH.to_string(),
+                    data: QueryDenomExistsRequest { denom: denom.to_string() }.encode_to_vec().into(),
+                })
+                .unwrap();
+            res.exists
+        };
+        assert!(!denom_exists(&app, "factory/sender/subdenom"));
+
+        let msg = CosmosMsg::<Empty>::Stargate {
+            type_url: MsgCreateDenom::TYPE_URL.to_string(),
+            value: MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: "subdenom".to_string(),
+            }
+            .into(),
+        };
+        app.execute(sender.clone(), msg).unwrap();
+
+        // The denom exists before anything is minted
+        assert!(app.wrap().query_supply("factory/sender/subdenom").unwrap().amount.is_zero());
+        assert!(denom_exists(&app, "factory/sender/subdenom"));
+        assert!(!denom_exists(&app, "factory/sender/other"));
+    }
+
+    #[test]
+    fn before_send_hook() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string(DEFAULT_INIT).unwrap()])
+                    .unwrap();
+            });
+        let denom = "factory/sender/subdenom";
+
+        let msg = CosmosMsg::<Empty>::Stargate {
+            type_url: MsgCreateDenom::TYPE_URL.to_string(),
+            value: MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: "subdenom".to_string(),
+            }
+            .into(),
+        };
+        app.execute(sender.clone(), msg).unwrap();
+
+        let set_hook = |app: &mut cw_multi_test::BasicApp, sender: &Addr, hook: &str| {
+            let msg = CosmosMsg::<Empty>::Stargate {
+                type_url: MsgSetBeforeSendHook::TYPE_URL.to_string(),
+                value: MsgSetBeforeSendHook {
+                    sender: sender.to_string(),
+                    denom: denom.to_string(),
+                    cosmwasm_address: hook.to_string(),
+                }
+                .into(),
+            };
+            app.execute(sender.clone(), msg)
+        };
+        let query_hook = |app: &cw_multi_test::BasicApp| -> String {
+            let res: QueryBeforeSendHookAddressResponse = app
+                .wrap()
+                .query(&QueryRequest::Stargate {
+                    path: QUERY_TOKENFACTORY_BEFORE_SEND_HOOK_PATH.to_string(),
+                    data: QueryBeforeSendHookAddressRequest { denom: denom.to_string() }
+                        .encode_to_vec()
+                        .into(),
+                })
+                .unwrap();
+            res.cosmwasm_address
+        };
+        assert_eq!(query_hook(&app), "");
+
+        set_hook(&mut app, &sender, "hook").unwrap();
+        assert_eq!(query_hook(&app), "hook");
+
+        // Only the admin of the denom can set the hook
+        let err = set_hook(&mut app, &Addr::unchecked("other"), "other_hook").unwrap_err();
+        assert!(format!("{:#}", err).contains("Not the admin of the denom"));
+        assert_eq!(query_hook(&app), "hook");
+
+        // An empty address clears the hook
+        set_hook(&mut app, &sender, "").unwrap();
+        assert_eq!(query_hook(&app), "");
+    }
+
+    #[test]
+    fn create_denom_without_fee_coin() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string("10000000uother").unwrap()])
+                    .unwrap();
+            });
+
+        let msg = CosmosMsg::<Empty>::Stargate {
+            type_url: MsgCreateDenom::TYPE_URL.to_string(),
+            value: MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: "subdenom".to_string(),
+            }
+            .into(),
+        };
+
+        let err = app.execute(sender.clone(), msg).unwrap_err();
+        assert!(format!("{:#}", err).contains("insufficient funds for denom creation fee"));
+        assert!(!DENOM_ADMINS.has(app.storage(), "factory/sender/subdenom"));
+    }
+
+    #[test]
+    fn fee_collector() {
+        let sender = Addr::unchecked("sender");
+        let stargate = TOKEN_FACTORY.clone().with_fee_collector("collector");
+        let fee = coin_from_sdk_string(DEFAULT_INIT).unwrap();
+
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(stargate)
+            .build(|router, _, storage| {
+                router.bank.init_balance(storage, &sender, vec![fee.clone()]).unwrap();
+            });
+
+        let msg = CosmosMsg::<Empty>::Stargate {
+            type_url: MsgCreateDenom::TYPE_URL.to_string(),
+            value: MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: "subdenom".to_string(),
+            }
+            .into(),
+        };
+        app.execute(sender.clone(), msg).unwrap();
+
+        // The fee is moved to the collector rather than burned
+        let collector_balance = app.wrap().query_balance("collector", &fee.denom).unwrap();
+        assert_eq!(collector_balance, fee);
+        assert!(app.wrap().query_balance(&sender, &fee.denom).unwrap().amount.is_zero());
+        assert_eq!(app.wrap().query_supply(&fee.denom).unwrap(), fee);
+    }
+
+    #[test]
+    fn max_denoms_per_creator() {
+        let sender = Addr::unchecked("sender");
+        let stargate = TOKEN_FACTORY.clone().with_max_denoms_per_creator(2);
+
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(stargate)
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string(constcat::concat!("100000000", DEFAULT_COIN_DENOM)).unwrap()])
+                    .unwrap();
+            });
+
+        let create_denom = |subdenom: &str| CosmosMsg::<Empty>::Stargate {
+            type_url: MsgCreateDenom::TYPE_URL.to_string(),
+            value: MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: subdenom.to_string(),
+            }
+            .into(),
+        };
+
+        app.execute(sender.clone(), create_denom("denom1")).unwrap();
+        app.execute(sender.clone(), create_denom("denom2")).unwrap();
+        let err = app.execute(sender.clone(), create_denom("denom3")).unwrap_err();
+        assert!(format!("{:#}", err).contains("Creator has reached the maximum number of denoms: 2"));
+    }
+
+    #[test]
+    fn multiple_prefixes() {
+        let sender = Addr::unchecked("sender");
+        let stargate = TokenFactories::new(vec![
+            TOKEN_FACTORY.clone().with_max_denoms_per_creator(1),
+            TokenFactory::new("denom", 32, 16, 59 + 16, DEFAULT_INIT).with_max_denoms_per_creator(1),
+        ]);
+
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(stargate)
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(
+                        storage,
+                        &sender,
+                        vec![coin_from_sdk_string(constcat::concat!("100000000", DEFAULT_COIN_DENOM)).unwrap()],
+                    )
+                    .unwrap();
+            });
+
+        let create_denom = |subdenom: &str| CosmosMsg::<Empty>::Stargate {
+            type_url: MsgCreateDenom::TYPE_URL.to_string(),
+            value: MsgCreateDenom {
+                sender: sender.to_string(),
+                subdenom: subdenom.to_string(),
+            }
+            .into(),
+        };
+        let mint = |denom: &str| CosmosMsg::<Empty>::Stargate {
+            type_url: MsgMint::TYPE_URL.to_string(),
+            value: MsgMint {
+                sender: sender.to_string(),
+                amount: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
+                    denom: denom.to_string(),
+                    amount: "1000".to_string(),
+                }),
+                mint_to_address: sender.to_string(),
+            }
+            .into(),
+        };
+
+        // Creators without a route go to the first factory, also for subdenoms containing a '/'
+        let res = app.execute(sender.clone(), create_denom("denom/token")).unwrap();
+        let factory_denom = "factory/sender/denom/token";
+        assert_eq!(
+            res.data.unwrap(),
+            StdBinary::from(MsgCreateDenomResponse {
+                new_token_denom: factory_denom.to_string()
+            })
+        );
+
+        // The max denom count is per factory, so the creator can still create a denom in the
+        // factory it is routed to
+        app.init_modules(|_, _, storage| TokenFactories::route_creator(storage, sender.as_str(), "denom"))
+            .unwrap();
+        let res = app.execute(sender.clone(), create_denom("token")).unwrap();
+        let custom_denom = "denom/sender/token";
+        assert_eq!(
+            res.data.unwrap(),
+            StdBinary::from(MsgCreateDenomResponse {
+                new_token_denom: custom_denom.to_string()
+            })
+        );
+        let err = app.execute(sender.clone(), create_denom("other")).unwrap_err();
+        assert!(format!("{:#}", err).contains("Creator has reached the maximum number of denoms: 1"));
+
+        // Both denoms can be minted independently
+        app.execute(sender.clone(), mint(factory_denom)).unwrap();
+        app.execute(sender.clone(), mint(custom_denom)).unwrap();
+        app.execute(sender.clone(), mint(custom_denom)).unwrap();
+        assert_eq!(app.wrap().query_balance(&sender, factory_denom).unwrap().amount.u128(), 1000);
+        assert_eq!(app.wrap().query_balance(&sender, custom_denom).unwrap().amount.u128(), 2000);
+
+        // Denoms of unknown prefixes are rejected
+        let err = app.execute(sender.clone(), mint("other/sender/token")).unwrap_err();
+        assert!(format!("{:#}", err).contains("No token factory for denom other/sender/token"));
+
+        // Queries combine the denoms of both factories
+        let res: QueryDenomsFromCreatorResponse = app
+            .wrap()
+            .query(&QueryRequest::Stargate {
+                path: QUERY_TOKENFACTORY_DENOMS_FROM_CREATOR_PATH.to_string(),
+                data: QueryDenomsFromCreatorRequest {
+                    creator: sender.to_string(),
+                }
+                .into(),
+            })
+            .unwrap();
+        assert_eq!(res.denoms, vec![custom_denom.to_string(), factory_denom.to_string()]);
+        let res: QueryDenomCountResponse = app
+            .wrap()
+            .query(&QueryRequest::Stargate {
+                path: QUERY_TOKENFACTORY_DENOM_COUNT_PATH.to_string(),
+                data: QueryDenomCountRequest {}.encode_to_vec().into(),
+            })
+            .unwrap();
+        assert_eq!(res.count, 2);
+    }
+
     #[test_case(Addr::unchecked("sender"), Addr::unchecked("sender"), 1000u128 ; "valid mint")]
     #[test_case(Addr::unchecked("sender"), Addr::unchecked("sender"), 0u128 => panics "Invalid zero amount" ; "zero amount")]
     #[test_case(Addr::unchecked("sender"), Addr::unchecked("creator"), 1000u128 => panics "Unauthorized mint. Not the creator of the denom." ; "sender is not creator")]
@@ -494,6 +1382,57 @@ mod tests {
         assert_eq!(balance.u128(), initial_balance - burn_amount);
     }
 
+    #[test_case(Addr::unchecked("admin"), 1000u128, 1000u128 ; "valid force transfer")]
+    #[test_case(Addr::unchecked("admin"), 2000u128, 1000u128 => panics "insufficient funds" ; "insufficient balance")]
+    #[test_case(Addr::unchecked("sender"), 1000u128, 1000u128 => panics "Unauthorized force transfer. Not the admin of the denom." ; "sender is not admin")]
+    fn force_transfer(sender: Addr, transfer_amount: u128, initial_balance: u128) {
+        let stargate = TOKEN_FACTORY.clone();
+
+        let tf_denom = format!("{}/{}/{}", TOKEN_FACTORY.module_denom_prefix, "admin", "subdenom");
+        let from = Addr::unchecked("from");
+        let to = Addr::unchecked("to");
+
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(stargate)
+            .build(|router, _, storage| {
+                DENOM_ADMINS.save(storage, &tf_denom, &"admin".to_string()).unwrap();
+                router
+                    .bank
+                    .init_balance(storage, &from, vec![Coin::new(initial_balance, tf_denom.clone())])
+                    .unwrap();
+            });
+
+        let msg = CosmosMsg::<Empty>::Stargate {
+            type_url: MsgForceTransfer::TYPE_URL.to_string(),
+            value: MsgForceTransfer {
+                sender: sender.to_string(),
+                amount: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
+                    denom: tf_denom.clone(),
+                    amount: transfer_amount.to_string(),
+                }),
+                transfer_from_address: from.to_string(),
+                transfer_to_address: to.to_string(),
+            }
+            .into(),
+        };
+
+        let res = app.execute(sender.clone(), msg).unwrap();
+
+        // Assert event
+        res.assert_event(
+            &Event::new("force_transfer")
+                .add_attribute("transfer_from_address", from.to_string())
+                .add_attribute("transfer_to_address", to.to_string())
+                .add_attribute("amount", format!("{}{}", transfer_amount, tf_denom)),
+        );
+
+        // Query bank balances
+        let from_balance = app.wrap().query_balance(&from, &tf_denom).unwrap().amount;
+        let to_balance = app.wrap().query_balance(&to, &tf_denom).unwrap().amount;
+        assert_eq!(from_balance.u128(), initial_balance - transfer_amount);
+        assert_eq!(to_balance.u128(), transfer_amount);
+    }
+
     #[test_case(DEFAULT_COIN_DENOM ; "native denom")]
     #[test_case("IBC/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2" ; "ibc denom")]
     #[test_case("IBC/27394FB092D2ECCD56123CA622B25F41E5EB2" => panics "Invalid sdk string" ; "invalid ibc denom")]
@@ -508,4 +1447,68 @@ mod tests {
         assert_eq!(coin.denom, denom);
         assert_eq!(coin.amount, Uint128::from(1000u128));
     }
+
+    /// A straightforward parser for the coin strings accepted by [`coin_from_sdk_string`], to
+    /// compare it against: the leading digits are the amount and the rest is the denom, which must
+    /// be a native, IBC or factory denom.
+    fn reference_parse(sdk_string: &str) -> Option<Coin> {
+        let digits = sdk_string.chars().take_while(|c| c.is_ascii_digit()).count();
+        let (amount, denom) = sdk_string.split_at(digits);
+        if amount.is_empty() {
+            return None;
+        }
+
+        let is_native = !denom.is_empty() && denom.chars().all(|c| c.is_ascii_lowercase());
+        let is_ibc = match denom.strip_prefix("ibc/").or_else(|| denom.strip_prefix("IBC/")) {
+            Some(hash) => hash.len() == 64 && hash.chars().all(|c| c.is_ascii_digit() || ('A'..='F').contains(&c)),
+            None => false,
+        };
+        let is_factory = match denom.strip_prefix("factory/").and_then(|rest| rest.split_once('/')) {
+            Some((creator, subdenom)) => {
+                !creator.is_empty()
+                    && creator.chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+                    && !subdenom.is_empty()
+                    && subdenom.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            None => false,
+        };
+        if !(is_native || is_ibc || is_factory) {
+            return None;
+        }
+
+        Some(Coin {
+            denom: denom.to_string(),
+            amount: Uint128::new(amount.parse().ok()?),
+        })
+    }
+
+    fn sdk_string_strategy() -> impl proptest::strategy::Strategy<Value = String> {
+        use proptest::prelude::*;
+
+        let amount = prop_oneof![
+            any::<u128>().prop_map(|amount| amount.to_string()),
+            // Leading zeros and amounts that overflow a u128
+            "0{0,3}[0-9]{1,45}",
+            Just(String::new()),
+        ];
+        let denom = prop_oneof![
+            "[a-z]{1,12}",
+            "(ibc|IBC)/[0-9A-F]{64}",
+            "factory/[0-9a-z]{1,20}/[0-9a-zA-Z]{1,20}",
+            // Near misses of the valid denoms and arbitrary garbage
+            "[a-zA-Z0-9/ -]{0,20}",
+            "(ibc|IBC)/[0-9A-Fa-f]{60,68}",
+            "factory/[0-9a-zA-Z/]{0,20}",
+            ".{0,20}",
+        ];
+        (amount, denom).prop_map(|(amount, denom)| format!("{}{}", amount, denom))
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn coin_from_sdk_string_matches_reference(sdk_string in sdk_string_strategy()) {
+            let parsed = coin_from_sdk_string(&sdk_string).ok();
+            proptest::prop_assert_eq!(parsed, reference_parse(&sdk_string), "sdk string: {:?}", sdk_string);
+        }
+    }
 }
@@ -1,11 +1,14 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use anyhow::{anyhow, bail, Result as AnyResult};
 use coreum_wasm_sdk::types::coreum::asset::ft::v1::{
-    MsgBurn, MsgIssue, MsgMint, QueryTokenRequest, QueryTokenResponse, QueryTokensRequest, QueryTokensResponse, Token,
+    Feature, MsgBurn, MsgClawback, MsgFreeze, MsgGloballyFreeze, MsgGloballyUnfreeze, MsgIssue, MsgMint, MsgSetWhitelistedLimit,
+    MsgUnfreeze, Params, QueryParamsResponse, QueryTokenRequest, QueryTokenResponse, QueryTokensRequest, QueryTokensResponse, Token,
 };
 use coreum_wasm_sdk::types::coreum::asset::nft::v1::{
-    Class, ClassFeature, MsgBurn as MsgNftBurn, MsgIssueClass, MsgMint as MsgNftMint, QueryClassRequest, QueryClassResponse,
+    Class, ClassFeature, DataDynamic, DataEditor, MsgAddToWhitelist, MsgBurn as MsgNftBurn, MsgFreeze as MsgNftFreeze, MsgIssueClass,
+    MsgMint as MsgNftMint, MsgRemoveFromWhitelist, MsgUnfreeze as MsgNftUnfreeze, MsgUpdateData, QueryClassRequest, QueryClassResponse,
     QueryClassesRequest, QueryClassesResponse,
 };
 use coreum_wasm_sdk::types::cosmos::nft::v1beta1::{
@@ -14,8 +17,8 @@ use coreum_wasm_sdk::types::cosmos::nft::v1beta1::{
 };
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Api, BankMsg, BankQuery, Binary, BlockInfo, Coin, CustomMsg, CustomQuery, Empty, Event, Querier,
-    QueryRequest, Storage, SupplyResponse, Uint128,
+    from_json, to_json_binary, Addr, Api, BalanceResponse, BankMsg, BankQuery, Binary, BlockInfo, Coin, CustomMsg, CustomQuery, Decimal,
+    Empty, Event, Querier, QueryRequest, Storage, SupplyResponse, Uint128,
 };
 use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module, Stargate, StargateMsg, StargateQuery, SudoMsg};
 use cw_storage_plus::{Item, Map};
@@ -29,6 +32,7 @@ use coreum_wasm_sdk::{
 use coreum_wasm_sdk::nft::{NFTResponse, NFTsResponse, OwnerResponse};
 use coreum_wasm_sdk::pagination::PageRequest;
 
+use crate::multi_test::modules::unified_stargate::track_minted_denoms;
 use crate::traits::{CREATE_TOKEN_FEE, DEFAULT_COIN_DENOM};
 
 const DEFAULT_INIT: &str = constcat::concat!(CREATE_TOKEN_FEE, DEFAULT_COIN_DENOM);
@@ -39,6 +43,216 @@ const DEFAULT_INIT: &str = constcat::concat!(CREATE_TOKEN_FEE, DEFAULT_COIN_DENO
 /// e.g. `ashare-core1xyz...`.
 pub const ISSUED_TOKENS: Map<&str, MsgIssue> = Map::new("coreum_assetft/issued");
 
+/// Set of **denoms** that are globally frozen.
+pub const GLOBALLY_FROZEN_DENOMS: Map<&str, Empty> = Map::new("coreum_assetft/globally_frozen");
+
+/// Map of **(denom, account) -> frozen amount**.
+pub const FROZEN_BALANCES: Map<(&str, &str), Uint128> = Map::new("coreum_assetft/frozen_balances");
+
+/// Checks that `account` may send `coin` out of its `balance`, given the freezing state of the denom.
+///
+/// Like on chain, the issuer of a denom is not affected by freezing. Globally frozen denoms can't be
+/// sent by anyone else, and frozen amounts can't be sent by the account they are frozen for.
+///
+/// NB: cw-multi-test's bank module has no send hooks, so this is only enforced for the bank sends
+/// executed through the [`crate::multi_test::MultiTestRunner`], not for `BankMsg::Send` emitted by contracts.
+pub fn assert_spendable(storage: &dyn Storage, account: &str, coin: &Coin, balance: Uint128) -> AnyResult<()> {
+    let Some(issue) = ISSUED_TOKENS.may_load(storage, &coin.denom)? else {
+        return Ok(());
+    };
+    if issue.issuer == account {
+        return Ok(());
+    }
+
+    if GLOBALLY_FROZEN_DENOMS.has(storage, &coin.denom) {
+        bail!("denom {} is globally frozen", coin.denom);
+    }
+
+    let frozen = FROZEN_BALANCES.may_load(storage, (&coin.denom, account))?.unwrap_or_default();
+    let spendable = balance.saturating_sub(frozen);
+    if spendable < coin.amount {
+        bail!(
+            "insufficient funds: {} has {}{} spendable ({}{} frozen), but needs {}",
+            account,
+            spendable,
+            coin.denom,
+            frozen,
+            coin.denom,
+            coin
+        );
+    }
+    Ok(())
+}
+
+/// Map of **denom -> maximum supply** for every token issued with a mint cap.
+pub const MINT_CAPS: Map<&str, Uint128> = Map::new("coreum_assetft/mint_caps");
+
+/// Checks that minting `amount` of `denom` does not take its `supply` above its mint cap, if it
+/// has one.
+fn assert_within_mint_cap(storage: &dyn Storage, denom: &str, supply: Uint128, amount: Uint128) -> AnyResult<()> {
+    let Some(cap) = MINT_CAPS.may_load(storage, denom)? else {
+        return Ok(());
+    };
+    if supply.checked_add(amount)? > cap {
+        bail!(
+            "mint cap exceeded: {} has a supply of {}{}, minting {}{} would exceed the cap of {}{}",
+            denom,
+            supply,
+            denom,
+            amount,
+            denom,
+            cap,
+            denom
+        );
+    }
+    Ok(())
+}
+
+/// Map of **(denom, account) -> whitelisted limit**.
+pub const WHITELISTED_LIMITS: Map<(&str, &str), Uint128> = Map::new("coreum_assetft/whitelisted_limits");
+
+/// Checks that `account`, currently holding `balance`, may receive `coin` given the whitelisting
+/// state of the denom. Only enforced for denoms with the whitelisting feature, and never for the issuer.
+///
+/// NB: like [`assert_spendable`], this is only enforced for mints and for the bank sends executed
+/// through the [`crate::multi_test::MultiTestRunner`].
+pub fn assert_receivable(storage: &dyn Storage, account: &str, coin: &Coin, balance: Uint128) -> AnyResult<()> {
+    let Some(issue) = ISSUED_TOKENS.may_load(storage, &coin.denom)? else {
+        return Ok(());
+    };
+    if issue.issuer == account || !issue.features.contains(&(Feature::Whitelisting as i32)) {
+        return Ok(());
+    }
+
+    let limit = WHITELISTED_LIMITS.may_load(storage, (&coin.denom, account))?.unwrap_or_default();
+    if balance.checked_add(coin.amount)? > limit {
+        bail!(
+            "whitelisted limit exceeded: {} may hold at most {}{}, but would hold {}{}",
+            account,
+            limit,
+            coin.denom,
+            balance + coin.amount,
+            coin.denom
+        );
+    }
+    Ok(())
+}
+
+/// Parses a `burn_rate` or `send_commission_rate` of a [`MsgIssue`], treating an empty rate as zero.
+fn parse_rate(rate: &str) -> AnyResult<Decimal> {
+    if rate.is_empty() {
+        return Ok(Decimal::zero());
+    }
+    let parsed = Decimal::from_str(rate).map_err(|e| anyhow!("invalid rate `{}`: {}", rate, e))?;
+    if parsed > Decimal::one() {
+        bail!("invalid rate `{}`: must be between 0 and 1", rate);
+    }
+    Ok(parsed)
+}
+
+/// Formats a rate of a [`MsgIssue`] the way the token queries report it.
+fn format_rate(rate: &str) -> String {
+    if rate.is_empty() {
+        "0".to_string()
+    } else {
+        rate.to_string()
+    }
+}
+
+/// Amounts deducted from a transfer of a denom issued through the TokenFactory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferDeductions {
+    /// Amount burned, as per the `burn_rate` of the denom.
+    pub burn: Uint128,
+    /// Amount sent to the issuer, as per the `send_commission_rate` of the denom.
+    pub commission: Uint128,
+    /// The issuer of the denom, receiving the commission.
+    pub issuer: String,
+}
+
+/// Computes the `burn_rate` and `send_commission_rate` deductions for sending `coin` from `sender`
+/// to `recipient`. Like on chain, no rates apply to transfers from or to the issuer, and the
+/// deducted amounts are rounded up.
+///
+/// NB: on chain the rates are charged to the sender on top of the sent amount, whereas here they are
+/// deducted from it, so the recipient receives the net amount. Like [`assert_spendable`], this is only
+/// applied to the bank sends executed through the [`crate::multi_test::MultiTestRunner`].
+pub fn transfer_deductions(storage: &dyn Storage, sender: &str, recipient: &str, coin: &Coin) -> AnyResult<TransferDeductions> {
+    let Some(issue) = ISSUED_TOKENS.may_load(storage, &coin.denom)? else {
+        return Ok(TransferDeductions::default());
+    };
+    if issue.issuer == sender || issue.issuer == recipient {
+        return Ok(TransferDeductions::default());
+    }
+
+    Ok(TransferDeductions {
+        burn: coin.amount.mul_ceil(parse_rate(&issue.burn_rate)?),
+        commission: coin.amount.mul_ceil(parse_rate(&issue.send_commission_rate)?),
+        issuer: issue.issuer,
+    })
+}
+
+/// The page size used when a query does not set a `limit`, like the cosmos sdk's `DefaultLimit`.
+const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// A page of query results, as returned by [`paginate`].
+struct Page<T> {
+    items: Vec<T>,
+    next_key: Option<Vec<u8>>,
+    total: u64,
+}
+
+/// Sorts `items` by their key and returns the page selected by `key` (or `offset` if no key is
+/// given) and `limit`, where a `limit` of zero returns [`DEFAULT_PAGE_LIMIT`] items. The `next_key`
+/// of the page is the key of the first item of the next page, if any.
+fn paginate<T>(mut items: Vec<(String, T)>, key: Option<&[u8]>, offset: u64, limit: u64, reverse: bool) -> Page<T> {
+    items.sort_by(|(a, _), (b, _)| a.cmp(b));
+    if reverse {
+        items.reverse();
+    }
+    let total = items.len() as u64;
+
+    let start = match key {
+        Some(key) if !key.is_empty() => items
+            .iter()
+            .position(|(k, _)| if reverse { k.as_bytes() <= key } else { k.as_bytes() >= key })
+            .unwrap_or(items.len()),
+        _ => (offset as usize).min(items.len()),
+    };
+    let limit = if limit == 0 { DEFAULT_PAGE_LIMIT } else { limit };
+    let end = start.saturating_add(limit as usize).min(items.len());
+
+    let next_key = items.get(end).map(|(k, _)| k.as_bytes().to_vec());
+    let items = items.into_iter().skip(start).take(end - start).map(|(_, item)| item).collect();
+    Page { items, next_key, total }
+}
+
+/// [`paginate`]s `items` according to a wasm query's `pagination`, returning the first page if none is given.
+fn paginate_query<T>(items: Vec<(String, T)>, pagination: Option<PageRequest>) -> (Vec<T>, coreum_wasm_sdk::pagination::PageResponse) {
+    let page = match pagination {
+        Some(pagination) => paginate(
+            items,
+            pagination.key.as_ref().map(|k| k.as_slice()),
+            pagination.offset.unwrap_or_default(),
+            pagination.limit.unwrap_or_default(),
+            pagination.reverse.unwrap_or_default(),
+        ),
+        None => paginate(items, None, 0, 0, false),
+    };
+    let response = coreum_wasm_sdk::pagination::PageResponse {
+        next_key: page.next_key.map(Binary::from),
+        total: Some(page.total),
+    };
+    (page.items, response)
+}
+
+/// Returns all denoms issued through the TokenFactory, in ascending order.
+pub fn created_denoms(storage: &dyn Storage) -> AnyResult<Vec<String>> {
+    Ok(ISSUED_TOKENS
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<Result<_, _>>()?)
+}
+
 /// Map of **class_id -> MsgIssueClass definition**
 pub const ISSUED_NFT_CLASSES: Map<&str, MsgIssueClass> = Map::new("coreum_assetnft/issued_classes");
 
@@ -52,9 +266,25 @@ pub struct StoredNft {
     pub data: Option<coreum_wasm_sdk::shim::Any>,
 }
 
+/// Stargate query path of the cosmos nft module's NFT query.
+pub const QUERY_NFT_PATH: &str = "/cosmos.nft.v1beta1.Query/NFT";
+
+/// Stargate query path of the cosmos nft module's NFTs query.
+pub const QUERY_NFTS_PATH: &str = "/cosmos.nft.v1beta1.Query/NFTs";
+
+/// Stargate query path of the coreum assetft module's Params query, answered with the issue fee
+/// of the [`TokenFactory`].
+pub const QUERY_ASSETFT_PARAMS_PATH: &str = "/coreum.asset.ft.v1.Query/Params";
+
 /// (class_id, nft_id) -> StoredNft
 pub const MINTED_NFTS: Map<(&str, &str), StoredNft> = Map::new("coreum_assetnft/minted");
 
+/// Set of frozen NFTs, keyed by **(class_id, nft_id)**.
+pub const FROZEN_NFTS: Map<(&str, &str), Empty> = Map::new("coreum_assetnft/frozen");
+
+/// Set of accounts whitelisted to receive an NFT, keyed by **(class_id, nft_id, account)**.
+pub const WHITELISTED_NFT_ACCOUNTS: Map<(&str, &str, &str), Empty> = Map::new("coreum_assetnft/whitelisted");
+
 /// This is a struct that implements the [`cw_multi_test::Stargate`] trait to
 /// mimic the behavior of the Osmosis TokenFactory module.
 #[derive(Clone)]
@@ -64,10 +294,17 @@ pub struct TokenFactory<'a> {
     pub max_hrp_len: usize,
     pub max_creator_len: usize,
     pub denom_creation_fee: &'a str,
+    /// Map of **denom -> maximum supply** for tokens issued through this factory, stored in
+    /// [`MINT_CAPS`] when the denom is issued. Denoms without a cap are unlimited.
+    pub mint_caps: BTreeMap<String, Uint128>,
 }
 
-#[derive(Clone, Default)]
-pub struct CoreumQueryModule;
+/// Custom module answering the [`CoreumQueries`] issued by contracts.
+#[derive(Clone)]
+pub struct CoreumQueryModule {
+    /// The precision (number of decimals) reported for the native denom [`DEFAULT_COIN_DENOM`].
+    pub native_denom_precision: u32,
+}
 
 impl<'a> TokenFactory<'a> {
     /// Creates a new TokenFactory instance with the given parameters.
@@ -84,8 +321,22 @@ impl<'a> TokenFactory<'a> {
             max_hrp_len,
             max_creator_len,
             denom_creation_fee,
+            mint_caps: BTreeMap::new(),
         }
     }
+
+    /// Limits the supply of `denom` to `cap` once it is issued, e.g. `subunit-issuer`.
+    pub fn with_mint_cap(mut self, denom: impl Into<String>, cap: Uint128) -> Self {
+        self.mint_caps.insert(denom.into(), cap);
+        self
+    }
+
+    /// Returns the configured denom creation fee as a [`Coin`], or `None` if creating denoms is free.
+    pub fn denom_creation_fee_coin(&self) -> Option<Coin> {
+        coin_from_sdk_string(self.denom_creation_fee)
+            .ok()
+            .filter(|fee| !fee.amount.is_zero())
+    }
 }
 
 impl Default for TokenFactory<'_> {
@@ -94,6 +345,19 @@ impl Default for TokenFactory<'_> {
     }
 }
 
+impl CoreumQueryModule {
+    /// Creates a new CoreumQueryModule reporting the given precision for the native denom.
+    pub const fn new(native_denom_precision: u32) -> Self {
+        Self { native_denom_precision }
+    }
+}
+
+impl Default for CoreumQueryModule {
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
 impl TokenFactory<'_> {
     /// Utility: build the Coreum FT denom from MsgIssue.
     ///
@@ -131,8 +395,9 @@ impl TokenFactory<'_> {
             subunit: issue.subunit.clone(),
             precision: issue.precision,
             description: issue.description.clone(),
-            // we might add feature flags / booleans later:
-            // burn_rate, send_commission_rate, etc...
+            features: issue.features.clone(),
+            burn_rate: format_rate(&issue.burn_rate),
+            send_commission_rate: format_rate(&issue.send_commission_rate),
             ..Token::default()
         }
     }
@@ -151,6 +416,7 @@ impl TokenFactory<'_> {
         ExecC: CustomMsg + DeserializeOwned + 'static,
         QueryC: CustomQuery + DeserializeOwned + 'static,
     {
+        track_minted_denoms(storage, &coins)?;
         let sudo = SudoMsg::Bank(BankSudo::Mint {
             to_address: to.to_string(),
             amount: coins,
@@ -261,9 +527,17 @@ impl TokenFactory<'_> {
             bail!("symbol must match regex format '^[a-zA-Z][a-zA-Z0-9/:._-]{{2,127}}$': invalid input");
         }
 
+        // Validate rates, which are deducted from the sent amount and so can't exceed it together
+        if parse_rate(&msg.burn_rate)? + parse_rate(&msg.send_commission_rate)? > Decimal::one() {
+            bail!("burn_rate and send_commission_rate can't exceed 1 together");
+        }
+
         let denom = Self::issue_to_denom(&msg);
 
         ISSUED_TOKENS.save(storage, denom.as_str(), &msg)?;
+        if let Some(cap) = self.mint_caps.get(&denom) {
+            MINT_CAPS.save(storage, denom.as_str(), cap)?;
+        }
 
         // Query supply of denom
         let request = QueryRequest::Bank(BankQuery::Supply { denom: denom.clone() });
@@ -286,6 +560,7 @@ impl TokenFactory<'_> {
                 .map_err(|e| anyhow::anyhow!("invalid initial_amount `{}`: {e}", msg.initial_amount))?;
 
             if amount_u128 > 0 {
+                assert_within_mint_cap(storage, &denom, Uint128::zero(), amount_u128.into())?;
                 let coin = Coin {
                     denom: denom.clone(),
                     amount: amount_u128.into(),
@@ -345,6 +620,11 @@ impl TokenFactory<'_> {
             bail!("Invalid zero amount");
         }
 
+        // Validate the mint does not exceed the mint cap of the denom
+        let request = QueryRequest::Bank(BankQuery::Supply { denom: denom.to_string() });
+        let supply: SupplyResponse = from_json(router.query(api, storage, block, request)?)?;
+        assert_within_mint_cap(storage, denom, supply.amount.amount, amount)?;
+
         // Determine recipient
         let recipient = if msg.recipient.is_empty() {
             msg.sender.clone()
@@ -352,13 +632,29 @@ impl TokenFactory<'_> {
             msg.recipient.clone()
         };
 
+        // Validate the recipient may receive the minted amount
+        let request = QueryRequest::Bank(BankQuery::Balance {
+            address: recipient.clone(),
+            denom: denom.to_string(),
+        });
+        let balance: cosmwasm_std::BalanceResponse = from_json(router.query(api, storage, block, request)?)?;
+        assert_receivable(
+            storage,
+            &recipient,
+            &Coin {
+                denom: denom.to_string(),
+                amount,
+            },
+            balance.amount.amount,
+        )?;
+
         // Mint through BankKeeper sudo method
         let mut res = self.bank_mint::<ExecC, QueryC>(
             api,
             storage,
             router,
             block,
-            &msg.recipient,
+            &recipient,
             vec![Coin {
                 denom: coin.denom.clone(),
                 amount,
@@ -432,6 +728,182 @@ impl TokenFactory<'_> {
         Ok(res)
     }
 
+    /// Loads the issue definition of `denom`, validating that `sender` is its issuer and that the
+    /// freezing feature is enabled for it.
+    fn load_freezable(storage: &dyn Storage, denom: &str, sender: &Addr, msg_sender: &str) -> AnyResult<MsgIssue> {
+        if sender.to_string() != msg_sender {
+            bail!("Invalid sender. Sender in msg must be same as sender of transaction.");
+        }
+        let Some(issue) = ISSUED_TOKENS.may_load(storage, denom)? else {
+            bail!("Unknown Coreum FT denom `{}`", denom);
+        };
+        if issue.issuer != sender.to_string() {
+            bail!("Unauthorized. Not the issuer of the denom.");
+        }
+        if !issue.features.contains(&(Feature::Freezing as i32)) {
+            bail!("Feature freezing is disabled for denom `{}`", denom);
+        }
+        Ok(issue)
+    }
+
+    fn globally_freeze(&self, msg: &MsgGloballyFreeze, storage: &mut dyn Storage, sender: Addr) -> AnyResult<AppResponse> {
+        Self::load_freezable(storage, &msg.denom, &sender, &msg.sender)?;
+        GLOBALLY_FROZEN_DENOMS.save(storage, &msg.denom, &Empty {})?;
+        Ok(AppResponse::default())
+    }
+
+    fn globally_unfreeze(&self, msg: &MsgGloballyUnfreeze, storage: &mut dyn Storage, sender: Addr) -> AnyResult<AppResponse> {
+        Self::load_freezable(storage, &msg.denom, &sender, &msg.sender)?;
+        GLOBALLY_FROZEN_DENOMS.remove(storage, &msg.denom);
+        Ok(AppResponse::default())
+    }
+
+    /// Shared handler for `MsgFreeze` and `MsgUnfreeze`, changing the frozen amount of an account.
+    fn change_frozen_amount(
+        &self,
+        storage: &mut dyn Storage,
+        sender: Addr,
+        msg_sender: &str,
+        account: &str,
+        coin: Option<&coreum_wasm_sdk::types::cosmos::base::v1beta1::Coin>,
+        freeze: bool,
+    ) -> AnyResult<AppResponse> {
+        let Some(coin) = coin else {
+            bail!("coin is None");
+        };
+        let issue = Self::load_freezable(storage, &coin.denom, &sender, msg_sender)?;
+        if issue.issuer == account {
+            bail!("Issuer's balance can't be frozen");
+        }
+
+        let amount = Uint128::from_str(&coin.amount)?;
+        let previous = FROZEN_BALANCES.may_load(storage, (&coin.denom, account))?.unwrap_or_default();
+        let current = if freeze {
+            previous.checked_add(amount)?
+        } else {
+            previous
+                .checked_sub(amount)
+                .map_err(|_| anyhow!("Unfreeze amount {} exceeds frozen amount {}", amount, previous))?
+        };
+        FROZEN_BALANCES.save(storage, (&coin.denom, account), &current)?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("/coreum.asset.ft.v1.EventFrozenAmountChanged")
+                .add_attribute("account", account)
+                .add_attribute("denom", coin.denom.clone())
+                .add_attribute("previous_amount", previous.to_string())
+                .add_attribute("current_amount", current.to_string()),
+        );
+        Ok(res)
+    }
+
+    fn set_whitelisted_limit(&self, msg: &MsgSetWhitelistedLimit, storage: &mut dyn Storage, sender: Addr) -> AnyResult<AppResponse> {
+        if sender.to_string() != msg.sender {
+            bail!("Invalid sender. Sender in msg must be same as sender of transaction.");
+        }
+        let Some(coin) = &msg.coin else {
+            bail!("MsgSetWhitelistedLimit.coin is None");
+        };
+        let Some(issue) = ISSUED_TOKENS.may_load(storage, &coin.denom)? else {
+            bail!("Unknown Coreum FT denom `{}`", coin.denom);
+        };
+        if issue.issuer != sender.to_string() {
+            bail!("Unauthorized. Not the issuer of the denom.");
+        }
+        if !issue.features.contains(&(Feature::Whitelisting as i32)) {
+            bail!("Feature whitelisting is disabled for denom `{}`", coin.denom);
+        }
+        if issue.issuer == msg.account {
+            bail!("Issuer's balance can't be whitelisted");
+        }
+
+        let limit = Uint128::from_str(&coin.amount)?;
+        let previous = WHITELISTED_LIMITS.may_load(storage, (&coin.denom, &msg.account))?.unwrap_or_default();
+        WHITELISTED_LIMITS.save(storage, (&coin.denom, &msg.account), &limit)?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("/coreum.asset.ft.v1.EventWhitelistedAmountChanged")
+                .add_attribute("account", msg.account.clone())
+                .add_attribute("denom", coin.denom.clone())
+                .add_attribute("previous_amount", previous.to_string())
+                .add_attribute("current_amount", limit.to_string()),
+        );
+        Ok(res)
+    }
+
+    /// Handles `MsgClawback`, moving `coin` from `account` back to the issuer by burning it from the
+    /// account and minting it to the issuer.
+    fn clawback<ExecC, QueryC>(
+        &self,
+        msg: &MsgClawback,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: cosmwasm_std::CustomMsg + serde::de::DeserializeOwned + 'static,
+        QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
+    {
+        if sender.to_string() != msg.sender {
+            bail!("Invalid sender. Sender in msg must be same as sender of transaction.");
+        }
+        let Some(coin) = &msg.coin else {
+            bail!("MsgClawback.coin is None");
+        };
+        let Some(issue) = ISSUED_TOKENS.may_load(storage, &coin.denom)? else {
+            bail!("Unknown Coreum FT denom `{}`", coin.denom);
+        };
+        if issue.issuer != sender.to_string() {
+            bail!("Unauthorized. Not the issuer of the denom.");
+        }
+        if !issue.features.contains(&(Feature::Clawback as i32)) {
+            bail!("Feature clawback is disabled for denom `{}`", coin.denom);
+        }
+        if issue.issuer == msg.account {
+            bail!("Issuer's balance can't be clawed back");
+        }
+
+        let amount = Uint128::from_str(&coin.amount)?;
+        if amount.is_zero() {
+            bail!("Invalid zero amount");
+        }
+        let request = QueryRequest::Bank(BankQuery::Balance {
+            address: msg.account.clone(),
+            denom: coin.denom.clone(),
+        });
+        let balance: BalanceResponse = from_json(router.query(api, storage, block, request)?)?;
+        if balance.amount.amount < amount {
+            bail!(
+                "insufficient funds: {} has {}, but {}{} are clawed back",
+                msg.account,
+                balance.amount,
+                amount,
+                coin.denom
+            );
+        }
+
+        let coins = vec![Coin {
+            denom: coin.denom.clone(),
+            amount,
+        }];
+        let burn_msg = BankMsg::Burn { amount: coins.clone() };
+        router.execute(api, storage, block, Addr::unchecked(&msg.account), burn_msg.into())?;
+        self.bank_mint(api, storage, router, block, &issue.issuer, coins)?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("clawback")
+                .add_attribute("sender", msg.sender.clone())
+                .add_attribute("account", msg.account.clone())
+                .add_attribute("amount", format!("{}{}", amount, coin.denom)),
+        );
+        Ok(res)
+    }
+
     fn issue_class<ExecC, QueryC>(
         &self,
         msg: &MsgIssueClass,
@@ -570,6 +1042,14 @@ impl TokenFactory<'_> {
         }
 
         MINTED_NFTS.remove(storage, (class_id, nft_id));
+        FROZEN_NFTS.remove(storage, (class_id, nft_id));
+        let whitelisted = WHITELISTED_NFT_ACCOUNTS
+            .prefix((class_id, nft_id))
+            .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()?;
+        for account in whitelisted {
+            WHITELISTED_NFT_ACCOUNTS.remove(storage, (class_id, nft_id, &account));
+        }
 
         let mut res = AppResponse::default();
         res.events.push(
@@ -622,6 +1102,19 @@ impl TokenFactory<'_> {
             bail!("MsgSend.receiver is empty");
         }
 
+        if FROZEN_NFTS.has(storage, (class_id, nft_id)) {
+            bail!("NFT {}/{} is frozen", class_id, nft_id);
+        }
+        if class.features.contains(&(ClassFeature::DisableSending as i32)) && stored.owner != class.issuer && to != class.issuer {
+            bail!("Sending is disabled for class {}", class_id);
+        }
+        if class.features.contains(&(ClassFeature::Whitelisting as i32))
+            && to != class.issuer
+            && !WHITELISTED_NFT_ACCOUNTS.has(storage, (class_id, nft_id, &to))
+        {
+            bail!("Receiver {} is not whitelisted for NFT {}/{}", to, class_id, nft_id);
+        }
+
         stored.owner = to.clone();
         MINTED_NFTS.save(storage, (class_id, nft_id), &stored)?;
 
@@ -637,6 +1130,147 @@ impl TokenFactory<'_> {
         Ok(res)
     }
 
+    /// Loads the NFT `class_id`/`nft_id`, validating that `sender` is the issuer of its class and
+    /// that `feature` is enabled for the class.
+    fn load_nft_for_feature(
+        storage: &dyn Storage,
+        class_id: &str,
+        nft_id: &str,
+        sender: &Addr,
+        msg_sender: &str,
+        feature: ClassFeature,
+    ) -> AnyResult<StoredNft> {
+        if sender.to_string() != msg_sender {
+            bail!("Invalid sender. sender in msg must match tx sender.");
+        }
+        let Some(class) = ISSUED_NFT_CLASSES.may_load(storage, class_id)? else {
+            bail!("Class id not found: {}", class_id);
+        };
+        if class.issuer != sender.to_string() {
+            bail!("Unauthorized. Only the issuer of class {} can do this", class_id);
+        }
+        if !class.features.contains(&(feature as i32)) {
+            bail!("Feature {:?} is disabled for class {}", feature, class_id);
+        }
+        let Some(stored) = MINTED_NFTS.may_load(storage, (class_id, nft_id))? else {
+            bail!("NFT not found: {}/{}", class_id, nft_id);
+        };
+        Ok(stored)
+    }
+
+    /// Shared handler for the NFT `MsgFreeze` and `MsgUnfreeze`.
+    fn nft_change_frozen(
+        storage: &mut dyn Storage,
+        sender: Addr,
+        msg_sender: &str,
+        class_id: &str,
+        nft_id: &str,
+        freeze: bool,
+    ) -> AnyResult<AppResponse> {
+        let stored = Self::load_nft_for_feature(storage, class_id, nft_id, &sender, msg_sender, ClassFeature::Freezing)?;
+
+        let event = if freeze {
+            FROZEN_NFTS.save(storage, (class_id, nft_id), &Empty {})?;
+            "/coreum.asset.nft.v1.EventFrozen"
+        } else {
+            FROZEN_NFTS.remove(storage, (class_id, nft_id));
+            "/coreum.asset.nft.v1.EventUnfrozen"
+        };
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new(event)
+                .add_attribute("class_id", class_id.to_string())
+                .add_attribute("id", nft_id.to_string())
+                .add_attribute("owner", stored.owner),
+        );
+        Ok(res)
+    }
+
+    /// Shared handler for `MsgAddToWhitelist` and `MsgRemoveFromWhitelist`.
+    fn nft_change_whitelisted(
+        storage: &mut dyn Storage,
+        sender: Addr,
+        msg_sender: &str,
+        class_id: &str,
+        nft_id: &str,
+        account: &str,
+        whitelist: bool,
+    ) -> AnyResult<AppResponse> {
+        Self::load_nft_for_feature(storage, class_id, nft_id, &sender, msg_sender, ClassFeature::Whitelisting)?;
+        if account.is_empty() {
+            bail!("account is empty");
+        }
+
+        let event = if whitelist {
+            WHITELISTED_NFT_ACCOUNTS.save(storage, (class_id, nft_id, account), &Empty {})?;
+            "/coreum.asset.nft.v1.EventAddedToWhitelist"
+        } else {
+            WHITELISTED_NFT_ACCOUNTS.remove(storage, (class_id, nft_id, account));
+            "/coreum.asset.nft.v1.EventRemovedFromWhitelist"
+        };
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new(event)
+                .add_attribute("class_id", class_id.to_string())
+                .add_attribute("id", nft_id.to_string())
+                .add_attribute("account", account.to_string()),
+        );
+        Ok(res)
+    }
+
+    /// Handles `MsgUpdateData`, updating items of an NFT minted with [`DataDynamic`] data. Each item
+    /// may only be updated by its editors: the class issuer for `Admin` and the NFT owner for `Owner`.
+    fn nft_update_data(&self, msg: &MsgUpdateData, storage: &mut dyn Storage, sender: Addr) -> AnyResult<AppResponse> {
+        if msg.sender != sender.to_string() {
+            bail!("Invalid sender. sender in msg must match tx sender.");
+        }
+        let class_id = msg.class_id.as_str();
+        let nft_id = msg.id.as_str();
+        let Some(class) = ISSUED_NFT_CLASSES.may_load(storage, class_id)? else {
+            bail!("Class id not found: {}", class_id);
+        };
+        let Some(mut stored) = MINTED_NFTS.may_load(storage, (class_id, nft_id))? else {
+            bail!("NFT not found: {}/{}", class_id, nft_id);
+        };
+
+        let Some(data) = stored.data.as_mut().filter(|data| data.type_url == DataDynamic::TYPE_URL) else {
+            bail!("Data of NFT {}/{} is not dynamic", class_id, nft_id);
+        };
+        let mut dynamic = DataDynamic::decode(data.value.as_slice())?;
+        for update in &msg.items {
+            let Some(item) = dynamic.items.get_mut(update.index as usize) else {
+                bail!("Invalid data item index {} for NFT {}/{}", update.index, class_id, nft_id);
+            };
+            let authorized = item.editors.iter().any(|&editor| {
+                (editor == DataEditor::Admin as i32 && class.issuer == msg.sender)
+                    || (editor == DataEditor::Owner as i32 && stored.owner == msg.sender)
+            });
+            if !authorized {
+                bail!(
+                    "Unauthorized. {} is not an editor of data item {} of NFT {}/{}",
+                    msg.sender,
+                    update.index,
+                    class_id,
+                    nft_id
+                );
+            }
+            item.data = update.data.clone();
+        }
+        data.value = dynamic.encode_to_vec();
+        MINTED_NFTS.save(storage, (class_id, nft_id), &stored)?;
+
+        let mut res = AppResponse::default();
+        res.events.push(
+            Event::new("/coreum.asset.nft.v1.EventDataUpdated")
+                .add_attribute("class_id", class_id.to_string())
+                .add_attribute("id", nft_id.to_string())
+                .add_attribute("sender", msg.sender.clone()),
+        );
+        Ok(res)
+    }
+
     /// Shared internal handler for `CosmosMsg::Stargate`.
     fn handle_any<ExecC, QueryC>(
         &self,
@@ -666,11 +1300,56 @@ impl TokenFactory<'_> {
                 let msg = Self::decode_burn(value)?;
                 self.burn(&msg, api, storage, router, block, sender)
             }
+            MsgGloballyFreeze::TYPE_URL => {
+                let msg = MsgGloballyFreeze::try_from(value).map_err(|e| anyhow!("failed to decode MsgGloballyFreeze: {e}"))?;
+                self.globally_freeze(&msg, storage, sender)
+            }
+            MsgGloballyUnfreeze::TYPE_URL => {
+                let msg = MsgGloballyUnfreeze::try_from(value).map_err(|e| anyhow!("failed to decode MsgGloballyUnfreeze: {e}"))?;
+                self.globally_unfreeze(&msg, storage, sender)
+            }
+            MsgFreeze::TYPE_URL => {
+                let msg = MsgFreeze::try_from(value).map_err(|e| anyhow!("failed to decode MsgFreeze: {e}"))?;
+                self.change_frozen_amount(storage, sender, &msg.sender, &msg.account, msg.coin.as_ref(), true)
+            }
+            MsgUnfreeze::TYPE_URL => {
+                let msg = MsgUnfreeze::try_from(value).map_err(|e| anyhow!("failed to decode MsgUnfreeze: {e}"))?;
+                self.change_frozen_amount(storage, sender, &msg.sender, &msg.account, msg.coin.as_ref(), false)
+            }
+            MsgSetWhitelistedLimit::TYPE_URL => {
+                let msg =
+                    MsgSetWhitelistedLimit::try_from(value).map_err(|e| anyhow!("failed to decode MsgSetWhitelistedLimit: {e}"))?;
+                self.set_whitelisted_limit(&msg, storage, sender)
+            }
+            MsgClawback::TYPE_URL => {
+                let msg = MsgClawback::try_from(value).map_err(|e| anyhow!("failed to decode MsgClawback: {e}"))?;
+                self.clawback(&msg, api, storage, router, block, sender)
+            }
             // --- NFT ---
             MsgIssueClass::TYPE_URL => {
                 let msg = Self::decode_issue_class(value)?;
                 self.issue_class(&msg, api, storage, router, block, sender)
             }
+            MsgNftFreeze::TYPE_URL => {
+                let msg = MsgNftFreeze::try_from(value).map_err(|e| anyhow!("failed to decode MsgFreeze (NFT): {e}"))?;
+                Self::nft_change_frozen(storage, sender, &msg.sender, &msg.class_id, &msg.id, true)
+            }
+            MsgNftUnfreeze::TYPE_URL => {
+                let msg = MsgNftUnfreeze::try_from(value).map_err(|e| anyhow!("failed to decode MsgUnfreeze (NFT): {e}"))?;
+                Self::nft_change_frozen(storage, sender, &msg.sender, &msg.class_id, &msg.id, false)
+            }
+            MsgAddToWhitelist::TYPE_URL => {
+                let msg = MsgAddToWhitelist::try_from(value).map_err(|e| anyhow!("failed to decode MsgAddToWhitelist: {e}"))?;
+                Self::nft_change_whitelisted(storage, sender, &msg.sender, &msg.class_id, &msg.id, &msg.account, true)
+            }
+            MsgRemoveFromWhitelist::TYPE_URL => {
+                let msg = MsgRemoveFromWhitelist::try_from(value).map_err(|e| anyhow!("failed to decode MsgRemoveFromWhitelist: {e}"))?;
+                Self::nft_change_whitelisted(storage, sender, &msg.sender, &msg.class_id, &msg.id, &msg.account, false)
+            }
+            MsgUpdateData::TYPE_URL => {
+                let msg = MsgUpdateData::try_from(value).map_err(|e| anyhow!("failed to decode MsgUpdateData: {e}"))?;
+                self.nft_update_data(&msg, storage, sender)
+            }
             MsgNftMint::TYPE_URL => {
                 let msg = Self::decode_nft_mint(value)?;
                 self.nft_mint(&msg, api, storage, router, block, sender)
@@ -716,12 +1395,56 @@ impl<'a> Module for TokenFactory<'a> {
     fn query(
         &self,
         _api: &dyn Api,
-        _storage: &dyn Storage,
+        storage: &dyn Storage,
         _querier: &dyn Querier,
         _block: &BlockInfo,
-        _request: Self::QueryT,
+        request: Self::QueryT,
     ) -> AnyResult<Binary> {
-        bail!("Unsupported query type: Stargate queries are disabled");
+        match request.path.as_str() {
+            QUERY_NFT_PATH => {
+                let req = Self::decode_query_nft_req(request.data.as_slice())?;
+                let Some(stored) = MINTED_NFTS.may_load(storage, (&req.class_id, &req.id))? else {
+                    bail!("NFT not found for {}/{}", req.class_id, req.id);
+                };
+
+                Ok(to_json_binary(&QueryNftResponse {
+                    nft: Some(Self::stored_to_nft(&stored)),
+                })?)
+            }
+            QUERY_NFTS_PATH => {
+                let req = Self::decode_query_nfts_req(request.data.as_slice())?;
+                let nfts = MINTED_NFTS
+                    .range(storage, None, None, cosmwasm_std::Order::Ascending)
+                    .filter_map(|item| item.ok())
+                    .filter(|((cid, _), stored)| {
+                        (req.class_id.is_empty() || *cid == req.class_id) && (req.owner.is_empty() || stored.owner == req.owner)
+                    })
+                    .map(|((cid, nid), stored)| (format!("{}/{}", cid, nid), Self::stored_to_nft(&stored)))
+                    .collect();
+
+                let pagination = req.pagination.unwrap_or_default();
+                let page = paginate(nfts, Some(&pagination.key), pagination.offset, pagination.limit, pagination.reverse);
+                Ok(to_json_binary(&QueryNfTsResponse {
+                    nfts: page.items,
+                    pagination: Some(coreum_wasm_sdk::types::cosmos::base::query::v1beta1::PageResponse {
+                        next_key: page.next_key.unwrap_or_default(),
+                        total: page.total,
+                    }),
+                })?)
+            }
+            QUERY_ASSETFT_PARAMS_PATH => Ok(to_json_binary(&QueryParamsResponse {
+                params: Some(Params {
+                    issue_fee: self
+                        .denom_creation_fee_coin()
+                        .map(|fee| coreum_wasm_sdk::types::cosmos::base::v1beta1::Coin {
+                            denom: fee.denom,
+                            amount: fee.amount.to_string(),
+                        }),
+                    ..Default::default()
+                }),
+            })?),
+            _ => bail!("Unsupported query type: Stargate queries are disabled"),
+        }
     }
 
     fn sudo<ExecC, QueryC>(
@@ -798,7 +1521,7 @@ impl Module for CoreumQueryModule {
                 }
 
                 nft::Query::NFTs { class_id, owner, pagination } => {
-                    let mut nfts: Vec<nft::NFT> = vec![];
+                    let mut nfts: Vec<(String, nft::NFT)> = vec![];
 
                     // Scan all minted; filter locally
                     MINTED_NFTS
@@ -815,24 +1538,21 @@ impl Module for CoreumQueryModule {
                                         return;
                                     }
                                 }
-                                let _ = nid; // keep for clarity
-                                nfts.push(nft::NFT {
-                                    class_id: stored.class_id,
-                                    id: stored.id,
-                                    uri: if stored.uri.is_empty() { None } else { Some(stored.uri) },
-                                    uri_hash: None,
-                                    data: stored.data.map(|any| cosmwasm_std::Binary::from(any.value)),
-                                });
+                                nfts.push((
+                                    format!("{}/{}", cid, nid),
+                                    nft::NFT {
+                                        class_id: stored.class_id,
+                                        id: stored.id,
+                                        uri: if stored.uri.is_empty() { None } else { Some(stored.uri) },
+                                        uri_hash: None,
+                                        data: stored.data.map(|any| cosmwasm_std::Binary::from(any.value)),
+                                    },
+                                ));
                             }
                         });
 
-                    let resp = NFTsResponse {
-                        nfts,
-                        pagination: coreum_wasm_sdk::pagination::PageResponse {
-                            next_key: None,
-                            total: Some(0),
-                        },
-                    };
+                    let (nfts, pagination) = paginate_query(nfts, pagination);
+                    let resp = NFTsResponse { nfts, pagination };
 
                     Ok(to_json_binary(&resp)?)
                 }
@@ -875,7 +1595,7 @@ impl Module for CoreumQueryModule {
                 }
 
                 coreum_wasm_sdk::assetnft::Query::Classes { issuer, pagination } => {
-                    let mut classes: Vec<coreum_wasm_sdk::assetnft::Class> = Vec::new();
+                    let mut classes: Vec<(String, coreum_wasm_sdk::assetnft::Class)> = Vec::new();
                     ISSUED_NFT_CLASSES
                         .range(storage, None, None, cosmwasm_std::Order::Ascending)
                         .for_each(|item| {
@@ -884,28 +1604,26 @@ impl Module for CoreumQueryModule {
                                 if !issuer.is_empty() && issue.issuer != issuer {
                                     return;
                                 }
-                                classes.push(coreum_wasm_sdk::assetnft::Class {
-                                    id: class_id.clone(),
-                                    issuer: issue.issuer.clone(),
-                                    name: issue.name.clone(),
-                                    symbol: issue.symbol.clone(),
-                                    description: Some(issue.description.clone()),
-                                    uri: Some(issue.uri.clone()),
-                                    uri_hash: Some(issue.uri_hash.clone()),
-                                    features: Some(issue.features.iter().map(|&f| f as u32).collect()),
-                                    data: issue.data.clone().map(|d| Binary::from(d.value)),
-                                    royalty_rate: Some("0".to_string()),
-                                });
+                                classes.push((
+                                    class_id.clone(),
+                                    coreum_wasm_sdk::assetnft::Class {
+                                        id: class_id.clone(),
+                                        issuer: issue.issuer.clone(),
+                                        name: issue.name.clone(),
+                                        symbol: issue.symbol.clone(),
+                                        description: Some(issue.description.clone()),
+                                        uri: Some(issue.uri.clone()),
+                                        uri_hash: Some(issue.uri_hash.clone()),
+                                        features: Some(issue.features.iter().map(|&f| f as u32).collect()),
+                                        data: issue.data.clone().map(|d| Binary::from(d.value)),
+                                        royalty_rate: Some("0".to_string()),
+                                    },
+                                ));
                             }
                         });
 
-                    let resp = coreum_wasm_sdk::assetnft::ClassesResponse {
-                        classes,
-                        pagination: coreum_wasm_sdk::pagination::PageResponse {
-                            next_key: None,
-                            total: Some(0),
-                        },
-                    };
+                    let (classes, pagination) = paginate_query(classes, pagination);
+                    let resp = coreum_wasm_sdk::assetnft::ClassesResponse { classes, pagination };
 
                     Ok(to_json_binary(&resp)?)
                 }
@@ -925,10 +1643,10 @@ impl Module for CoreumQueryModule {
                             subunit: issue.subunit.clone(),
                             precision: issue.precision,
                             description: Some(issue.description.clone()),
-                            globally_frozen: Some(false),
-                            features: Some(vec![]),
-                            burn_rate: "0".to_string(),
-                            send_commission_rate: "0".to_string(),
+                            globally_frozen: Some(GLOBALLY_FROZEN_DENOMS.has(storage, &denom)),
+                            features: Some(issue.features.iter().map(|&f| f as u32).collect()),
+                            burn_rate: format_rate(&issue.burn_rate),
+                            send_commission_rate: format_rate(&issue.send_commission_rate),
                             version: 0,
                             uri: Some("".to_string()),
                             uri_hash: Some("".to_string()),
@@ -942,7 +1660,7 @@ impl Module for CoreumQueryModule {
                             issuer: "".to_string(),
                             symbol: "CORE".to_string(),
                             subunit: denom.clone(),
-                            precision: 6,
+                            precision: self.native_denom_precision,
                             description: Some("Native Coreum token".to_string()),
                             globally_frozen: Some(false),
                             features: Some(vec![]),
@@ -964,7 +1682,7 @@ impl Module for CoreumQueryModule {
                 }
 
                 coreum_wasm_sdk::assetft::Query::Tokens { issuer, pagination } => {
-                    let mut tokens: Vec<coreum_wasm_sdk::assetft::Token> = Vec::new();
+                    let mut tokens: Vec<(String, coreum_wasm_sdk::assetft::Token)> = Vec::new();
                     ISSUED_TOKENS
                         .range(storage, None, None, cosmwasm_std::Order::Ascending)
                         .for_each(|item| {
@@ -973,33 +1691,31 @@ impl Module for CoreumQueryModule {
                                 if !issuer.is_empty() && issue.issuer != issuer {
                                     return;
                                 }
-                                tokens.push(coreum_wasm_sdk::assetft::Token {
-                                    denom: denom.clone(),
-                                    issuer: issue.issuer.clone(),
-                                    symbol: issue.symbol.clone(),
-                                    subunit: issue.subunit.clone(),
-                                    precision: issue.precision,
-                                    description: Some(issue.description.clone()),
-                                    globally_frozen: Some(false),
-                                    features: Some(vec![]),
-                                    burn_rate: "0".to_string(),
-                                    send_commission_rate: "0".to_string(),
-                                    version: 0,
-                                    uri: Some("".to_string()),
-                                    uri_hash: Some("".to_string()),
-                                    extension_cw_address: None,
-                                    admin: None,
-                                });
+                                tokens.push((
+                                    denom.clone(),
+                                    coreum_wasm_sdk::assetft::Token {
+                                        denom: denom.clone(),
+                                        issuer: issue.issuer.clone(),
+                                        symbol: issue.symbol.clone(),
+                                        subunit: issue.subunit.clone(),
+                                        precision: issue.precision,
+                                        description: Some(issue.description.clone()),
+                                        globally_frozen: Some(GLOBALLY_FROZEN_DENOMS.has(storage, &denom)),
+                                        features: Some(issue.features.iter().map(|&f| f as u32).collect()),
+                                        burn_rate: format_rate(&issue.burn_rate),
+                                        send_commission_rate: format_rate(&issue.send_commission_rate),
+                                        version: 0,
+                                        uri: Some("".to_string()),
+                                        uri_hash: Some("".to_string()),
+                                        extension_cw_address: None,
+                                        admin: None,
+                                    },
+                                ));
                             }
                         });
 
-                    let resp = coreum_wasm_sdk::assetft::TokensResponse {
-                        tokens,
-                        pagination: coreum_wasm_sdk::pagination::PageResponse {
-                            next_key: None,
-                            total: Some(0),
-                        },
-                    };
+                    let (tokens, pagination) = paginate_query(tokens, pagination);
+                    let resp = coreum_wasm_sdk::assetft::TokensResponse { tokens, pagination };
 
                     Ok(to_json_binary(&resp)?)
                 }
@@ -1027,7 +1743,7 @@ impl Module for CoreumQueryModule {
     }
 }
 
-fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
+pub(super) fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
     let denom_re = Regex::new(r"^[0-9]+[a-z]+$")?;
     let denom_re2 = Regex::new(r"^([0-9]+)([a-z0-9]+)-([A-Za-z0-9]+)$")?;
     let ibc_re = Regex::new(r"^[0-9]+(ibc|IBC)/[0-9A-F]{64}$")?;
@@ -1040,11 +1756,12 @@ fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
 
     // Parse amount
     let re = Regex::new(r"[0-9]+")?;
-    let amount = re.find(sdk_string).unwrap().as_str();
-    let amount = Uint128::from_str(amount)?;
+    let amount_str = re.find(sdk_string).unwrap().as_str();
+    let amount = Uint128::from_str(amount_str)?;
 
-    // The denom is the rest of the string
-    let denom = sdk_string[amount.to_string().len()..].to_string();
+    // The denom is the rest of the string. Slice by the parsed digits rather than the formatted
+    // amount, as the two differ for amounts with leading zeros.
+    let denom = sdk_string[amount_str.len()..].to_string();
 
     Ok(Coin { denom, amount })
 }
@@ -1052,9 +1769,15 @@ fn coin_from_sdk_string(sdk_string: &str) -> AnyResult<Coin> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use coreum_wasm_sdk::types::coreum::asset::ft::v1::{MsgIssueResponse, MsgMintResponse};
+    use coreum_wasm_sdk::types::coreum::asset::nft::v1::{MsgIssueClassResponse, MsgMintResponse as MsgNftMintResponse};
     use cosmwasm_std::{BalanceResponse, CosmosMsg};
     use cw_multi_test::{BasicAppBuilder, Executor};
     use test_case::test_case;
+    use test_tube::{Account, Runner, SigningAccount};
+
+    use crate::multi_test::MultiTestRunner;
+    use crate::traits::CwItRunner;
 
     const TOKEN_FACTORY: TokenFactory<'static> = TokenFactory::new("factory", 32, 16, 59 + 16, DEFAULT_INIT);
 
@@ -1156,6 +1879,61 @@ mod tests {
         assert_eq!(balance, Uint128::from(mint_amount));
     }
 
+    #[test]
+    fn mint_cap() {
+        let sender = Addr::unchecked("sender");
+        let stargate = TOKEN_FACTORY
+            .clone()
+            .with_mint_cap("capped-sender", Uint128::new(1500))
+            .with_mint_cap("small-sender", Uint128::new(600));
+
+        let mut app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(stargate)
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string(DEFAULT_INIT).unwrap()])
+                    .unwrap();
+            });
+
+        let issue = |subunit: &str| CosmosMsg::<Empty>::Stargate {
+            type_url: MsgIssue::TYPE_URL.to_string(),
+            value: MsgIssue {
+                issuer: sender.to_string(),
+                subunit: subunit.to_string(),
+                symbol: subunit.to_uppercase(),
+                initial_amount: "500".to_string(),
+                ..MsgIssue::default()
+            }
+            .into(),
+        };
+        let mint = |amount: u128, denom: &str| CosmosMsg::<Empty>::Stargate {
+            type_url: MsgMint::TYPE_URL.to_string(),
+            value: MsgMint {
+                sender: sender.to_string(),
+                coin: Some(Coin::new(amount, denom).into()),
+                recipient: sender.to_string(),
+            }
+            .into(),
+        };
+        for subunit in ["capped", "small", "unlimited"] {
+            app.execute(sender.clone(), issue(subunit)).unwrap();
+        }
+
+        // Minting up to the cap of each denom succeeds, minting beyond it fails
+        app.execute(sender.clone(), mint(1000, "capped-sender")).unwrap();
+        let err = app.execute(sender.clone(), mint(1, "capped-sender")).unwrap_err();
+        assert!(format!("{:#}", err).contains("mint cap exceeded"), "{:#}", err);
+        app.execute(sender.clone(), mint(100, "small-sender")).unwrap();
+        let err = app.execute(sender.clone(), mint(1, "small-sender")).unwrap_err();
+        assert!(format!("{:#}", err).contains("mint cap exceeded"), "{:#}", err);
+        assert_eq!(app.wrap().query_supply("capped-sender").unwrap().amount, Uint128::new(1500));
+        assert_eq!(app.wrap().query_supply("small-sender").unwrap().amount, Uint128::new(600));
+
+        // Denoms without a cap are unlimited
+        app.execute(sender.clone(), mint(1_000_000, "unlimited-sender")).unwrap();
+    }
+
     #[test_case(Addr::unchecked("sender"), Addr::unchecked("sender"), 1000u128, 1000u128 ; "valid burn")]
     #[test_case(Addr::unchecked("sender"), Addr::unchecked("sender"), 1000u128, 2000u128 ; "valid burn 2")]
     #[test_case(Addr::unchecked("sender"), Addr::unchecked("creator"), 1000u128, 1000u128 => panics "Unauthorized burn. Not the issuer of the denom." ; "sender is not creator")]
@@ -1221,6 +1999,23 @@ mod tests {
         assert_eq!(balance.u128(), initial_balance - burn_amount);
     }
 
+    #[test]
+    fn query_params() {
+        let app = BasicAppBuilder::<Empty, Empty>::new()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .build(|_, _, _| {});
+
+        let res: QueryParamsResponse = app
+            .wrap()
+            .query(&QueryRequest::Stargate {
+                path: QUERY_ASSETFT_PARAMS_PATH.to_string(),
+                data: Binary::default(),
+            })
+            .unwrap();
+        let issue_fee = res.params.unwrap().issue_fee.unwrap();
+        assert_eq!(format!("{}{}", issue_fee.amount, issue_fee.denom), DEFAULT_INIT);
+    }
+
     #[test_case(DEFAULT_COIN_DENOM ; "native denom")]
     #[test_case("IBC/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2" ; "ibc denom")]
     #[test_case("IBC/27394FB092D2ECCD56123CA622B25F41E5EB2" => panics "Invalid sdk string" ; "invalid ibc denom")]
@@ -1372,4 +2167,907 @@ mod tests {
             .unwrap();
         assert_eq!(resp.nfts.len(), 0);
     }
+
+    #[test]
+    fn nft_total_count() {
+        let sender = Addr::unchecked("sender");
+        let receiver = Addr::unchecked("receiver");
+        let mut app = BasicAppBuilder::<CoreumMsg, CoreumQueries>::new_custom()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .with_custom(CoreumQueryModule::default())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string(DEFAULT_INIT).unwrap()])
+                    .unwrap();
+            });
+
+        let issue_class = CosmosMsg::<CoreumMsg>::Stargate {
+            type_url: MsgIssueClass::TYPE_URL.to_string(),
+            value: MsgIssueClass {
+                issuer: sender.to_string(),
+                name: "My NFT Class".to_string(),
+                symbol: "NFTCLASS".to_string(),
+                ..MsgIssueClass::default()
+            }
+            .into(),
+        };
+        app.execute(sender.clone(), issue_class).unwrap();
+        for (id, recipient) in [("nft1", &sender), ("nft2", &receiver), ("nft3", &receiver)] {
+            let mint = CosmosMsg::<CoreumMsg>::Stargate {
+                type_url: MsgNftMint::TYPE_URL.to_string(),
+                value: MsgNftMint {
+                    sender: sender.to_string(),
+                    class_id: "nftclass-sender".to_string(),
+                    id: id.to_string(),
+                    recipient: recipient.to_string(),
+                    ..MsgNftMint::default()
+                }
+                .into(),
+            };
+            app.execute(sender.clone(), mint).unwrap();
+        }
+
+        let query_total = |owner: Option<&Addr>| {
+            app.wrap()
+                .query::<NFTsResponse>(&QueryRequest::Custom(CoreumQueries::NFT(nft::Query::NFTs {
+                    class_id: Some("nftclass-sender".to_string()),
+                    owner: owner.map(|o| o.to_string()),
+                    pagination: None,
+                })))
+                .unwrap()
+                .pagination
+                .total
+        };
+        assert_eq!(query_total(None), Some(3));
+        assert_eq!(query_total(Some(&receiver)), Some(2));
+        assert_eq!(query_total(Some(&sender)), Some(1));
+    }
+
+    #[test]
+    fn nft_pagination() {
+        let sender = Addr::unchecked("sender");
+        let mut app = BasicAppBuilder::<CoreumMsg, CoreumQueries>::new_custom()
+            .with_stargate(TOKEN_FACTORY.clone())
+            .with_custom(CoreumQueryModule::default())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &sender, vec![coin_from_sdk_string(DEFAULT_INIT).unwrap()])
+                    .unwrap();
+            });
+
+        let issue_class = CosmosMsg::<CoreumMsg>::Stargate {
+            type_url: MsgIssueClass::TYPE_URL.to_string(),
+            value: MsgIssueClass {
+                issuer: sender.to_string(),
+                name: "My NFT Class".to_string(),
+                symbol: "NFTCLASS".to_string(),
+                ..MsgIssueClass::default()
+            }
+            .into(),
+        };
+        app.execute(sender.clone(), issue_class).unwrap();
+        for i in 1..=5 {
+            let mint = CosmosMsg::<CoreumMsg>::Stargate {
+                type_url: MsgNftMint::TYPE_URL.to_string(),
+                value: MsgNftMint {
+                    sender: sender.to_string(),
+                    class_id: "nftclass-sender".to_string(),
+                    id: format!("nft{}", i),
+                    recipient: sender.to_string(),
+                    ..MsgNftMint::default()
+                }
+                .into(),
+            };
+            app.execute(sender.clone(), mint).unwrap();
+        }
+
+        // Page through the NFTs with a limit of 2
+        let mut ids = vec![];
+        let mut key = None;
+        let mut pages = 0;
+        loop {
+            let resp = app
+                .wrap()
+                .query::<NFTsResponse>(&QueryRequest::Custom(CoreumQueries::NFT(nft::Query::NFTs {
+                    class_id: Some("nftclass-sender".to_string()),
+                    owner: None,
+                    pagination: Some(PageRequest {
+                        key: key.clone(),
+                        offset: None,
+                        limit: Some(2),
+                        count_total: None,
+                        reverse: None,
+                    }),
+                })))
+                .unwrap();
+            pages += 1;
+            assert!(resp.nfts.len() <= 2);
+            assert_eq!(resp.pagination.total, Some(5));
+            ids.extend(resp.nfts.into_iter().map(|n| n.id));
+            key = resp.pagination.next_key;
+            if key.is_none() {
+                break;
+            }
+        }
+        assert_eq!(pages, 3);
+        assert_eq!(ids, vec!["nft1", "nft2", "nft3", "nft4", "nft5"]);
+
+        // Without pagination all NFTs are returned in a single page
+        let resp = app
+            .wrap()
+            .query::<NFTsResponse>(&QueryRequest::Custom(CoreumQueries::NFT(nft::Query::NFTs {
+                class_id: Some("nftclass-sender".to_string()),
+                owner: None,
+                pagination: None,
+            })))
+            .unwrap();
+        assert_eq!(resp.nfts.len(), 5);
+        assert_eq!(resp.pagination.next_key, None);
+        assert_eq!(resp.pagination.total, Some(5));
+
+        // The stargate NFTs query is paginated the same way
+        let resp = app
+            .wrap()
+            .query::<QueryNfTsResponse>(&QueryRequest::Stargate {
+                path: QUERY_NFTS_PATH.to_string(),
+                data: QueryNfTsRequest {
+                    class_id: "nftclass-sender".to_string(),
+                    owner: String::new(),
+                    pagination: Some(coreum_wasm_sdk::types::cosmos::base::query::v1beta1::PageRequest {
+                        offset: 3,
+                        limit: 2,
+                        ..Default::default()
+                    }),
+                }
+                .encode_to_vec()
+                .into(),
+            })
+            .unwrap();
+        let ids: Vec<_> = resp.nfts.into_iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec!["nft4", "nft5"]);
+        let pagination = resp.pagination.unwrap();
+        assert!(pagination.next_key.is_empty());
+        assert_eq!(pagination.total, 5);
+    }
+
+    #[test]
+    fn paginate_zero_limit_uses_default_limit() {
+        let items = (0..150).map(|i| (format!("{:03}", i), i)).collect();
+
+        let page = paginate(items, None, 0, 0, false);
+        assert_eq!(page.items, (0..100).collect::<Vec<_>>());
+        assert_eq!(page.next_key, Some(b"100".to_vec()));
+        assert_eq!(page.total, 150);
+    }
+
+    /// A [`MultiTestRunner`] with the Coreum [`TokenFactory`], where `issuer` issued a token or an
+    /// NFT class held by `holder`.
+    struct Setup {
+        runner: MultiTestRunner<TokenFactory<'static>>,
+        issuer: SigningAccount,
+        holder: SigningAccount,
+        receiver: SigningAccount,
+        /// The denom of the issued token, or the class id of the issued NFT class.
+        denom: String,
+    }
+
+    impl Setup {
+        fn new() -> Self {
+            let runner = MultiTestRunner::new_with_stargate("core", TokenFactory::default());
+            let issuer = runner.init_default_account().unwrap();
+            let holder = runner.init_default_account().unwrap();
+            let receiver = runner.init_default_account().unwrap();
+
+            Setup {
+                runner,
+                issuer,
+                holder,
+                receiver,
+                denom: String::new(),
+            }
+        }
+    }
+
+    /// Issues the token `subunit` with `features` and mints 1000 of it to the holder.
+    fn setup_ft(subunit: &str, features: Vec<i32>) -> Setup {
+        let mut setup = Setup::new();
+        setup.denom = format!("{}-{}", subunit, setup.issuer.address());
+
+        setup
+            .runner
+            .execute::<_, MsgIssueResponse>(
+                MsgIssue {
+                    issuer: setup.issuer.address(),
+                    subunit: subunit.to_string(),
+                    symbol: subunit.trim_start_matches('u').to_uppercase(),
+                    features,
+                    ..MsgIssue::default()
+                },
+                MsgIssue::TYPE_URL,
+                &setup.issuer,
+            )
+            .unwrap();
+        setup
+            .runner
+            .execute::<_, MsgMintResponse>(
+                MsgMint {
+                    sender: setup.issuer.address(),
+                    coin: Some(cosmwasm_std::coin(1000, &setup.denom).into()),
+                    recipient: setup.holder.address(),
+                },
+                MsgMint::TYPE_URL,
+                &setup.issuer,
+            )
+            .unwrap();
+        setup
+    }
+
+    /// Issues an NFT class with `features` and mints the NFT `nft1` of it to the holder.
+    fn setup_nft(features: Vec<i32>) -> Setup {
+        let mut setup = Setup::new();
+        setup.denom = format!("nftclass-{}", setup.issuer.address());
+
+        setup
+            .runner
+            .execute::<_, MsgIssueClassResponse>(
+                MsgIssueClass {
+                    issuer: setup.issuer.address(),
+                    name: "My NFT Class".to_string(),
+                    symbol: "NFTCLASS".to_string(),
+                    features,
+                    ..MsgIssueClass::default()
+                },
+                MsgIssueClass::TYPE_URL,
+                &setup.issuer,
+            )
+            .unwrap();
+        setup
+            .runner
+            .execute::<_, MsgNftMintResponse>(
+                MsgNftMint {
+                    sender: setup.issuer.address(),
+                    class_id: setup.denom.clone(),
+                    id: "nft1".to_string(),
+                    recipient: setup.holder.address(),
+                    ..MsgNftMint::default()
+                },
+                MsgNftMint::TYPE_URL,
+                &setup.issuer,
+            )
+            .unwrap();
+        setup
+    }
+
+    mod clawback {
+        use coreum_wasm_sdk::types::coreum::asset::ft::v1::MsgClawbackResponse;
+        use cosmwasm_std::coin;
+        use test_tube::{Account, Runner, RunnerExecuteResult, SigningAccount};
+
+        use super::*;
+        use crate::helpers::bank_balance_query;
+
+        fn clawback(setup: &Setup, signer: &SigningAccount, amount: u128) -> RunnerExecuteResult<MsgClawbackResponse> {
+            setup.runner.execute::<_, MsgClawbackResponse>(
+                MsgClawback {
+                    sender: signer.address(),
+                    account: setup.holder.address(),
+                    coin: Some(coin(amount, &setup.denom).into()),
+                },
+                MsgClawback::TYPE_URL,
+                signer,
+            )
+        }
+
+        fn balance(setup: &Setup, account: &SigningAccount) -> u128 {
+            bank_balance_query(&setup.runner, account.address(), setup.denom.clone())
+                .unwrap()
+                .u128()
+        }
+
+        #[test]
+        fn clawback_moves_funds_to_issuer() {
+            let setup = setup_ft("uclaw", vec![Feature::Clawback as i32]);
+
+            let res = clawback(&setup, &setup.issuer, 300).unwrap();
+            let event = res.events.iter().find(|e| e.ty == "clawback").unwrap();
+            let attribute = |key: &str| event.attributes.iter().find(|a| a.key == key).unwrap().value.clone();
+            assert_eq!(attribute("account"), setup.holder.address());
+            assert_eq!(attribute("amount"), format!("300{}", setup.denom));
+
+            assert_eq!(balance(&setup, &setup.holder), 700);
+            assert_eq!(balance(&setup, &setup.issuer), 300);
+            let supply = setup.runner.app.borrow().wrap().query_supply(&setup.denom).unwrap();
+            assert_eq!(supply.amount.u128(), 1000);
+        }
+
+        #[test]
+        fn clawback_by_non_issuer_fails() {
+            let setup = setup_ft("uclaw", vec![Feature::Clawback as i32]);
+
+            let err = clawback(&setup, &setup.holder, 300).unwrap_err();
+            assert!(err.to_string().contains("Not the issuer of the denom"), "{}", err);
+            assert_eq!(balance(&setup, &setup.holder), 1000);
+        }
+
+        #[test]
+        fn clawback_exceeding_balance_fails() {
+            let setup = setup_ft("uclaw", vec![Feature::Clawback as i32]);
+
+            let err = clawback(&setup, &setup.issuer, 1001).unwrap_err();
+            assert!(err.to_string().contains("insufficient funds"), "{}", err);
+            assert_eq!(balance(&setup, &setup.holder), 1000);
+        }
+
+        #[test]
+        fn clawback_without_feature_fails() {
+            let setup = setup_ft("uclaw", vec![]);
+
+            let err = clawback(&setup, &setup.issuer, 300).unwrap_err();
+            assert!(err.to_string().contains("Feature clawback is disabled"), "{}", err);
+        }
+    }
+
+    mod nft_transfer_restrictions {
+        use coreum_wasm_sdk::types::coreum::asset::nft::v1::{
+            MsgAddToWhitelistResponse, MsgFreezeResponse as MsgNftFreezeResponse, MsgUnfreezeResponse as MsgNftUnfreezeResponse,
+        };
+        use coreum_wasm_sdk::types::cosmos::nft::v1beta1::MsgSendResponse as MsgNftSendResponse;
+        use test_tube::{Account, Runner, RunnerExecuteResult};
+
+        use super::*;
+
+        fn send(setup: &Setup) -> RunnerExecuteResult<MsgNftSendResponse> {
+            setup.runner.execute::<_, MsgNftSendResponse>(
+                MsgNftSend {
+                    class_id: setup.denom.clone(),
+                    id: "nft1".to_string(),
+                    sender: setup.holder.address(),
+                    receiver: setup.receiver.address(),
+                },
+                MsgNftSend::TYPE_URL,
+                &setup.holder,
+            )
+        }
+
+        fn freeze(setup: &Setup) -> RunnerExecuteResult<MsgNftFreezeResponse> {
+            setup.runner.execute::<_, MsgNftFreezeResponse>(
+                MsgNftFreeze {
+                    sender: setup.issuer.address(),
+                    class_id: setup.denom.clone(),
+                    id: "nft1".to_string(),
+                },
+                MsgNftFreeze::TYPE_URL,
+                &setup.issuer,
+            )
+        }
+
+        fn owner(setup: &Setup) -> String {
+            MINTED_NFTS
+                .load(setup.runner.app.borrow().storage(), (&setup.denom, "nft1"))
+                .unwrap()
+                .owner
+        }
+
+        #[test]
+        fn unrestricted_send_succeeds() {
+            let setup = setup_nft(vec![ClassFeature::Freezing as i32, ClassFeature::Whitelisting as i32]);
+
+            // Whitelist the receiver so the send goes through
+            setup
+                .runner
+                .execute::<_, MsgAddToWhitelistResponse>(
+                    MsgAddToWhitelist {
+                        sender: setup.issuer.address(),
+                        class_id: setup.denom.clone(),
+                        id: "nft1".to_string(),
+                        account: setup.receiver.address(),
+                    },
+                    MsgAddToWhitelist::TYPE_URL,
+                    &setup.issuer,
+                )
+                .unwrap();
+
+            send(&setup).unwrap();
+            assert_eq!(owner(&setup), setup.receiver.address());
+        }
+
+        #[test]
+        fn frozen_nft_cannot_be_sent() {
+            let setup = setup_nft(vec![ClassFeature::Freezing as i32]);
+
+            let res = freeze(&setup).unwrap();
+            assert!(res.events.iter().any(|e| e.ty == "/coreum.asset.nft.v1.EventFrozen"));
+            let err = send(&setup).unwrap_err();
+            assert!(err.to_string().contains("is frozen"), "{}", err);
+            assert_eq!(owner(&setup), setup.holder.address());
+
+            // Once unfrozen it can be sent again
+            setup
+                .runner
+                .execute::<_, MsgNftUnfreezeResponse>(
+                    MsgNftUnfreeze {
+                        sender: setup.issuer.address(),
+                        class_id: setup.denom.clone(),
+                        id: "nft1".to_string(),
+                    },
+                    MsgNftUnfreeze::TYPE_URL,
+                    &setup.issuer,
+                )
+                .unwrap();
+            send(&setup).unwrap();
+            assert_eq!(owner(&setup), setup.receiver.address());
+        }
+
+        #[test]
+        fn freezing_requires_feature() {
+            let setup = setup_nft(vec![]);
+
+            let err = freeze(&setup).unwrap_err();
+            assert!(err.to_string().contains("is disabled for class"), "{}", err);
+            send(&setup).unwrap();
+        }
+
+        #[test]
+        fn whitelisting_requires_whitelisted_receiver() {
+            let setup = setup_nft(vec![ClassFeature::Whitelisting as i32]);
+
+            let err = send(&setup).unwrap_err();
+            assert!(err.to_string().contains("is not whitelisted"), "{}", err);
+            assert_eq!(owner(&setup), setup.holder.address());
+        }
+
+        #[test]
+        fn disable_sending_blocks_transfers_between_holders() {
+            let setup = setup_nft(vec![ClassFeature::DisableSending as i32]);
+
+            let err = send(&setup).unwrap_err();
+            assert!(err.to_string().contains("Sending is disabled"), "{}", err);
+        }
+    }
+
+    mod nft_dynamic_data {
+        use coreum_wasm_sdk::shim::Any;
+        use coreum_wasm_sdk::types::coreum::asset::nft::v1::{
+            DataDynamicIndexedItem, DataDynamicItem, MsgIssueClassResponse, MsgMintResponse as MsgNftMintResponse, MsgUpdateDataResponse,
+        };
+        use test_tube::{Account, Runner, RunnerExecuteResult, SigningAccount};
+
+        use super::*;
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::CwItRunner;
+
+        fn update_data(
+            runner: &MultiTestRunner<TokenFactory<'static>>,
+            signer: &SigningAccount,
+            class_id: &str,
+            index: u32,
+            data: &[u8],
+        ) -> RunnerExecuteResult<MsgUpdateDataResponse> {
+            runner.execute::<_, MsgUpdateDataResponse>(
+                MsgUpdateData {
+                    sender: signer.address(),
+                    class_id: class_id.to_string(),
+                    id: "nft1".to_string(),
+                    items: vec![DataDynamicIndexedItem {
+                        index,
+                        data: data.to_vec(),
+                    }],
+                },
+                MsgUpdateData::TYPE_URL,
+                signer,
+            )
+        }
+
+        #[test]
+        fn update_dynamic_data() {
+            let runner = MultiTestRunner::new_with_stargate("core", TokenFactory::default());
+            let issuer = runner.init_default_account().unwrap();
+            let owner = runner.init_default_account().unwrap();
+            let class_id = format!("nftclass-{}", issuer.address());
+
+            runner
+                .execute::<_, MsgIssueClassResponse>(
+                    MsgIssueClass {
+                        issuer: issuer.address(),
+                        name: "My NFT Class".to_string(),
+                        symbol: "NFTCLASS".to_string(),
+                        ..MsgIssueClass::default()
+                    },
+                    MsgIssueClass::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+            let data = DataDynamic {
+                items: vec![
+                    DataDynamicItem {
+                        editors: vec![DataEditor::Owner as i32],
+                        data: b"owner v1".to_vec(),
+                    },
+                    DataDynamicItem {
+                        editors: vec![DataEditor::Admin as i32],
+                        data: b"admin v1".to_vec(),
+                    },
+                ],
+            };
+            runner
+                .execute::<_, MsgNftMintResponse>(
+                    MsgNftMint {
+                        sender: issuer.address(),
+                        class_id: class_id.clone(),
+                        id: "nft1".to_string(),
+                        recipient: owner.address(),
+                        data: Some(Any {
+                            type_url: DataDynamic::TYPE_URL.to_string(),
+                            value: data.encode_to_vec(),
+                        }),
+                        ..MsgNftMint::default()
+                    },
+                    MsgNftMint::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+
+            update_data(&runner, &owner, &class_id, 0, b"owner v2").unwrap();
+            update_data(&runner, &issuer, &class_id, 1, b"admin v2").unwrap();
+
+            // Only the editors of an item may update it
+            let err = update_data(&runner, &issuer, &class_id, 0, b"owner v3").unwrap_err();
+            assert!(err.to_string().contains("is not an editor"), "{}", err);
+            let err = update_data(&runner, &owner, &class_id, 2, b"out of range").unwrap_err();
+            assert!(err.to_string().contains("Invalid data item index"), "{}", err);
+
+            // The updated data is returned by the Coreum NFT query
+            let resp = runner
+                .app
+                .borrow()
+                .wrap()
+                .query::<NFTResponse>(&QueryRequest::Custom(CoreumQueries::NFT(nft::Query::NFT {
+                    class_id: class_id.clone(),
+                    id: "nft1".to_string(),
+                })))
+                .unwrap();
+            let data = DataDynamic::decode(resp.nft.data.unwrap().as_slice()).unwrap();
+            assert_eq!(data.items[0].data, b"owner v2".to_vec());
+            assert_eq!(data.items[1].data, b"admin v2".to_vec());
+        }
+    }
+
+    mod freezing {
+        use coreum_wasm_sdk::types::coreum::asset::ft::v1::{MsgFreezeResponse, MsgGloballyFreezeResponse, MsgGloballyUnfreezeResponse};
+        use cosmwasm_std::coin;
+        use osmosis_std::types::cosmos::bank::v1beta1::{MsgSend as MsgBankSend, MsgSendResponse};
+        use test_tube::{Account, Runner};
+
+        use super::*;
+
+        fn send(setup: &Setup, amount: u128) -> Result<(), String> {
+            setup
+                .runner
+                .execute::<_, MsgSendResponse>(
+                    MsgBankSend {
+                        from_address: setup.holder.address(),
+                        to_address: setup.receiver.address(),
+                        amount: vec![coin(amount, &setup.denom).into()],
+                    },
+                    MsgBankSend::TYPE_URL,
+                    &setup.holder,
+                )
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }
+
+        fn globally_frozen(setup: &Setup) -> Option<bool> {
+            setup
+                .runner
+                .app
+                .borrow()
+                .wrap()
+                .query::<coreum_wasm_sdk::assetft::TokenResponse>(&QueryRequest::Custom(CoreumQueries::AssetFT(
+                    coreum_wasm_sdk::assetft::Query::Token {
+                        denom: setup.denom.clone(),
+                    },
+                )))
+                .unwrap()
+                .token
+                .globally_frozen
+        }
+
+        #[test]
+        fn global_freeze_blocks_transfers_until_unfrozen() {
+            let setup = setup_ft("ufrz", vec![Feature::Freezing as i32]);
+            assert_eq!(globally_frozen(&setup), Some(false));
+
+            setup
+                .runner
+                .execute::<_, MsgGloballyFreezeResponse>(
+                    MsgGloballyFreeze {
+                        sender: setup.issuer.address(),
+                        denom: setup.denom.clone(),
+                    },
+                    MsgGloballyFreeze::TYPE_URL,
+                    &setup.issuer,
+                )
+                .unwrap();
+            assert_eq!(globally_frozen(&setup), Some(true));
+            let err = send(&setup, 100).unwrap_err();
+            assert!(err.contains("is globally frozen"), "unexpected error: {}", err);
+
+            setup
+                .runner
+                .execute::<_, MsgGloballyUnfreezeResponse>(
+                    MsgGloballyUnfreeze {
+                        sender: setup.issuer.address(),
+                        denom: setup.denom.clone(),
+                    },
+                    MsgGloballyUnfreeze::TYPE_URL,
+                    &setup.issuer,
+                )
+                .unwrap();
+            assert_eq!(globally_frozen(&setup), Some(false));
+            send(&setup, 100).unwrap();
+        }
+
+        #[test]
+        fn frozen_amount_is_not_spendable() {
+            let setup = setup_ft("ufrz", vec![Feature::Freezing as i32]);
+
+            let res = setup
+                .runner
+                .execute::<_, MsgFreezeResponse>(
+                    MsgFreeze {
+                        sender: setup.issuer.address(),
+                        account: setup.holder.address(),
+                        coin: Some(coin(800, &setup.denom).into()),
+                    },
+                    MsgFreeze::TYPE_URL,
+                    &setup.issuer,
+                )
+                .unwrap();
+            assert!(res.events.iter().any(|e| e.ty == "/coreum.asset.ft.v1.EventFrozenAmountChanged"));
+
+            let err = send(&setup, 300).unwrap_err();
+            assert!(err.contains("200ufrz"), "unexpected error: {}", err);
+            send(&setup, 200).unwrap();
+        }
+
+        #[test]
+        fn freeze_requires_issuer() {
+            let setup = setup_ft("ufrz", vec![Feature::Freezing as i32]);
+
+            let err = setup
+                .runner
+                .execute::<_, MsgGloballyFreezeResponse>(
+                    MsgGloballyFreeze {
+                        sender: setup.holder.address(),
+                        denom: setup.denom.clone(),
+                    },
+                    MsgGloballyFreeze::TYPE_URL,
+                    &setup.holder,
+                )
+                .unwrap_err();
+            assert!(err.to_string().contains("Not the issuer of the denom"));
+        }
+    }
+
+    mod transfer_rates {
+        use coreum_wasm_sdk::types::coreum::asset::ft::v1::{MsgIssueResponse, MsgMintResponse};
+        use cosmwasm_std::coin;
+        use osmosis_std::types::cosmos::bank::v1beta1::{MsgSend as MsgBankSend, MsgSendResponse};
+        use test_tube::{Account, Runner};
+
+        use super::*;
+        use crate::helpers::bank_balance_query;
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::CwItRunner;
+
+        #[test]
+        fn burn_rate_and_send_commission_rate_are_deducted() {
+            let runner = MultiTestRunner::new_with_stargate("core", TokenFactory::default());
+            let issuer = runner.init_default_account().unwrap();
+            let holder = runner.init_default_account().unwrap();
+            let receiver = runner.init_default_account().unwrap();
+            let denom = format!("urate-{}", issuer.address());
+
+            runner
+                .execute::<_, MsgIssueResponse>(
+                    MsgIssue {
+                        issuer: issuer.address(),
+                        subunit: "urate".to_string(),
+                        symbol: "RATE".to_string(),
+                        burn_rate: "0.1".to_string(),
+                        send_commission_rate: "0.05".to_string(),
+                        ..MsgIssue::default()
+                    },
+                    MsgIssue::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+            runner
+                .execute::<_, MsgMintResponse>(
+                    MsgMint {
+                        sender: issuer.address(),
+                        coin: Some(coin(1000, &denom).into()),
+                        recipient: holder.address(),
+                    },
+                    MsgMint::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+
+            // The stored rates are reported by the token query
+            let token = runner
+                .app
+                .borrow()
+                .wrap()
+                .query::<coreum_wasm_sdk::assetft::TokenResponse>(&QueryRequest::Custom(CoreumQueries::AssetFT(
+                    coreum_wasm_sdk::assetft::Query::Token { denom: denom.clone() },
+                )))
+                .unwrap()
+                .token;
+            assert_eq!(token.burn_rate, "0.1");
+            assert_eq!(token.send_commission_rate, "0.05");
+
+            runner
+                .execute::<_, MsgSendResponse>(
+                    MsgBankSend {
+                        from_address: holder.address(),
+                        to_address: receiver.address(),
+                        amount: vec![coin(100, &denom).into()],
+                    },
+                    MsgBankSend::TYPE_URL,
+                    &holder,
+                )
+                .unwrap();
+
+            let balance = |address: String| bank_balance_query(&runner, address, denom.clone()).unwrap().u128();
+            assert_eq!(balance(holder.address()), 900);
+            assert_eq!(balance(receiver.address()), 85);
+            assert_eq!(balance(issuer.address()), 5);
+            let supply = runner.app.borrow().wrap().query_supply(&denom).unwrap();
+            assert_eq!(supply.amount.u128(), 990);
+
+            // Rates don't apply to transfers from the issuer
+            runner
+                .execute::<_, MsgSendResponse>(
+                    MsgBankSend {
+                        from_address: issuer.address(),
+                        to_address: receiver.address(),
+                        amount: vec![coin(5, &denom).into()],
+                    },
+                    MsgBankSend::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+            assert_eq!(balance(receiver.address()), 90);
+        }
+
+        #[test]
+        fn rates_exceeding_one_are_rejected() {
+            let runner = MultiTestRunner::new_with_stargate("core", TokenFactory::default());
+            let issuer = runner.init_default_account().unwrap();
+
+            let err = runner
+                .execute::<_, MsgIssueResponse>(
+                    MsgIssue {
+                        issuer: issuer.address(),
+                        subunit: "urate".to_string(),
+                        symbol: "RATE".to_string(),
+                        burn_rate: "0.6".to_string(),
+                        send_commission_rate: "0.5".to_string(),
+                        ..MsgIssue::default()
+                    },
+                    MsgIssue::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap_err();
+            assert!(err.to_string().contains("can't exceed 1 together"), "{}", err);
+        }
+    }
+
+    mod whitelisting {
+        use coreum_wasm_sdk::types::coreum::asset::ft::v1::{MsgIssueResponse, MsgMintResponse, MsgSetWhitelistedLimitResponse};
+        use cosmwasm_std::coin;
+        use test_tube::{Account, Runner, RunnerExecuteResult, SigningAccount};
+
+        use super::*;
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::CwItRunner;
+
+        fn mint(
+            runner: &MultiTestRunner<TokenFactory<'static>>,
+            issuer: &SigningAccount,
+            recipient: &SigningAccount,
+            amount: u128,
+        ) -> RunnerExecuteResult<MsgMintResponse> {
+            runner.execute::<_, MsgMintResponse>(
+                MsgMint {
+                    sender: issuer.address(),
+                    coin: Some(coin(amount, format!("uwl-{}", issuer.address())).into()),
+                    recipient: recipient.address(),
+                },
+                MsgMint::TYPE_URL,
+                issuer,
+            )
+        }
+
+        #[test]
+        fn mints_are_limited_by_whitelisted_limit() {
+            let runner = MultiTestRunner::new_with_stargate("core", TokenFactory::default());
+            let issuer = runner.init_default_account().unwrap();
+            let holder = runner.init_default_account().unwrap();
+            let denom = format!("uwl-{}", issuer.address());
+
+            runner
+                .execute::<_, MsgIssueResponse>(
+                    MsgIssue {
+                        issuer: issuer.address(),
+                        subunit: "uwl".to_string(),
+                        symbol: "WL".to_string(),
+                        features: vec![Feature::Whitelisting as i32],
+                        ..MsgIssue::default()
+                    },
+                    MsgIssue::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+
+            // The feature is reported by the token query
+            let token = runner
+                .app
+                .borrow()
+                .wrap()
+                .query::<coreum_wasm_sdk::assetft::TokenResponse>(&QueryRequest::Custom(CoreumQueries::AssetFT(
+                    coreum_wasm_sdk::assetft::Query::Token { denom: denom.clone() },
+                )))
+                .unwrap()
+                .token;
+            assert_eq!(token.features, Some(vec![Feature::Whitelisting as u32]));
+
+            // Without a limit nothing can be received
+            let err = mint(&runner, &issuer, &holder, 1).unwrap_err();
+            assert!(err.to_string().contains("whitelisted limit exceeded"), "unexpected error: {}", err);
+
+            runner
+                .execute::<_, MsgSetWhitelistedLimitResponse>(
+                    MsgSetWhitelistedLimit {
+                        sender: issuer.address(),
+                        account: holder.address(),
+                        coin: Some(coin(1000, &denom).into()),
+                    },
+                    MsgSetWhitelistedLimit::TYPE_URL,
+                    &issuer,
+                )
+                .unwrap();
+
+            mint(&runner, &issuer, &holder, 600).unwrap();
+            mint(&runner, &issuer, &holder, 400).unwrap();
+            let err = mint(&runner, &issuer, &holder, 1).unwrap_err();
+            assert!(err.to_string().contains("whitelisted limit exceeded"), "unexpected error: {}", err);
+
+            // The issuer is not limited
+            mint(&runner, &issuer, &issuer, 10_000).unwrap();
+        }
+    }
+
+    #[test_case(CoreumQueryModule::default(), 6 ; "default precision")]
+    #[test_case(CoreumQueryModule::new(18), 18 ; "configured precision")]
+    fn native_denom_precision(module: CoreumQueryModule, expected: u32) {
+        let app = BasicAppBuilder::<CoreumMsg, CoreumQueries>::new_custom()
+            .with_custom(module)
+            .build(|_, _, _| {});
+
+        let resp = app
+            .wrap()
+            .query::<coreum_wasm_sdk::assetft::TokenResponse>(&QueryRequest::Custom(CoreumQueries::AssetFT(
+                coreum_wasm_sdk::assetft::Query::Token {
+                    denom: DEFAULT_COIN_DENOM.to_string(),
+                },
+            )))
+            .unwrap();
+
+        assert_eq!(resp.token.denom, DEFAULT_COIN_DENOM);
+        assert_eq!(resp.token.precision, expected);
+    }
 }
@@ -1,13 +1,18 @@
 use anyhow::{anyhow, Result as AnyResult};
+use cw_storage_plus::Map;
+use osmosis_std::shim::Any;
+use osmosis_std::types::cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse};
 use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
 use osmosis_std::types::cosmos::bank::v1beta1::{
-    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse, QuerySupplyOfRequest,
-    QuerySupplyOfResponse,
+    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse, QuerySendEnabledRequest,
+    QuerySendEnabledResponse, QuerySupplyOfRequest, QuerySupplyOfResponse, QueryTotalSupplyRequest, QueryTotalSupplyResponse,
+    SendEnabled,
 };
+use osmosis_std::types::cosmos::base::query::v1beta1::PageResponse;
 
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Api, BankQuery, Binary, BlockInfo, ContractResult, Empty, Querier, QuerierWrapper, QueryRequest,
-    Storage, SystemResult, WasmQuery,
+    to_json_binary, Addr, Api, BankQuery, Binary, BlockInfo, Coin, ContractResult, Empty, Order, Querier, QuerierWrapper,
+    QueryRequest, StdResult, Storage, SystemResult, WasmQuery,
 };
 use cw_multi_test::{AppResponse, CosmosRouter, Module, Stargate, StargateFailingModule, StargateMsg, StargateQuery};
 use osmosis_std::types::cosmwasm::wasm::v1::{
@@ -17,11 +22,44 @@ use prost::Message;
 use serde::de::DeserializeOwned;
 
 use crate::multi_test::modules::{
-    QUERY_ALL_BALANCES_PATH, QUERY_BALANCE_PATH, QUERY_SUPPLY_PATH, QUERY_WASM_CONTRACT_INFO_PATH, QUERY_WASM_CONTRACT_SMART_PATH,
+    QUERY_ALL_BALANCES_PATH, QUERY_AUTH_ACCOUNT_PATH, QUERY_BALANCE_PATH, QUERY_SEND_ENABLED_PATH, QUERY_SUPPLY_PATH,
+    QUERY_TOTAL_SUPPLY_PATH, QUERY_WASM_CONTRACT_INFO_PATH, QUERY_WASM_CONTRACT_SMART_PATH,
 };
 
+/// Map of **address -> (account number, sequence)** answering auth account queries. There is no
+/// auth module in multi-test, so this is kept up to date by the
+/// [`crate::multi_test::MultiTestRunner`] for the accounts it knows about.
+pub const AUTH_ACCOUNTS: Map<&str, (u64, u64)> = Map::new("auth/accounts");
+
+/// Map of **denom -> enabled** of the denoms whose sends are explicitly enabled or disabled, as
+/// set with [`crate::multi_test::MultiTestRunner::set_send_enabled`]. Sends of denoms without an
+/// entry are enabled.
+pub const SEND_ENABLED: Map<&str, bool> = Map::new("bank/send_enabled");
+
+/// The default page size of paginated queries, as in the cosmos-sdk.
+const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// Set of the denoms that have been minted, answering total supply queries. The multi-test bank
+/// module has no query to enumerate its denoms, so they are recorded with
+/// [`track_minted_denoms`] wherever coins are minted. The amounts are queried from the bank module,
+/// so burns are reflected as well.
+pub const MINTED_DENOMS: Map<&str, Empty> = Map::new("bank/minted_denoms");
+
+/// Records the denoms of `coins` in [`MINTED_DENOMS`]. Call this after minting `coins` with
+/// [`cw_multi_test::BankSudo::Mint`], so that they are part of the total supply.
+pub fn track_minted_denoms(storage: &mut dyn Storage, coins: &[Coin]) -> StdResult<()> {
+    for coin in coins {
+        MINTED_DENOMS.save(storage, &coin.denom, &Empty {})?;
+    }
+    Ok(())
+}
+
 pub struct UnifiedStargate<Stargate = StargateFailingModule> {
     pub extra: Option<Stargate>,
+    /// Whether queries for unknown paths error when there is no `extra` module to handle them.
+    /// If false, an empty JSON object is returned instead, which deserializes into the default of
+    /// most responses. Defaults to true.
+    pub strict: bool,
 }
 
 impl<StargateT> UnifiedStargate<StargateT>
@@ -29,11 +67,20 @@ where
     StargateT: Stargate,
 {
     pub fn new_without_extra() -> Self {
-        Self { extra: None }
+        Self { extra: None, strict: true }
     }
 
     pub fn new_with_extra(extra: StargateT) -> Self {
-        Self { extra: Some(extra) }
+        Self {
+            extra: Some(extra),
+            strict: true,
+        }
+    }
+
+    /// Sets whether queries for unknown paths error, see [`UnifiedStargate::strict`].
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
     }
 }
 
@@ -148,6 +195,100 @@ where
 
                 Ok(to_json_binary(&proto_resp)?)
             }
+            QUERY_TOTAL_SUPPLY_PATH => {
+                let req = QueryTotalSupplyRequest::decode(data).map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+                let page = req.pagination.unwrap_or_default();
+
+                // Like the SDK, denoms whose supply has been burned completely are left out
+                let mut supplies = MINTED_DENOMS
+                    .keys(storage, None, None, Order::Ascending)
+                    .map(|denom| {
+                        let request = QueryRequest::Bank(BankQuery::Supply { denom: denom? });
+                        let cw_resp: cosmwasm_std::SupplyResponse = wrapper.query(&request)?;
+                        Ok(cw_resp.amount)
+                    })
+                    .collect::<AnyResult<Vec<_>>>()?;
+                supplies.retain(|supply| !supply.amount.is_zero());
+                if page.reverse {
+                    supplies.reverse();
+                }
+                let total = supplies.len() as u64;
+
+                // The key is the denom the page starts at, and takes precedence over the offset
+                let start = if page.key.is_empty() {
+                    page.offset as usize
+                } else {
+                    let key = String::from_utf8(page.key)?;
+                    supplies.iter().position(|supply| supply.denom == key).unwrap_or(supplies.len())
+                };
+                let limit = if page.limit == 0 { DEFAULT_PAGE_LIMIT } else { page.limit };
+                let end = supplies.len().min(start.saturating_add(limit as usize));
+
+                let supply = supplies
+                    .get(start..end)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|supply| ProtoCoin {
+                        denom: supply.denom.clone(),
+                        amount: supply.amount.to_string(),
+                    })
+                    .collect();
+
+                let proto_resp = QueryTotalSupplyResponse {
+                    supply,
+                    pagination: Some(PageResponse {
+                        next_key: supplies.get(end).map(|supply| supply.denom.as_bytes().to_vec()).unwrap_or_default(),
+                        total: if page.count_total { total } else { 0 },
+                    }),
+                };
+
+                Ok(to_json_binary(&proto_resp)?)
+            }
+            QUERY_SEND_ENABLED_PATH => {
+                let req = QuerySendEnabledRequest::decode(data).map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+                // Like the SDK, only denoms with an explicit entry are returned
+                let send_enabled = if req.denoms.is_empty() {
+                    SEND_ENABLED
+                        .range(storage, None, None, Order::Ascending)
+                        .map(|entry| entry.map(|(denom, enabled)| SendEnabled { denom, enabled }))
+                        .collect::<Result<_, _>>()?
+                } else {
+                    req.denoms
+                        .into_iter()
+                        .filter_map(|denom| match SEND_ENABLED.may_load(storage, &denom) {
+                            Ok(enabled) => enabled.map(|enabled| Ok(SendEnabled { denom, enabled })),
+                            Err(e) => Some(Err(e)),
+                        })
+                        .collect::<Result<_, _>>()?
+                };
+
+                Ok(to_json_binary(&QuerySendEnabledResponse {
+                    send_enabled,
+                    pagination: None,
+                })?)
+            }
+            // Auth queries
+            QUERY_AUTH_ACCOUNT_PATH => {
+                let req = QueryAccountRequest::decode(data).map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+                let Some((account_number, sequence)) = AUTH_ACCOUNTS.may_load(storage, &req.address)? else {
+                    return Err(anyhow!("account {} not found", req.address));
+                };
+
+                let proto_resp = QueryAccountResponse {
+                    account: Some(Any {
+                        type_url: BaseAccount::TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: req.address,
+                            pub_key: None,
+                            account_number,
+                            sequence,
+                        }
+                        .encode_to_vec(),
+                    }),
+                };
+
+                Ok(to_json_binary(&proto_resp)?)
+            }
             QUERY_WASM_CONTRACT_SMART_PATH => {
                 let req = QuerySmartContractStateRequest::decode(data).map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
 
@@ -199,8 +340,10 @@ where
             _ => {
                 if let Some(extra) = &self.extra {
                     extra.query(api, storage, querier, block, request)
-                } else {
+                } else if self.strict {
                     Err(anyhow!("Unexpected stargate query: path={}, data={:?}", path, request.data))
+                } else {
+                    Ok(to_json_binary(&Empty {})?)
                 }
             }
         }
@@ -208,3 +351,148 @@ where
 }
 
 impl<StargateT> Stargate for UnifiedStargate<StargateT> where StargateT: Stargate {}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(true => matches Err(_) ; "strict mode errors")]
+    #[test_case(false => matches Ok(_) ; "permissive mode returns empty")]
+    fn unknown_query_path(strict: bool) -> AnyResult<Binary> {
+        let stargate = UnifiedStargate::<StargateFailingModule>::new_without_extra().with_strict(strict);
+
+        let res = stargate.query(
+            &MockApi::default(),
+            &MockStorage::new(),
+            &MockQuerier::<Empty>::new(&[]),
+            &mock_env().block,
+            StargateQuery {
+                path: "/unknown.module.v1.Query/Unknown".to_string(),
+                data: Binary::default(),
+            },
+        );
+        if let Ok(bin) = &res {
+            assert_eq!(bin, &to_json_binary(&Empty {}).unwrap());
+        }
+        res
+    }
+    #[test]
+    fn total_supply() {
+        use osmosis_std::types::cosmos::base::query::v1beta1::PageRequest;
+        use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgMintResponse};
+        use test_tube::{Account, Runner};
+
+        use crate::multi_test::modules::TokenFactory;
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::{CwItRunner, DEFAULT_ADDRESS_PREFIX};
+
+        let runner = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+        let creator = runner.init_default_account().unwrap();
+
+        let mut denoms = vec![];
+        for (subdenom, amount) in [("denom1", 1000u128), ("denom2", 2000u128)] {
+            let denom = runner
+                .execute::<_, MsgCreateDenomResponse>(
+                    MsgCreateDenom {
+                        sender: creator.address(),
+                        subdenom: subdenom.to_string(),
+                    },
+                    MsgCreateDenom::TYPE_URL,
+                    &creator,
+                )
+                .unwrap()
+                .data
+                .new_token_denom;
+            runner
+                .execute::<_, MsgMintResponse>(
+                    MsgMint {
+                        sender: creator.address(),
+                        amount: Some(Coin::new(amount, &denom).into()),
+                        mint_to_address: creator.address(),
+                    },
+                    MsgMint::TYPE_URL,
+                    &creator,
+                )
+                .unwrap();
+            denoms.push((denom, amount));
+        }
+
+        let query_total_supply = |pagination: Option<PageRequest>| {
+            runner
+                .query::<_, QueryTotalSupplyResponse>(QUERY_TOTAL_SUPPLY_PATH, &QueryTotalSupplyRequest { pagination })
+                .unwrap()
+        };
+
+        let supply = query_total_supply(None).supply;
+        for (denom, amount) in &denoms {
+            assert!(
+                supply.contains(&ProtoCoin {
+                    denom: denom.clone(),
+                    amount: amount.to_string(),
+                }),
+                "{} missing from total supply {:?}",
+                denom,
+                supply
+            );
+        }
+
+        // Paginating one denom at a time returns every denom exactly once
+        let mut paginated = vec![];
+        let mut key = vec![];
+        loop {
+            let res = query_total_supply(Some(PageRequest {
+                key,
+                limit: 1,
+                count_total: true,
+                ..Default::default()
+            }));
+            assert_eq!(res.supply.len(), 1);
+            assert_eq!(res.pagination.as_ref().unwrap().total, supply.len() as u64);
+            paginated.extend(res.supply);
+            key = res.pagination.unwrap().next_key;
+            if key.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(paginated, supply);
+    }
+    #[test]
+    fn auth_account() {
+        use test_tube::{Account, Runner};
+
+        use crate::helpers::bank_send;
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::{CwItRunner, DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        runner.init_default_account().unwrap();
+        let account = runner.init_default_account().unwrap();
+        bank_send(&runner, &account, &account.address(), vec![Coin::new(1, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let res = runner
+            .query::<_, QueryAccountResponse>(
+                QUERY_AUTH_ACCOUNT_PATH,
+                &QueryAccountRequest {
+                    address: account.address(),
+                },
+            )
+            .unwrap();
+        let base_account = BaseAccount::decode(res.account.unwrap().value.as_slice()).unwrap();
+
+        assert_eq!(base_account.address, account.address());
+        assert_eq!((base_account.account_number, base_account.sequence), (1, 1));
+
+        // Unknown accounts are not found, like on chain
+        assert!(runner
+            .query::<_, QueryAccountResponse>(
+                QUERY_AUTH_ACCOUNT_PATH,
+                &QueryAccountRequest {
+                    address: "osmo1unknown".to_string(),
+                },
+            )
+            .is_err());
+    }
+}
@@ -1,7 +1,11 @@
 use crate::multi_test::api::MockApiBech32;
-use crate::multi_test::modules::unified_stargate::UnifiedStargate;
+use crate::multi_test::debug::{diff_storage, DebugReport};
+use crate::multi_test::mock_address_generator::Bech32AddressGenerator;
+use crate::multi_test::modules::unified_stargate::{track_minted_denoms, UnifiedStargate, AUTH_ACCOUNTS, SEND_ENABLED};
+use crate::multi_test::storage_cache::StorageCache;
 use crate::MultiTestStargateBound;
 use crate::test_runner::DefaultStargate;
+use crate::traits::{CwItResponse, DEFAULT_COIN_DENOM};
 use crate::{traits::CwItRunner, ContractType};
 use anyhow::bail;
 use cosmrs::proto::cosmos::bank::v1beta1::{
@@ -14,17 +18,17 @@ use cosmrs::proto::cosmwasm::wasm::v1::{
 };
 use cosmwasm_std::testing::{MockApi, MockStorage};
 use cw_multi_test::{
-    AcceptingModule, BankKeeper, BankSudo, BasicAppBuilder, DistributionKeeper, FailingModule, GovFailingModule, IbcFailingModule,
-    MockAddressGenerator, Router, StakeKeeper, Stargate, StargateFailingModule, WasmKeeper,
+    AcceptingModule, AppResponse, BankKeeper, BankSudo, BasicAppBuilder, Contract, CosmosRouter, DistributionKeeper, Executor,
+    FailingModule, GovFailingModule, IbcFailingModule, Module, Router, StakeKeeper, StargateFailingModule, WasmKeeper,
 };
 
 use cosmrs::{crypto::secp256k1::SigningKey, proto::cosmos::base::abci::v1beta1::GasInfo};
 use cosmwasm_std::{
-    Addr, AllBalanceResponse, BalanceResponse, BankMsg, BankQuery, Binary, Coin, ContractInfoResponse, CosmosMsg, Empty, QueryRequest, StakingMsg, StdResult, SupplyResponse, WasmMsg, WasmQuery, coin, from_binary, from_json, to_json_binary
+    Addr, AllBalanceResponse, BalanceResponse, BankMsg, BankQuery, Binary, BlockInfo, Coin, ContractInfoResponse, CosmosMsg, DenomMetadataResponse, Deps, DepsMut, Empty, Env, MessageInfo, Order, QuerierWrapper, QueryRequest, Reply, Response, StakingMsg, StdResult, Storage, SupplyResponse, Timestamp, Uint128, WasmMsg, WasmQuery, coin, from_binary, from_json, to_json_binary, to_json_vec
 };
 use osmosis_std::types::{
     cosmos::{
-        bank::v1beta1::MsgSend,
+        bank::v1beta1::{DenomUnit, Metadata, MsgSend},
         staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate},
     },
     cosmwasm::wasm::v1::{MsgClearAdmin, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract, MsgUpdateAdmin},
@@ -33,7 +37,9 @@ use prost::Message;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde::ser::SerializeMap;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
 use std::str::FromStr;
 use test_tube::{Account, DecodeError, EncodeError, FeeSetting, Runner, RunnerError, SigningAccount};
 
@@ -72,6 +78,104 @@ where
         >,
     >,
     pub address_prefix: String,
+    /// Code ids of all contracts stored through [`CwItRunner::store_code`] along with the
+    /// contracts, in order of storage. The contracts are kept to store them in forks of the runner.
+    stored_codes: RefCell<Vec<(u64, Rc<dyn Contract<ExecC, QueryC>>)>>,
+    /// Addresses of the contracts deployed through [`MultiTestRunner::deploy_named`], by name.
+    named_contracts: RefCell<HashMap<String, String>>,
+    /// The approximate amount of gas that may be used per block, see
+    /// [`MultiTestRunner::with_block_gas_limit`].
+    block_gas_limit: Option<u64>,
+    /// The height of the current block and the approximate gas used in it so far.
+    block_gas_used: RefCell<(u64, u64)>,
+    /// The synthesized **account number, sequence** of every account known to the runner, by
+    /// address. Account numbers are assigned in the order the accounts are first seen, and the
+    /// sequence is incremented with every transaction signed by the account.
+    accounts: RefCell<HashMap<String, (u64, u64)>>,
+    /// Whether the approximate gas used by executions is recorded, see
+    /// [`MultiTestRunner::with_gas_tracking`].
+    gas_tracking: bool,
+    /// The approximate gas used by the last successful execution, if gas tracking is enabled.
+    last_gas_used: Cell<Option<u64>>,
+    /// The denom reported by [`CwItRunner::fee_denom`], see [`MultiTestRunner::with_fee_denom`].
+    fee_denom: String,
+    /// The fee in the fee denom charged per transaction, see [`MultiTestRunner::with_tx_fee`].
+    tx_fee: Option<u128>,
+    /// Type urls of the messages signed by accounts that are passed to the stargate module as they
+    /// are, see [`MultiTestRunner::with_stargate_msgs`].
+    stargate_msgs: HashSet<String>,
+    /// Whether the `sudo` entry point of the stargate module runs whenever the block is advanced,
+    /// see [`MultiTestRunner::with_sudo_on_block_advance`].
+    sudo_on_block_advance: bool,
+}
+
+/// The approximate gas charged per executed message when a block gas limit is set.
+const APPROX_GAS_PER_MSG: u64 = 50_000;
+/// The approximate gas charged per byte of a JSON encoded message when a block gas limit is set.
+const APPROX_GAS_PER_BYTE: u64 = 10;
+/// The approximate gas charged per event emitted by an execution when gas tracking is enabled, to
+/// account for the sub-messages and state changes of contracts.
+const APPROX_GAS_PER_EVENT: u64 = 5_000;
+
+/// Returns the approximate gas `msgs` use, based on their JSON encoded size.
+fn approx_msgs_gas(msgs: &[CosmosMsg<ExecC>]) -> Result<u64, RunnerError> {
+    msgs.iter()
+        .map(|msg| {
+            let bytes = to_json_vec(msg).map_err(|e| RunnerError::GenericError(e.to_string()))?;
+            Ok(APPROX_GAS_PER_MSG + APPROX_GAS_PER_BYTE * bytes.len() as u64)
+        })
+        .sum()
+}
+
+/// Returns the wasm keeper of the app of a [`MultiTestRunner`]. Contract addresses are generated
+/// as bech32 addresses with `prefix`, the same prefix the [`MockApiBech32`] of the app validates
+/// account addresses with, so that all addresses of the app share the prefix of the runner.
+fn wasm_keeper(prefix: &str) -> WasmKeeper<ExecC, QueryC> {
+    WasmKeeper::new().with_address_generator(Bech32AddressGenerator::new(prefix))
+}
+
+/// A copy of the state of a [`MultiTestRunner`], taken with [`MultiTestRunner::snapshot`] and
+/// reapplied with [`MultiTestRunner::restore`]. Snapshots are only supported by the multi-test
+/// runner, as the test-tube and RPC runners don't expose their underlying storage.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// All key-value pairs of the app storage.
+    storage: Vec<(Vec<u8>, Vec<u8>)>,
+    block: BlockInfo,
+    named_contracts: HashMap<String, String>,
+    accounts: HashMap<String, (u64, u64)>,
+    block_gas_used: (u64, u64),
+    last_gas_used: Option<u64>,
+}
+
+/// A contract stored in the app of a [`MultiTestRunner`] and all of its forks. Multi-test contracts
+/// only hold pointers to their entry points, so they can be shared between apps.
+struct SharedContract(Rc<dyn Contract<ExecC, QueryC>>);
+
+impl Contract<ExecC, QueryC> for SharedContract {
+    fn execute(&self, deps: DepsMut<QueryC>, env: Env, info: MessageInfo, msg: Vec<u8>) -> anyhow::Result<Response<ExecC>> {
+        self.0.execute(deps, env, info, msg)
+    }
+
+    fn instantiate(&self, deps: DepsMut<QueryC>, env: Env, info: MessageInfo, msg: Vec<u8>) -> anyhow::Result<Response<ExecC>> {
+        self.0.instantiate(deps, env, info, msg)
+    }
+
+    fn query(&self, deps: Deps<QueryC>, env: Env, msg: Vec<u8>) -> anyhow::Result<Binary> {
+        self.0.query(deps, env, msg)
+    }
+
+    fn sudo(&self, deps: DepsMut<QueryC>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response<ExecC>> {
+        self.0.sudo(deps, env, msg)
+    }
+
+    fn reply(&self, deps: DepsMut<QueryC>, env: Env, msg: Reply) -> anyhow::Result<Response<ExecC>> {
+        self.0.reply(deps, env, msg)
+    }
+
+    fn migrate(&self, deps: DepsMut<QueryC>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response<ExecC>> {
+        self.0.migrate(deps, env, msg)
+    }
 }
 
 impl MultiTestRunner<StargateFailingModule> {
@@ -83,7 +187,7 @@ impl MultiTestRunner<StargateFailingModule> {
         let leaked_prefix: &'static str = Box::leak(prefix_string.clone().into_boxed_str());
 
         // Construct app
-        let wasm_keeper: WasmKeeper<Empty, Empty> = WasmKeeper::new().with_address_generator(MockAddressGenerator);
+        let wasm_keeper = wasm_keeper(leaked_prefix);
 
         let stargate = UnifiedStargate::new_without_extra();
 
@@ -96,6 +200,17 @@ impl MultiTestRunner<StargateFailingModule> {
         Self {
             app: app.into(),
             address_prefix: prefix_string,
+            stored_codes: RefCell::new(vec![]),
+            named_contracts: RefCell::new(HashMap::new()),
+            block_gas_limit: None,
+            block_gas_used: RefCell::new((0, 0)),
+            accounts: RefCell::new(HashMap::new()),
+            gas_tracking: false,
+            last_gas_used: Cell::new(None),
+            fee_denom: DEFAULT_COIN_DENOM.to_string(),
+            tx_fee: None,
+            stargate_msgs: HashSet::new(),
+            sudo_on_block_advance: false,
         }
     }
 
@@ -105,7 +220,7 @@ impl MultiTestRunner<StargateFailingModule> {
         let leaked_prefix: &'static str = Box::leak(prefix_string.clone().into_boxed_str());
 
         // Construct app with CoreumMsg and CoreumQueries
-        let wasm_keeper: WasmKeeper<ExecC, QueryC> = WasmKeeper::new().with_address_generator(MockAddressGenerator);
+        let wasm_keeper = wasm_keeper(leaked_prefix);
 
         let stargate = UnifiedStargate::new_without_extra();
 
@@ -119,13 +234,24 @@ impl MultiTestRunner<StargateFailingModule> {
         Self {
             app: app.into(),
             address_prefix: prefix_string,
+            stored_codes: RefCell::new(vec![]),
+            named_contracts: RefCell::new(HashMap::new()),
+            block_gas_limit: None,
+            block_gas_used: RefCell::new((0, 0)),
+            accounts: RefCell::new(HashMap::new()),
+            gas_tracking: false,
+            last_gas_used: Cell::new(None),
+            fee_denom: DEFAULT_COIN_DENOM.to_string(),
+            tx_fee: None,
+            stargate_msgs: HashSet::new(),
+            sudo_on_block_advance: false,
         }
     }
 }
 
 impl<StargateT> MultiTestRunner<StargateT>
 where
-    StargateT: Stargate + 'static,
+    StargateT: MultiTestStargateBound,
 {
     /// Creates a new instance of a `MultiTestRunner`, wrapping a `cw_multi_test::App`
     /// with the given address prefix and stargate keeper. This is needed for testing
@@ -135,7 +261,7 @@ where
         let prefix_string = address_prefix.to_owned();
         let leaked_prefix: &'static str = Box::leak(prefix_string.clone().into_boxed_str());
 
-        let wasm_keeper: WasmKeeper<Empty, Empty> = WasmKeeper::new().with_address_generator(MockAddressGenerator);
+        let wasm_keeper = wasm_keeper(leaked_prefix);
 
         let stargate = UnifiedStargate::new_with_extra(stargate_impl);
 
@@ -149,6 +275,17 @@ where
         Self {
             app: app.into(),
             address_prefix: prefix_string,
+            stored_codes: RefCell::new(vec![]),
+            named_contracts: RefCell::new(HashMap::new()),
+            block_gas_limit: None,
+            block_gas_used: RefCell::new((0, 0)),
+            accounts: RefCell::new(HashMap::new()),
+            gas_tracking: false,
+            last_gas_used: Cell::new(None),
+            fee_denom: DEFAULT_COIN_DENOM.to_string(),
+            tx_fee: None,
+            stargate_msgs: HashSet::new(),
+            sudo_on_block_advance: false,
         }
     }
 
@@ -157,7 +294,7 @@ where
         let prefix_string = address_prefix.to_owned();
         let leaked_prefix: &'static str = Box::leak(prefix_string.clone().into_boxed_str());
 
-        let wasm_keeper: WasmKeeper<ExecC, QueryC> = WasmKeeper::new().with_address_generator(MockAddressGenerator);
+        let wasm_keeper = wasm_keeper(leaked_prefix);
 
         let stargate = UnifiedStargate::new_with_extra(stargate_impl);
 
@@ -172,13 +309,24 @@ where
         Self {
             app: app.into(),
             address_prefix: prefix_string,
+            stored_codes: RefCell::new(vec![]),
+            named_contracts: RefCell::new(HashMap::new()),
+            block_gas_limit: None,
+            block_gas_used: RefCell::new((0, 0)),
+            accounts: RefCell::new(HashMap::new()),
+            gas_tracking: false,
+            last_gas_used: Cell::new(None),
+            fee_denom: DEFAULT_COIN_DENOM.to_string(),
+            tx_fee: None,
+            stargate_msgs: HashSet::new(),
+            sudo_on_block_advance: false,
         }
     }
 }
 
 impl<StargateT> Runner<'_> for MultiTestRunner<StargateT>
 where
-    StargateT: Stargate + 'static,
+    StargateT: MultiTestStargateBound,
 {
     fn execute_cosmos_msgs<S>(
         &self,
@@ -188,8 +336,6 @@ where
     where
         S: test_tube::cosmrs::proto::traits::Message + Default,
     {
-        let sender = Addr::unchecked(signer.address());
-
         // Convert CosmosMsg<Empty> to CosmosMsg<ExecC>
         let converted_msgs: Vec<CosmosMsg<ExecC>> = msgs
             .iter()
@@ -201,17 +347,10 @@ where
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        // Execute messages with multi test app
-        let app_responses = self
-            .app
-            .borrow_mut()
-            .execute_multi(sender, converted_msgs)
-            // NB: Must use this syntax to capture full anyhow message.
-            // to_string() will only give the outermost error context.
-            .map_err(|e| RunnerError::GenericError(format!("{:#}", e)))?;
+        let (app_responses, gas_used) = self.execute_signed(converted_msgs, signer)?;
 
         // Construct test_tube::ExecuteResponse from cw_multi_test::AppResponse
-        let events = app_responses.iter().flat_map(|r| r.events.clone()).collect();
+        let events: Vec<cosmwasm_std::Event> = app_responses.iter().flat_map(|r| r.events.clone()).collect();
         let tmp = app_responses
             .iter()
             .map(|r| r.data.clone())
@@ -227,10 +366,7 @@ where
             data,
             events,
             raw_data,
-            gas_info: GasInfo {
-                gas_wanted: 0,
-                gas_used: 0,
-            },
+            gas_info: GasInfo { gas_wanted: 0, gas_used },
         };
 
         Ok(runner_res)
@@ -265,104 +401,7 @@ where
     where
         R: test_tube::cosmrs::proto::traits::Message + Default,
     {
-        let msgs = msgs
-            .iter()
-            .map(|msg| match msg.type_url.as_str() {
-                // WasmMsg
-                MsgExecuteContract::TYPE_URL => {
-                    let msg = MsgExecuteContract::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::Execute {
-                        contract_addr: msg.contract,
-                        msg: Binary(msg.msg),
-                        funds: msg
-                            .funds
-                            .into_iter()
-                            .map(|c| coin(u128::from_str(&c.amount).unwrap(), c.denom))
-                            .collect(),
-                    }))
-                }
-                MsgInstantiateContract::TYPE_URL => {
-                    let msg = MsgInstantiateContract::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::Instantiate {
-                        code_id: msg.code_id,
-                        admin: Some(msg.admin),
-                        msg: Binary(msg.msg),
-                        funds: msg
-                            .funds
-                            .into_iter()
-                            .map(|c| coin(u128::from_str(&c.amount).unwrap(), c.denom))
-                            .collect(),
-                        label: msg.label,
-                    }))
-                }
-                MsgMigrateContract::TYPE_URL => {
-                    let msg = MsgMigrateContract::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::Migrate {
-                        contract_addr: msg.contract,
-                        new_code_id: msg.code_id,
-                        msg: Binary(msg.msg),
-                    }))
-                }
-                MsgUpdateAdmin::TYPE_URL => {
-                    let msg = MsgUpdateAdmin::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::UpdateAdmin {
-                        contract_addr: msg.contract,
-                        admin: msg.new_admin,
-                    }))
-                }
-                MsgClearAdmin::TYPE_URL => {
-                    let msg = MsgClearAdmin::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::ClearAdmin {
-                        contract_addr: msg.contract,
-                    }))
-                }
-                // BankMsg
-                MsgSend::TYPE_URL => {
-                    let msg = MsgSend::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    Ok(CosmosMsg::<ExecC>::Bank(BankMsg::Send {
-                        to_address: msg.to_address,
-                        amount: msg
-                            .amount
-                            .into_iter()
-                            .map(|c| coin(u128::from_str(&c.amount).unwrap(), c.denom))
-                            .collect(),
-                    }))
-                }
-                // StakingMsg
-                MsgDelegate::TYPE_URL => {
-                    let msg = MsgDelegate::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    let proto_coin = msg.amount.unwrap_or_default();
-                    Ok(CosmosMsg::<ExecC>::Staking(StakingMsg::Delegate {
-                        validator: msg.validator_address,
-                        amount: coin(u128::from_str(&proto_coin.amount).unwrap(), proto_coin.denom),
-                    }))
-                }
-                MsgUndelegate::TYPE_URL => {
-                    let msg = MsgUndelegate::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    let proto_coin = msg.amount.unwrap_or_default();
-                    Ok(CosmosMsg::<ExecC>::Staking(StakingMsg::Undelegate {
-                        validator: msg.validator_address,
-                        amount: coin(u128::from_str(&proto_coin.amount).unwrap(), proto_coin.denom),
-                    }))
-                }
-                MsgBeginRedelegate::TYPE_URL => {
-                    let msg = MsgBeginRedelegate::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
-                    let proto_coin = msg.amount.unwrap_or_default();
-                    Ok(CosmosMsg::<ExecC>::Staking(StakingMsg::Redelegate {
-                        src_validator: msg.validator_src_address,
-                        dst_validator: msg.validator_dst_address,
-                        amount: coin(u128::from_str(&proto_coin.amount).unwrap(), proto_coin.denom),
-                    }))
-                }
-                _ => {
-                    // Else assume StargateMsg
-                    Ok(CosmosMsg::<ExecC>::Stargate {
-                        type_url: msg.type_url.clone(),
-                        value: msg.value.clone().into(),
-                    })
-                }
-            })
-            .collect::<Result<Vec<CosmosMsg<ExecC>>, RunnerError>>()?;
+        let msgs = self.any_to_cosmos_msgs(&msgs)?;
 
         // Convert CosmosMsg<ExecC> to CosmosMsg<Empty> for the trait method
         let converted_msgs: Vec<CosmosMsg> = msgs
@@ -399,13 +438,98 @@ where
     }
 }
 
+/// Converts a [`MsgSend`] into a [`BankMsg::Send`], validating that all amounts are valid and that
+/// the sender holds enough of every denom being sent. Balances are checked against the state before
+/// the transaction, so a clear error is returned rather than the bank module's underflow error.
+/// Sends of denoms disabled with [`MultiTestRunner::set_send_enabled`] are rejected.
+/// With the `coreum` feature, frozen funds and sends above whitelisted limits are rejected as well,
+/// and the `burn_rate` and `send_commission_rate` of the sent denoms are deducted from the sent
+/// amounts, adding a [`BankMsg::Burn`] and a [`BankMsg::Send`] to the issuer as needed.
+#[cfg_attr(not(feature = "coreum"), allow(unused_variables, unused_mut))]
+fn bank_send_msgs(
+    querier: &QuerierWrapper<QueryC>,
+    storage: &dyn cosmwasm_std::Storage,
+    msg: MsgSend,
+) -> Result<Vec<CosmosMsg<ExecC>>, RunnerError> {
+    let mut amount: Vec<Coin> = vec![];
+    for c in msg.amount {
+        let value = u128::from_str(&c.amount)
+            .map_err(|e| RunnerError::GenericError(format!("invalid amount {}{} in MsgSend: {}", c.amount, c.denom, e)))?;
+        match amount.iter_mut().find(|existing| existing.denom == c.denom) {
+            Some(existing) => existing.amount += Uint128::new(value),
+            None => amount.push(coin(value, c.denom)),
+        }
+    }
+
+    let mut burns: Vec<Coin> = vec![];
+    let mut commissions: Vec<(String, Coin)> = vec![];
+    for required in amount.iter_mut() {
+        if SEND_ENABLED.may_load(storage, &required.denom).ok().flatten() == Some(false) {
+            return Err(RunnerError::GenericError(format!(
+                "{} transfers are currently disabled",
+                required.denom
+            )));
+        }
+        let balance = querier
+            .query_balance(&msg.from_address, &required.denom)
+            .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+        if balance.amount < required.amount {
+            return Err(RunnerError::GenericError(format!(
+                "insufficient funds: {} has {}, but needs {}",
+                msg.from_address, balance, required
+            )));
+        }
+        #[cfg(feature = "coreum")]
+        {
+            crate::multi_test::modules::assert_spendable(storage, &msg.from_address, required, balance.amount)
+                .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+
+            let deductions = crate::multi_test::modules::transfer_deductions(storage, &msg.from_address, &msg.to_address, required)
+                .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+            if !deductions.burn.is_zero() {
+                burns.push(coin(deductions.burn.u128(), &required.denom));
+            }
+            if !deductions.commission.is_zero() {
+                commissions.push((deductions.issuer, coin(deductions.commission.u128(), &required.denom)));
+            }
+            required.amount -= deductions.burn + deductions.commission;
+
+            let recipient_balance = querier
+                .query_balance(&msg.to_address, &required.denom)
+                .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+            crate::multi_test::modules::assert_receivable(storage, &msg.to_address, required, recipient_balance.amount)
+                .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+        }
+    }
+
+    let mut msgs = vec![CosmosMsg::<ExecC>::Bank(BankMsg::Send {
+        to_address: msg.to_address,
+        amount,
+    })];
+    if !burns.is_empty() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Burn { amount: burns }));
+    }
+    for (issuer, commission) in commissions {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: issuer,
+            amount: vec![commission],
+        }));
+    }
+    Ok(msgs)
+}
+
 impl<'a, StargateT> CwItRunner<'a> for MultiTestRunner<StargateT>
 where
     StargateT: MultiTestStargateBound,
 {
     fn store_code(&self, code: ContractType, _signer: &SigningAccount) -> Result<u64, anyhow::Error> {
         match code {
-            ContractType::MultiTestContract(contract) => Ok(self.app.borrow_mut().store_code(contract)),
+            ContractType::MultiTestContract(contract) => {
+                let contract: Rc<dyn Contract<ExecC, QueryC>> = Rc::from(contract);
+                let code_id = self.app.borrow_mut().store_code(Box::new(SharedContract(contract.clone())));
+                self.stored_codes.borrow_mut().push((code_id, contract));
+                Ok(code_id)
+            }
             ContractType::Artifact(_) => bail!("Artifact not supported for MultiTestRunner"),
         }
     }
@@ -422,18 +546,20 @@ where
             },
         );
 
+        self.register_account(&account.address())?;
+
         // Mint the initial balances to the account
         if !initial_balance.is_empty() {
-            self.app
-                .borrow_mut()
-                .sudo(
-                    BankSudo::Mint {
-                        to_address: account.address(),
-                        amount: initial_balance.to_vec(),
-                    }
-                    .into(),
-                )
-                .unwrap();
+            let mut app = self.app.borrow_mut();
+            app.sudo(
+                BankSudo::Mint {
+                    to_address: account.address(),
+                    amount: initial_balance.to_vec(),
+                }
+                .into(),
+            )
+            .unwrap();
+            app.init_modules(|_, _, storage| track_minted_denoms(storage, initial_balance))?;
         }
 
         Ok(account)
@@ -453,317 +579,2176 @@ where
             block.height += 1;
         });
 
-        Ok(())
+        self.advance_block()
     }
 
     fn query_block_time_nanos(&self) -> u64 {
         self.app.borrow().block_info().time.nanos()
     }
-}
 
-impl<StargateT> MultiTestRunner<StargateT>
-where
-    StargateT: MultiTestStargateBound,
-{
-    pub fn query_wasm_smart<T: DeserializeOwned>(&self, contract_addr: impl Into<String>, msg: &impl Serialize) -> StdResult<T> {
-        self.app.borrow().wrap().query_wasm_smart(contract_addr, msg)
+    fn query_block_height(&self) -> Result<u64, anyhow::Error> {
+        Ok(self.app.borrow().block_info().height)
     }
-}
 
-#[cfg(test)]
-#[cfg(not(feature = "coreum"))]
-mod tests {
-    use cosmrs::proto::cosmos::bank::v1beta1::MsgSendResponse;
-    use cosmwasm_std::{coin, Event, Uint128};
+    fn set_block_time(&self, unix_seconds: u64) -> Result<(), anyhow::Error> {
+        let mut app = self.app.borrow_mut();
+        let mut block = app.block_info();
+        let time = Timestamp::from_seconds(unix_seconds);
+        if time < block.time {
+            bail!(
+                "Cannot set block time to {}, it is before the current block time {}",
+                unix_seconds,
+                block.time.seconds()
+            );
+        }
+        block.time = time;
+        app.set_block(block);
+        drop(app);
 
-    use crate::test_helpers::*;
-    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
-    use crate::{artifact::Artifact, helpers::upload_wasm_file};
-    use cw_multi_test::ContractWrapper;
+        self.advance_block()
+    }
 
-    use cw20::MinterResponse;
-    use osmosis_std::types::cosmos::bank::v1beta1::{
-        QueryBalanceRequest, QuerySupplyOfRequest as OsmosisQuerySupplyOfRequest, QueryTotalSupplyRequest,
-    };
-    use osmosis_std::types::cosmwasm::wasm::v1::QueryContractInfoRequest;
-    use osmosis_std::types::{cosmos::bank::v1beta1::QueryAllBalancesRequest, cosmwasm::wasm::v1::MsgInstantiateContractResponse};
-    use test_tube::{Bank, Module, RunnerExecuteResult, Wasm};
+    fn increase_blocks(&self, blocks: u64) -> Result<(), anyhow::Error> {
+        self.app.borrow_mut().update_block(|block| block.height += blocks);
+        self.advance_block()
+    }
 
-    use super::*;
+    fn migrate_contract(
+        &self,
+        contract: &str,
+        new_code_id: u64,
+        msg: &[u8],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, anyhow::Error> {
+        let msg = CosmosMsg::<ExecC>::Wasm(WasmMsg::Migrate {
+            contract_addr: contract.to_string(),
+            new_code_id,
+            msg: Binary::from(msg),
+        });
+        let (mut responses, _) = self.execute_signed(vec![msg], signer)?;
 
-    fn instantiate_astro_token(app: &MultiTestRunner, signer: &SigningAccount) -> RunnerExecuteResult<MsgInstantiateContractResponse> {
-        let code_id = upload_wasm_file(
-            app,
-            signer,
-            ContractType::MultiTestContract(Box::new(ContractWrapper::new(
-                cw20_base::contract::execute,
-                cw20_base::contract::instantiate,
-                cw20_base::contract::query,
-            ))),
-        )
-        .unwrap();
+        Ok(responses.remove(0).into())
+    }
 
-        let init_msg = cw20_base::msg::InstantiateMsg {
-            name: "Astro Token".to_string(),
-            symbol: "ASTRO".to_string(),
-            decimals: 6,
-            initial_balances: vec![],
-            mint: Some(MinterResponse {
-                minter: signer.address(),
-                cap: None,
-            }),
-            marketing: None,
-        };
+    fn query_code_hash(&self, code_id: u64) -> Result<Vec<u8>, anyhow::Error> {
+        // cw-multi-test computes a checksum for every stored contract, so no wasm is needed
+        Ok(self.app.borrow().wrap().query_wasm_code_info(code_id)?.checksum.to_vec())
+    }
 
-        let wasm = Wasm::new(app);
-        wasm.instantiate(code_id, &init_msg, None, Some("counter"), &[], signer)
+    /// Issues the token factory `Params` query through the app. Apps whose stargate module does not
+    /// answer it, i.e. that have no token factory, charge no fee for creating denoms.
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, anyhow::Error> {
+        Ok(crate::helpers::query_denom_creation_fee(self).unwrap_or(None))
     }
 
-    #[test]
-    fn upload_contract() {
-        let contract = ContractType::MultiTestContract(test_contract::contract());
+    fn is_contract(&self, address: &str) -> Result<bool, anyhow::Error> {
+        Ok(self.app.borrow().contract_data(&Addr::unchecked(address)).is_ok())
+    }
 
-        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
-        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+    /// Errors for addresses that were neither created through [`CwItRunner::init_account`] nor
+    /// signed a transaction yet, like the auth module does for accounts that don't exist.
+    fn account_info(&self, address: &str) -> Result<(u64, u64), anyhow::Error> {
+        match self.accounts.borrow().get(address) {
+            Some(info) => Ok(*info),
+            None => bail!("Account {} not found", address),
+        }
+    }
 
-        let code_id = app.store_code(contract, &alice).unwrap();
+    fn execute_contract<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, anyhow::Error> {
+        let msg = CosmosMsg::<ExecC>::Wasm(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(msg)?,
+            funds: funds.to_vec(),
+        });
+        let (mut responses, _) = self.execute_signed(vec![msg], signer)?;
 
-        assert_eq!(code_id, 1);
+        Ok(responses.remove(0).into())
     }
 
-    #[test]
-    #[should_panic]
-    // This test should panic because we are trying to upload a wasm contract to a MultiTestRunner
-    // which does not support wasm contracts.
-    fn upload_wasm_artifact() {
-        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
-        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        funds: &[Coin],
+        admin: Option<&str>,
+        label: &str,
+        signer: &SigningAccount,
+    ) -> Result<String, anyhow::Error> {
+        let msg = CosmosMsg::<ExecC>::Wasm(WasmMsg::Instantiate {
+            admin: admin.map(str::to_string),
+            code_id,
+            msg: to_json_binary(msg)?,
+            funds: funds.to_vec(),
+            label: label.to_string(),
+        });
+        let (responses, _) = self.execute_signed(vec![msg], signer)?;
 
-        let _code_id = upload_wasm_file(
-            &app,
-            &alice,
-            ContractType::Artifact(Artifact::Local(counter::WASM_PATH.to_string())),
-        )
-        .unwrap();
+        // The address of the new contract is the `_contract_address` of the instantiate event
+        let address = responses
+            .iter()
+            .flat_map(|r| &r.events)
+            .filter(|event| event.ty == "instantiate")
+            .flat_map(|event| &event.attributes)
+            .find(|attr| attr.key == "_contract_address")
+            .map(|attr| attr.value.clone());
+        match address {
+            Some(address) => Ok(address),
+            None => bail!("No instantiate event with the address of code {} emitted", code_id),
+        }
     }
 
-    #[test]
-    // This test should panic because we are trying to upload a wasm contract to a MultiTestRunner
-    // which does not support wasm contracts.
-    fn wasm_instantiate_contract() {
-        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
-        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
-
-        // Instantiate with test_tube::Wasm
-        let res = instantiate_astro_token(&app, &alice).unwrap();
-        assert_eq!(res.events.len(), 1);
-        assert_eq!(res.events[0].ty, "instantiate".to_string());
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, contract: &str, query: &Q) -> Result<R, anyhow::Error> {
+        Ok(self.app.borrow().wrap().query_wasm_smart(contract, query)?)
     }
 
-    #[test]
-    fn wasm_execute_contract() {
-        // start the keeper
-        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    fn query_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, anyhow::Error> {
+        let metadata: DenomMetadataResponse = self
+            .app
+            .borrow()
+            .wrap()
+            .query(&QueryRequest::Bank(BankQuery::DenomMetadata { denom: denom.to_string() }))?;
+        let metadata = metadata.metadata;
 
-        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+        // The bank module returns empty metadata for denoms without metadata
+        if metadata.base.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Metadata {
+            description: metadata.description,
+            denom_units: metadata
+                .denom_units
+                .into_iter()
+                .map(|unit| DenomUnit {
+                    denom: unit.denom,
+                    exponent: unit.exponent,
+                    aliases: unit.aliases,
+                })
+                .collect(),
+            base: metadata.base,
+            display: metadata.display,
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: metadata.uri,
+            uri_hash: metadata.uri_hash,
+        }))
+    }
 
-        let res = instantiate_astro_token(&app, &alice).unwrap();
+    fn fund_account(&self, address: &str, coins: &[Coin]) -> Result<(), anyhow::Error> {
+        let mut app = self.app.borrow_mut();
+        app.sudo(
+            BankSudo::Mint {
+                to_address: address.to_string(),
+                amount: coins.to_vec(),
+            }
+            .into(),
+        )?;
+        app.init_modules(|_, _, storage| track_minted_denoms(storage, coins))?;
+        Ok(())
+    }
 
-        let contract_addr = res.data.address;
+    /// Multi-test does not meter gas, so the gas used is approximated like with
+    /// [`MultiTestRunner::with_gas_tracking`], by executing `msgs` on a cache of the app storage
+    /// that is discarded afterwards. Like an execution, the simulation fails if the signer cannot
+    /// afford the fee or the messages exceed the remaining block gas, and the messages see the
+    /// balances after the fee and the incremented sequence of the signer.
+    fn simulate(&self, msgs: Vec<cosmrs::Any>, signer: &SigningAccount) -> Result<GasInfo, anyhow::Error> {
+        let msgs = self.any_to_cosmos_msgs(&msgs)?;
+        let msgs_gas = approx_msgs_gas(&msgs)?;
+        self.check_block_gas(&msgs)?;
+        let fee = self.check_tx_fee(&signer.address())?;
+
+        let app = self.app.borrow();
+        let mut cache = StorageCache::new(app.storage());
+        let block = app.block_info();
+        let sender = Addr::unchecked(signer.address());
 
-        let wasm = Wasm::new(&app);
-        let res = wasm
-            .execute(
-                &contract_addr,
-                &cw20_base::msg::ExecuteMsg::Mint {
-                    recipient: alice.address(),
-                    amount: 100u128.into(),
-                },
-                &[],
-                &alice,
-            )
-            .unwrap();
-        assert_eq!(res.events.len(), 2);
+        if let Some(fee) = fee {
+            let burn = BankMsg::Burn { amount: vec![fee] }.into();
+            app.router().execute(app.api(), &mut cache, &block, sender.clone(), burn)?;
+        }
+        let (account_number, sequence) = self
+            .accounts
+            .borrow()
+            .get(sender.as_str())
+            .copied()
+            .unwrap_or((self.accounts.borrow().len() as u64, 0));
+        AUTH_ACCOUNTS.save(&mut cache, sender.as_str(), &(account_number, sequence + 1))?;
+
+        let mut events = 0;
+        for msg in msgs {
+            let res = app.router().execute(app.api(), &mut cache, &block, sender.clone(), msg)?;
+            events += res.events.len() as u64;
+        }
 
-        let wasm_event = res.events.iter().find(|e| e.ty == "wasm").unwrap();
-        assert_eq!(
-            wasm_event,
-            &Event::new("wasm")
-                .add_attribute("_contract_address", contract_addr)
-                .add_attribute("action", "mint")
-                .add_attribute("to", alice.address())
-                .add_attribute("amount", "100")
-        );
+        let gas_used = msgs_gas + APPROX_GAS_PER_EVENT * events;
+        Ok(GasInfo {
+            gas_wanted: gas_used,
+            gas_used,
+        })
     }
 
-    #[test]
-    fn wasm_smart_query_contract() {
-        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+    /// The denom set with [`MultiTestRunner::with_fee_denom`], or the default denom of the chain.
+    fn fee_denom(&self) -> Result<&str, anyhow::Error> {
+        Ok(&self.fee_denom)
+    }
 
-        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+    /// The chain id of the block contracts see, see [`MultiTestRunner::with_chain_id`].
+    fn chain_id(&self) -> Result<String, anyhow::Error> {
+        Ok(self.app.borrow().block_info().chain_id)
+    }
+}
 
-        let res = instantiate_astro_token(&app, &alice).unwrap();
+impl<StargateT> MultiTestRunner<StargateT>
+where
+    StargateT: MultiTestStargateBound,
+{
+    pub fn query_wasm_smart<T: DeserializeOwned>(&self, contract_addr: impl Into<String>, msg: &impl Serialize) -> StdResult<T> {
+        self.app.borrow().wrap().query_wasm_smart(contract_addr, msg)
+    }
 
-        let contract_addr = res.data.address;
+    /// Returns every denom created through the [`crate::multi_test::modules::TokenFactory`]
+    /// module during the test, in ascending order. Errors if the stored denoms can't be read.
+    pub fn all_factory_denoms(&self) -> Result<Vec<String>, anyhow::Error> {
+        crate::multi_test::modules::created_denoms(self.app.borrow().storage())
+    }
 
-        let wasm = Wasm::new(&app);
+    /// Returns the number of accounts known to the runner that hold a nonzero balance of `denom`.
+    /// Known accounts are the ones created through [`CwItRunner::init_account`] or that signed a
+    /// transaction, and the contracts deployed with [`MultiTestRunner::deploy_named`]. Other
+    /// holders aren't counted, as multi-test has no query listing all holders of a denom.
+    pub fn holder_count(&self, denom: &str) -> usize {
+        let mut addresses = self.accounts.borrow().keys().cloned().collect::<BTreeSet<_>>();
+        addresses.extend(self.named_contracts.borrow().values().cloned());
 
-        let _code_id = upload_wasm_file(
-            &app,
-            &alice,
-            ContractType::MultiTestContract(Box::new(ContractWrapper::new(
-                cw20_base::contract::execute,
-                cw20_base::contract::instantiate,
-                cw20_base::contract::query,
-            ))),
-        )
-        .unwrap();
+        let app = self.app.borrow();
+        addresses
+            .iter()
+            .filter(|address| {
+                app.wrap()
+                    .query_balance(address.as_str(), denom)
+                    .map(|balance| !balance.amount.is_zero())
+                    .unwrap_or(false)
+            })
+            .count()
+    }
 
-        let res = wasm
-            .query::<_, cw20::BalanceResponse>(&contract_addr, &cw20_base::msg::QueryMsg::Balance { address: alice.address() })
+    /// Enables or disables sends of `denom`, as the `SendEnabled` params of the bank module do.
+    /// Disabled sends are rejected for `MsgSend`s signed by accounts and for transfers of the
+    /// `IbcTransfer` module, but not for bank messages dispatched by contracts, as those are handled
+    /// by the multi-test bank module directly.
+    pub fn set_send_enabled(&self, denom: &str, enabled: bool) {
+        self.app
+            .borrow_mut()
+            .init_modules(|_, _, storage| SEND_ENABLED.save(storage, denom, &enabled))
             .unwrap();
-
-        assert_eq!(res.balance, Uint128::zero());
     }
 
-    #[test]
-    fn wasm_contract_info_query() {
-        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
-
-        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+    /// Runs the `sudo` entry point of the stargate module with the current block, e.g. to deliver
+    /// the pending packets of the `IbcTransfer` module or to tally the proposals of the
+    /// [`Gov`](crate::multi_test::modules::Gov) module. Multi-test has no sudo message for the
+    /// stargate module, so it can't be reached through `App::sudo`.
+    pub fn sudo_stargate(&self) -> Result<AppResponse, anyhow::Error> {
+        let mut app = self.app.borrow_mut();
+        let block = app.block_info();
+        app.init_modules(|router, api, storage| {
+            let router: &_ = router;
+            router.stargate.sudo(api, storage, router, &block, Empty {})
+        })
+    }
 
-        let res = instantiate_astro_token(&app, &alice).unwrap();
-        let contract_addr = res.data.address;
+    /// Returns the current block info of the app, i.e. the height, time and chain id contracts see
+    /// in their `env.block`.
+    pub fn block_info(&self) -> BlockInfo {
+        self.app.borrow().block_info()
+    }
 
-        let res = QueryContractInfoRequest {
-            address: contract_addr.clone(),
+    /// Sets the block height of the app to `height`, leaving the block time untouched. Errors if
+    /// `height` is lower than the current block height.
+    pub fn set_block_height(&self, height: u64) -> Result<(), anyhow::Error> {
+        let mut app = self.app.borrow_mut();
+        let mut block = app.block_info();
+        if height < block.height {
+            bail!(
+                "Cannot set block height to {}, it is before the current block height {}",
+                height,
+                block.height
+            );
         }
-        .query(&app.app.borrow().wrap())
-        .unwrap();
+        block.height = height;
+        app.set_block(block);
+        drop(app);
 
-        assert_eq!(res.address, contract_addr);
-        let info = res.contract_info.unwrap();
-        assert_eq!(info.code_id, 1);
-        assert_eq!(info.creator, alice.address());
-        assert_eq!(info.label, "");
+        self.advance_block()
+    }
+
+    /// Returns the code ids of all contracts stored so far, in order of storage.
+    pub fn stored_code_ids(&self) -> Vec<u64> {
+        self.stored_codes.borrow().iter().map(|(code_id, _)| *code_id).collect()
+    }
+
+    /// Stores `code`, instantiates it with `init_msg` and registers the contract's address under
+    /// `name`, so it can later be looked up with [`MultiTestRunner::address_of`]. The contract is
+    /// labelled `name` and `signer` is set as its admin. Returns the address of the contract.
+    pub fn deploy_named(
+        &self,
+        name: &str,
+        code: ContractType,
+        init_msg: &impl Serialize,
+        signer: &SigningAccount,
+    ) -> Result<String, anyhow::Error> {
+        if self.named_contracts.borrow().contains_key(name) {
+            bail!("A contract named {} is already deployed", name);
+        }
+
+        let code_id = self.store_code(code, signer)?;
+        let address = self.instantiate_contract(code_id, init_msg, &[], Some(&signer.address()), name, signer)?;
+
+        self.named_contracts.borrow_mut().insert(name.to_string(), address.clone());
+        Ok(address)
+    }
+
+    /// Sets an approximate gas limit per block. Every executed message is charged a rough estimate
+    /// of gas based on its encoded size, and executions that would push the gas used in the current
+    /// block over `limit` fail with an out of gas error. This is a heuristic to catch obviously gas
+    /// heavy flows, not real gas metering. Only the messages passed to the runner are charged, not
+    /// the sub-messages they trigger.
+    pub fn with_block_gas_limit(mut self, limit: u64) -> Self {
+        self.block_gas_limit = Some(limit);
+        self
+    }
+
+    /// Enables recording the gas used by every successful execution, which is then returned by
+    /// [`MultiTestRunner::last_gas_used`] and set as the `gas_used` of the execute responses.
+    ///
+    /// Multi-test does not meter gas, so this is only an approximation: every message is charged
+    /// based on its encoded size, and every emitted event is charged a fixed amount to account for
+    /// the work of contracts and their sub-messages. The figures are useful to compare executions
+    /// with each other, but differ considerably from the gas metered on chain.
+    pub fn with_gas_tracking(mut self) -> Self {
+        self.gas_tracking = true;
+        self
+    }
+
+    /// Sets the denom reported by [`CwItRunner::fee_denom`], which is the default denom of the
+    /// chain if not set. Fees are only charged in this denom if enabled with
+    /// [`MultiTestRunner::with_tx_fee`], otherwise this only configures the denom tests that are
+    /// generic over the runner should expect fees in.
+    pub fn with_fee_denom(mut self, denom: &str) -> Self {
+        self.fee_denom = denom.to_string();
+        self
+    }
+
+    /// Enables charging a flat fee of `amount` in the [`CwItRunner::fee_denom`] for every
+    /// transaction. The fee is burned from the signer before the messages are executed and is not
+    /// refunded if the execution fails. Transactions of signers that cannot afford the fee are
+    /// rejected with an insufficient fee error before anything is executed, and without
+    /// incrementing the sequence of the signer.
+    pub fn with_tx_fee(mut self, amount: u128) -> Self {
+        self.tx_fee = Some(amount);
+        self
+    }
+
+    /// Passes the messages of `type_urls` signed by accounts to the stargate module as they are,
+    /// rather than converting them into native messages. E.g. `MsgDelegate` and `MsgUndelegate`
+    /// are executed by the multi-test staking keeper unless they are passed to the
+    /// [`Staking`](crate::multi_test::modules::Staking) module this way.
+    pub fn with_stargate_msgs(mut self, type_urls: &[&str]) -> Self {
+        self.stargate_msgs.extend(type_urls.iter().map(|type_url| type_url.to_string()));
+        self
+    }
+
+    /// Runs the `sudo` entry point of the stargate module every time the block time or height is
+    /// advanced through the runner, e.g. so that the
+    /// [`Staking`](crate::multi_test::modules::Staking) module pays out matured unbondings.
+    pub fn with_sudo_on_block_advance(mut self) -> Self {
+        self.sudo_on_block_advance = true;
+        self
+    }
+
+    /// Sets the chain id of the block contracts see in their `env.block`, which is also returned by
+    /// [`CwItRunner::chain_id`].
+    pub fn with_chain_id(self, chain_id: &str) -> Self {
+        self.app.borrow_mut().update_block(|block| block.chain_id = chain_id.to_string());
+        self
+    }
+
+    /// Returns the approximate gas used by the last successful execution, or `None` if gas tracking
+    /// is not enabled with [`MultiTestRunner::with_gas_tracking`] or nothing was executed yet.
+    pub fn last_gas_used(&self) -> Option<u64> {
+        self.last_gas_used.get()
+    }
+
+    /// Executes `msgs` signed by `signer` as a transaction, returning the responses of the app and
+    /// the approximate gas used. Like on a real chain, the block gas limit is checked and the fee
+    /// is deducted before the messages are executed, and the sequence of the signer is incremented
+    /// even if the execution fails. All executions signed by accounts go through here.
+    fn execute_signed(&self, msgs: Vec<CosmosMsg<ExecC>>, signer: &SigningAccount) -> Result<(Vec<AppResponse>, u64), RunnerError> {
+        let gas = self.check_block_gas(&msgs)?;
+        let msgs_gas = if self.gas_tracking { approx_msgs_gas(&msgs)? } else { 0 };
+
+        // Like on a real chain, the fee is deducted before the messages are executed, and the
+        // transaction is rejected without any state change if the signer cannot afford it
+        self.deduct_tx_fee(&signer.address())?;
+
+        // Like on a real chain, the sequence is incremented even if the transaction fails
+        self.register_account(&signer.address())
+            .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+        let info = self
+            .accounts
+            .borrow_mut()
+            .get_mut(&signer.address())
+            .map(|(account_number, sequence)| {
+                *sequence += 1;
+                (*account_number, *sequence)
+            });
+        if let Some(info) = info {
+            self.save_auth_account(&signer.address(), info)
+                .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+        }
+
+        // Execute messages with multi test app
+        let app_responses = self
+            .app
+            .borrow_mut()
+            .execute_multi(Addr::unchecked(signer.address()), msgs)
+            // NB: Must use this syntax to capture full anyhow message.
+            // to_string() will only give the outermost error context.
+            .map_err(|e| RunnerError::GenericError(format!("{:#}", e)))?;
+        self.block_gas_used.borrow_mut().1 += gas;
+
+        let gas_used = if self.gas_tracking {
+            let events = app_responses.iter().map(|r| r.events.len() as u64).sum::<u64>();
+            let gas_used = msgs_gas + APPROX_GAS_PER_EVENT * events;
+            self.last_gas_used.set(Some(gas_used));
+            gas_used
+        } else {
+            0
+        };
+
+        Ok((app_responses, gas_used))
+    }
+
+    /// Returns the fee set with [`MultiTestRunner::with_tx_fee`], erroring if `payer` cannot afford
+    /// it. Returns `None` if no fee is set.
+    fn check_tx_fee(&self, payer: &str) -> Result<Option<Coin>, RunnerError> {
+        let Some(amount) = self.tx_fee.filter(|amount| *amount > 0) else {
+            return Ok(None);
+        };
+        let fee = coin(amount, &self.fee_denom);
+
+        let balance = self
+            .app
+            .borrow()
+            .wrap()
+            .query_balance(payer, &fee.denom)
+            .map_err(|e| RunnerError::GenericError(e.to_string()))?;
+        if balance.amount < fee.amount {
+            return Err(RunnerError::GenericError(format!(
+                "insufficient fee: {} has {}, but the fee is {}",
+                payer, balance, fee
+            )));
+        }
+
+        Ok(Some(fee))
+    }
+
+    /// Burns the fee set with [`MultiTestRunner::with_tx_fee`] from `payer`, erroring without any
+    /// state change if `payer` cannot afford it. Does nothing if no fee is set.
+    fn deduct_tx_fee(&self, payer: &str) -> Result<(), RunnerError> {
+        let Some(fee) = self.check_tx_fee(payer)? else {
+            return Ok(());
+        };
+        self.app
+            .borrow_mut()
+            .execute(Addr::unchecked(payer), CosmosMsg::Bank(BankMsg::Burn { amount: vec![fee] }))
+            .map_err(|e| RunnerError::GenericError(format!("{:#}", e)))?;
+
+        Ok(())
+    }
+
+    /// Returns the approximate gas `msgs` use, erroring if it exceeds the remaining gas of the
+    /// current block. Always returns zero if no block gas limit is set.
+    fn check_block_gas(&self, msgs: &[CosmosMsg<ExecC>]) -> Result<u64, RunnerError> {
+        let Some(limit) = self.block_gas_limit else {
+            return Ok(0);
+        };
+
+        let gas = approx_msgs_gas(msgs)?;
+
+        let height = self.app.borrow().block_info().height;
+        let mut block_gas_used = self.block_gas_used.borrow_mut();
+        if block_gas_used.0 != height {
+            *block_gas_used = (height, 0);
+        }
+        if block_gas_used.1 + gas > limit {
+            return Err(RunnerError::GenericError(format!(
+                "out of gas: messages need approximately {} gas, but only {} of the block gas limit of {} is left",
+                gas,
+                limit - block_gas_used.1,
+                limit
+            )));
+        }
+
+        Ok(gas)
+    }
+
+    /// Returns the **account number, sequence** of `address`, assigning it the next account number
+    /// and storing it in [`AUTH_ACCOUNTS`] if the account is not known yet.
+    fn register_account(&self, address: &str) -> Result<(u64, u64), anyhow::Error> {
+        if let Some(info) = self.accounts.borrow().get(address) {
+            return Ok(*info);
+        }
+
+        let info = (self.accounts.borrow().len() as u64, 0);
+        self.accounts.borrow_mut().insert(address.to_string(), info);
+        self.save_auth_account(address, info)?;
+        Ok(info)
+    }
+
+    /// Stores the **account number, sequence** of `address` in [`AUTH_ACCOUNTS`], so auth account
+    /// queries of contracts see the same values as [`CwItRunner::account_info`].
+    fn save_auth_account(&self, address: &str, info: (u64, u64)) -> Result<(), anyhow::Error> {
+        self.app
+            .borrow_mut()
+            .init_modules(|_, _, storage| AUTH_ACCOUNTS.save(storage, address, &info))?;
+        Ok(())
+    }
+
+    /// Runs the `sudo` entry point of the stargate module after the block time or height was
+    /// advanced, if enabled with [`MultiTestRunner::with_sudo_on_block_advance`].
+    fn advance_block(&self) -> Result<(), anyhow::Error> {
+        if self.sudo_on_block_advance {
+            self.sudo_stargate()?;
+        }
+        Ok(())
+    }
+
+    /// Converts proto encoded `msgs` into the [`CosmosMsg`]s they are executed as by the app.
+    fn any_to_cosmos_msgs(&self, msgs: &[cosmrs::Any]) -> Result<Vec<CosmosMsg<ExecC>>, RunnerError> {
+        msgs.iter()
+            .map(|msg| -> Result<Vec<CosmosMsg<ExecC>>, RunnerError> {
+                let converted = match msg.type_url.as_str() {
+                    // Messages passed to the stargate module as they are, see `with_stargate_msgs`
+                    type_url if self.stargate_msgs.contains(type_url) => Ok(CosmosMsg::<ExecC>::Stargate {
+                        type_url: msg.type_url.clone(),
+                        value: msg.value.clone().into(),
+                    }),
+                    // WasmMsg
+                    MsgExecuteContract::TYPE_URL => {
+                        let msg = MsgExecuteContract::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::Execute {
+                            contract_addr: msg.contract,
+                            msg: Binary(msg.msg),
+                            funds: msg
+                                .funds
+                                .into_iter()
+                                .map(|c| coin(u128::from_str(&c.amount).unwrap(), c.denom))
+                                .collect(),
+                        }))
+                    }
+                    MsgInstantiateContract::TYPE_URL => {
+                        let msg = MsgInstantiateContract::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::Instantiate {
+                            code_id: msg.code_id,
+                            admin: Some(msg.admin),
+                            msg: Binary(msg.msg),
+                            funds: msg
+                                .funds
+                                .into_iter()
+                                .map(|c| coin(u128::from_str(&c.amount).unwrap(), c.denom))
+                                .collect(),
+                            label: msg.label,
+                        }))
+                    }
+                    MsgMigrateContract::TYPE_URL => {
+                        let msg = MsgMigrateContract::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::Migrate {
+                            contract_addr: msg.contract,
+                            new_code_id: msg.code_id,
+                            msg: Binary(msg.msg),
+                        }))
+                    }
+                    MsgUpdateAdmin::TYPE_URL => {
+                        let msg = MsgUpdateAdmin::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::UpdateAdmin {
+                            contract_addr: msg.contract,
+                            admin: msg.new_admin,
+                        }))
+                    }
+                    MsgClearAdmin::TYPE_URL => {
+                        let msg = MsgClearAdmin::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        Ok(CosmosMsg::<ExecC>::Wasm(WasmMsg::ClearAdmin {
+                            contract_addr: msg.contract,
+                        }))
+                    }
+                    // BankMsg
+                    MsgSend::TYPE_URL => {
+                        let msg = MsgSend::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        let app = self.app.borrow();
+                        return bank_send_msgs(&app.wrap(), app.storage(), msg);
+                    }
+                    // StakingMsg
+                    MsgDelegate::TYPE_URL => {
+                        let msg = MsgDelegate::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        let proto_coin = msg.amount.unwrap_or_default();
+                        Ok(CosmosMsg::<ExecC>::Staking(StakingMsg::Delegate {
+                            validator: msg.validator_address,
+                            amount: coin(u128::from_str(&proto_coin.amount).unwrap(), proto_coin.denom),
+                        }))
+                    }
+                    MsgUndelegate::TYPE_URL => {
+                        let msg = MsgUndelegate::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        let proto_coin = msg.amount.unwrap_or_default();
+                        Ok(CosmosMsg::<ExecC>::Staking(StakingMsg::Undelegate {
+                            validator: msg.validator_address,
+                            amount: coin(u128::from_str(&proto_coin.amount).unwrap(), proto_coin.denom),
+                        }))
+                    }
+                    MsgBeginRedelegate::TYPE_URL => {
+                        let msg = MsgBeginRedelegate::decode(msg.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+                        let proto_coin = msg.amount.unwrap_or_default();
+                        Ok(CosmosMsg::<ExecC>::Staking(StakingMsg::Redelegate {
+                            src_validator: msg.validator_src_address,
+                            dst_validator: msg.validator_dst_address,
+                            amount: coin(u128::from_str(&proto_coin.amount).unwrap(), proto_coin.denom),
+                        }))
+                    }
+                    _ => {
+                        // Else assume StargateMsg
+                        Ok(CosmosMsg::<ExecC>::Stargate {
+                            type_url: msg.type_url.clone(),
+                            value: msg.value.clone().into(),
+                        })
+                    }
+                };
+                converted.map(|msg| vec![msg])
+            })
+            .collect::<Result<Vec<Vec<CosmosMsg<ExecC>>>, RunnerError>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+    }
+
+    /// Returns the address of the contract deployed under `name` with [`MultiTestRunner::deploy_named`].
+    pub fn address_of(&self, name: &str) -> Option<String> {
+        self.named_contracts.borrow().get(name).cloned()
+    }
+
+    /// Takes a snapshot of the current state of the app, i.e. its storage and block info, along with
+    /// the named contracts, account sequences and gas used of the runner, which can later be
+    /// reapplied with [`MultiTestRunner::restore`].
+    ///
+    /// Stored code is kept across restores, as multi-test keeps it outside of the app storage and
+    /// can't remove it again. Code stored after the snapshot keeps its code id and can still be
+    /// instantiated after a restore, so [`MultiTestRunner::stored_code_ids`] doesn't change either.
+    pub fn snapshot(&self) -> Snapshot {
+        let app = self.app.borrow();
+        Snapshot {
+            storage: app.storage().range(None, None, Order::Ascending).collect(),
+            block: app.block_info(),
+            named_contracts: self.named_contracts.borrow().clone(),
+            accounts: self.accounts.borrow().clone(),
+            block_gas_used: *self.block_gas_used.borrow(),
+            last_gas_used: self.last_gas_used.get(),
+        }
+    }
+
+    /// Returns an independent copy of the runner, so that a fixture can be set up once and several
+    /// scenarios branch off from it. The fork gets a copy of the app storage, which holds the
+    /// balances, contracts and the state of all modules, and of the block info. The contracts
+    /// stored through [`CwItRunner::store_code`] are stored in the fork under the same code ids,
+    /// and the fork gets a clone of the stargate module of the runner. The named contracts,
+    /// account sequences and gas settings of the runner are copied as well.
+    ///
+    /// Errors if code was stored directly on [`MultiTestRunner::app`], as that code can not be
+    /// copied.
+    pub fn fork(&self) -> Result<Self, anyhow::Error>
+    where
+        StargateT: Clone,
+    {
+        let original = self.app.borrow();
+        let prefix = original.api().prefix();
+        let stargate = &original.router().stargate;
+        let stargate = UnifiedStargate {
+            extra: stargate.extra.clone(),
+            strict: stargate.strict,
+        };
+
+        #[cfg(not(feature = "coreum"))]
+        let mut app = BasicAppBuilder::<ExecC, QueryC>::new()
+            .with_api(MockApiBech32::new(prefix))
+            .with_wasm(wasm_keeper(prefix))
+            .with_stargate(stargate)
+            .build(|_, _, _| {});
+        #[cfg(feature = "coreum")]
+        let mut app = BasicAppBuilder::<ExecC, QueryC>::new_custom()
+            .with_api(MockApiBech32::new(prefix))
+            .with_wasm(wasm_keeper(prefix))
+            .with_stargate(stargate)
+            .with_custom(CustomModule::default())
+            .build(|_, _, _| {});
+
+        for (code_id, contract) in self.stored_codes.borrow().iter() {
+            let forked_code_id = app.store_code(Box::new(SharedContract(contract.clone())));
+            if forked_code_id != *code_id {
+                bail!("Code stored directly on the app can not be forked, store it through CwItRunner::store_code");
+            }
+        }
+
+        let storage = app.storage_mut();
+        for (key, value) in original.storage().range(None, None, Order::Ascending) {
+            storage.set(&key, &value);
+        }
+        app.set_block(original.block_info());
+
+        Ok(Self {
+            app: app.into(),
+            address_prefix: self.address_prefix.clone(),
+            stored_codes: RefCell::new(self.stored_codes.borrow().clone()),
+            named_contracts: RefCell::new(self.named_contracts.borrow().clone()),
+            block_gas_limit: self.block_gas_limit,
+            block_gas_used: RefCell::new(*self.block_gas_used.borrow()),
+            accounts: RefCell::new(self.accounts.borrow().clone()),
+            gas_tracking: self.gas_tracking,
+            last_gas_used: Cell::new(None),
+            fee_denom: self.fee_denom.clone(),
+            tx_fee: self.tx_fee,
+            stargate_msgs: self.stargate_msgs.clone(),
+            sudo_on_block_advance: self.sudo_on_block_advance,
+        })
+    }
+
+    /// Resets the state of the app to the given `snapshot`, discarding all changes made since it
+    /// was taken.
+    pub fn restore(&self, snapshot: &Snapshot) {
+        let mut app = self.app.borrow_mut();
+
+        let storage = app.storage_mut();
+        let keys = storage.range(None, None, Order::Ascending).map(|(key, _)| key).collect::<Vec<_>>();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in &snapshot.storage {
+            storage.set(key, value);
+        }
+
+        app.set_block(snapshot.block.clone());
+        *self.named_contracts.borrow_mut() = snapshot.named_contracts.clone();
+        *self.accounts.borrow_mut() = snapshot.accounts.clone();
+        *self.block_gas_used.borrow_mut() = snapshot.block_gas_used;
+        self.last_gas_used.set(snapshot.last_gas_used);
+    }
+
+    /// Executes `msg` like [`Runner::execute`], but returns a [`DebugReport`] with the emitted
+    /// events, the storage changes and the full error chain of a failed execution instead of the
+    /// response. Printing the report helps to understand cryptic failures without adding logging
+    /// to the test. The block gas limit is not applied to debug executions.
+    pub fn debug_execute<M>(&self, msg: M, type_url: &str, signer: &SigningAccount) -> DebugReport
+    where
+        M: prost::Message + std::fmt::Debug,
+    {
+        let mut report = DebugReport {
+            type_url: type_url.to_string(),
+            msg: format!("{:#?}", msg),
+            sender: signer.address(),
+            ..Default::default()
+        };
+
+        let any = cosmrs::Any {
+            type_url: type_url.to_string(),
+            value: msg.encode_to_vec(),
+        };
+        let msgs = match self.any_to_cosmos_msgs(&[any]) {
+            Ok(msgs) => msgs,
+            Err(e) => {
+                report.error_chain = vec![e.to_string()];
+                return report;
+            }
+        };
+
+        let storage = || {
+            self.app
+                .borrow()
+                .storage()
+                .range(None, None, Order::Ascending)
+                .collect::<BTreeMap<_, _>>()
+        };
+        let before = storage();
+        let res = self.app.borrow_mut().execute_multi(Addr::unchecked(signer.address()), msgs);
+        match res {
+            Ok(responses) => {
+                report.events = responses.into_iter().flat_map(|r| r.events).collect();
+                report.state_diff = diff_storage(&before, &storage());
+            }
+            Err(e) => report.error_chain = e.chain().map(|cause| cause.to_string()).collect(),
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "coreum"))]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::MsgSendResponse;
+    use cosmwasm_std::{coin, Event, Uint128};
+
+    use crate::test_helpers::*;
+    use crate::traits::{DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+    use crate::{
+        artifact::Artifact,
+        helpers::{bank_balance_query, upload_wasm_file},
+    };
+    use cw_multi_test::ContractWrapper;
+
+    use cw20::MinterResponse;
+    use osmosis_std::types::cosmos::bank::v1beta1::{
+        QueryBalanceRequest, QuerySupplyOfRequest as OsmosisQuerySupplyOfRequest, QueryTotalSupplyRequest,
+    };
+    use osmosis_std::types::cosmwasm::wasm::v1::QueryContractInfoRequest;
+    use osmosis_std::types::{cosmos::bank::v1beta1::QueryAllBalancesRequest, cosmwasm::wasm::v1::MsgInstantiateContractResponse};
+    use test_tube::{Bank, Module, RunnerExecuteResult, Wasm};
+
+    use super::*;
+
+    fn instantiate_astro_token(app: &MultiTestRunner, signer: &SigningAccount) -> RunnerExecuteResult<MsgInstantiateContractResponse> {
+        let code_id = upload_wasm_file(
+            app,
+            signer,
+            ContractType::MultiTestContract(Box::new(ContractWrapper::new(
+                cw20_base::contract::execute,
+                cw20_base::contract::instantiate,
+                cw20_base::contract::query,
+            ))),
+        )
+        .unwrap();
+
+        let init_msg = cw20_base::msg::InstantiateMsg {
+            name: "Astro Token".to_string(),
+            symbol: "ASTRO".to_string(),
+            decimals: 6,
+            initial_balances: vec![],
+            mint: Some(MinterResponse {
+                minter: signer.address(),
+                cap: None,
+            }),
+            marketing: None,
+        };
+
+        let wasm = Wasm::new(app);
+        wasm.instantiate(code_id, &init_msg, None, Some("counter"), &[], signer)
+    }
+
+    #[test]
+    fn upload_contract() {
+        let contract = ContractType::MultiTestContract(test_contract::contract());
+
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let code_id = app.store_code(contract, &alice).unwrap();
+
+        assert_eq!(code_id, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    // This test should panic because we are trying to upload a wasm contract to a MultiTestRunner
+    // which does not support wasm contracts.
+    fn upload_wasm_artifact() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let _code_id = upload_wasm_file(
+            &app,
+            &alice,
+            ContractType::Artifact(Artifact::Local(counter::WASM_PATH.to_string())),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    // This test should panic because we are trying to upload a wasm contract to a MultiTestRunner
+    // which does not support wasm contracts.
+    fn wasm_instantiate_contract() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        // Instantiate with test_tube::Wasm
+        let res = instantiate_astro_token(&app, &alice).unwrap();
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(res.events[0].ty, "instantiate".to_string());
+    }
+
+    #[test]
+    fn wasm_execute_contract() {
+        // start the keeper
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let res = instantiate_astro_token(&app, &alice).unwrap();
+
+        let contract_addr = res.data.address;
+
+        let wasm = Wasm::new(&app);
+        let res = wasm
+            .execute(
+                &contract_addr,
+                &cw20_base::msg::ExecuteMsg::Mint {
+                    recipient: alice.address(),
+                    amount: 100u128.into(),
+                },
+                &[],
+                &alice,
+            )
+            .unwrap();
+        assert_eq!(res.events.len(), 2);
+
+        let wasm_event = res.events.iter().find(|e| e.ty == "wasm").unwrap();
+        assert_eq!(
+            wasm_event,
+            &Event::new("wasm")
+                .add_attribute("_contract_address", contract_addr)
+                .add_attribute("action", "mint")
+                .add_attribute("to", alice.address())
+                .add_attribute("amount", "100")
+        );
+    }
+
+    #[test]
+    fn wasm_smart_query_contract() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let res = instantiate_astro_token(&app, &alice).unwrap();
+
+        let contract_addr = res.data.address;
+
+        let wasm = Wasm::new(&app);
+
+        let _code_id = upload_wasm_file(
+            &app,
+            &alice,
+            ContractType::MultiTestContract(Box::new(ContractWrapper::new(
+                cw20_base::contract::execute,
+                cw20_base::contract::instantiate,
+                cw20_base::contract::query,
+            ))),
+        )
+        .unwrap();
+
+        let res = wasm
+            .query::<_, cw20::BalanceResponse>(&contract_addr, &cw20_base::msg::QueryMsg::Balance { address: alice.address() })
+            .unwrap();
+
+        assert_eq!(res.balance, Uint128::zero());
+    }
+
+    #[test]
+    fn wasm_contract_info_query() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let res = instantiate_astro_token(&app, &alice).unwrap();
+        let contract_addr = res.data.address;
+
+        let res = QueryContractInfoRequest {
+            address: contract_addr.clone(),
+        }
+        .query(&app.app.borrow().wrap())
+        .unwrap();
+
+        assert_eq!(res.address, contract_addr);
+        let info = res.contract_info.unwrap();
+        assert_eq!(info.code_id, 1);
+        assert_eq!(info.creator, alice.address());
+        assert_eq!(info.label, "");
+    }
+
+    #[test]
+    fn bank_send() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        let bob = app.init_account(&[]).unwrap();
+
+        let msgs = vec![cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+            to_address: bob.address(),
+            amount: vec![cosmwasm_std::Coin {
+                denom: "uatom".to_string(),
+                amount: 100u128.into(),
+            }],
+        })];
+
+        let res = app.execute_cosmos_msgs::<MsgSendResponse>(&msgs, &alice).unwrap();
+
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(
+            res.events[0],
+            Event::new("transfer")
+                .add_attribute("recipient", bob.address())
+                .add_attribute("sender", alice.address())
+                .add_attribute("amount", "100uatom")
+        );
+
+        let bank = Bank::new(&app);
+        let res = bank
+            .send(
+                MsgSend {
+                    from_address: alice.address(),
+                    to_address: bob.address(),
+                    amount: vec![coin(100, "uatom").into()],
+                },
+                &alice,
+            )
+            .unwrap();
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(
+            res.events[0],
+            Event::new("transfer")
+                .add_attribute("recipient", bob.address())
+                .add_attribute("sender", alice.address())
+                .add_attribute("amount", "100uatom")
+        );
+    }
+
+    #[test]
+    fn send_enabled() {
+        use osmosis_std::types::cosmos::bank::v1beta1::{QuerySendEnabledRequest, QuerySendEnabledResponse, SendEnabled};
+
+        use crate::multi_test::modules::QUERY_SEND_ENABLED_PATH;
+
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom"), coin(1000, "uosmo")]).unwrap();
+        let bob = app.init_account(&[]).unwrap();
+
+        app.set_send_enabled("uatom", false);
+
+        let query = |denoms: Vec<String>| -> Vec<SendEnabled> {
+            app.query::<_, QuerySendEnabledResponse>(QUERY_SEND_ENABLED_PATH, &QuerySendEnabledRequest { denoms, pagination: None })
+                .unwrap()
+                .send_enabled
+        };
+        let disabled = SendEnabled {
+            denom: "uatom".to_string(),
+            enabled: false,
+        };
+        assert_eq!(query(vec![]), vec![disabled.clone()]);
+        assert_eq!(query(vec!["uatom".to_string(), "uosmo".to_string()]), vec![disabled]);
+
+        let send = |denom: &str| {
+            Bank::new(&app).send(
+                MsgSend {
+                    from_address: alice.address(),
+                    to_address: bob.address(),
+                    amount: vec![coin(100, denom).into()],
+                },
+                &alice,
+            )
+        };
+        let err = send("uatom").unwrap_err();
+        assert!(err.to_string().contains("uatom transfers are currently disabled"), "{}", err);
+        send("uosmo").unwrap();
+
+        // Sends are possible again once re-enabled
+        app.set_send_enabled("uatom", true);
+        send("uatom").unwrap();
+        assert_eq!(
+            bank_balance_query(&app, bob.address(), "uatom".to_string()).unwrap(),
+            Uint128::new(100)
+        );
+    }
+
+    #[test]
+    fn bank_send_multiple_coins() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom"), coin(50, "uosmo")]).unwrap();
+        let bob = app.init_account(&[]).unwrap();
+        let bank = Bank::new(&app);
+
+        // Sending both coins works when the sender has enough of each
+        bank.send(
+            MsgSend {
+                from_address: alice.address(),
+                to_address: bob.address(),
+                amount: vec![coin(100, "uatom").into(), coin(50, "uosmo").into()],
+            },
+            &alice,
+        )
+        .unwrap();
+        assert_eq!(
+            bank_balance_query(&app, bob.address(), "uatom".to_string()).unwrap(),
+            Uint128::new(100)
+        );
+        assert_eq!(
+            bank_balance_query(&app, bob.address(), "uosmo".to_string()).unwrap(),
+            Uint128::new(50)
+        );
+
+        // Alice has no uosmo left, so sending it together with uatom fails cleanly
+        let err = bank
+            .send(
+                MsgSend {
+                    from_address: alice.address(),
+                    to_address: bob.address(),
+                    amount: vec![coin(100, "uatom").into(), coin(1, "uosmo").into()],
+                },
+                &alice,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            RunnerError::GenericError(format!("insufficient funds: {} has 0uosmo, but needs 1uosmo", alice.address())).to_string()
+        );
+
+        // No funds were moved by the failed send
+        assert_eq!(
+            bank_balance_query(&app, alice.address(), "uatom".to_string()).unwrap(),
+            Uint128::new(900)
+        );
+        assert_eq!(
+            bank_balance_query(&app, bob.address(), "uatom".to_string()).unwrap(),
+            Uint128::new(100)
+        );
+    }
+
+    #[test]
+    fn bank_queries() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+
+        let bank = Bank::new(&app);
+
+        // Query all balances
+        let res = bank
+            .query_all_balances(&QueryAllBalancesRequest {
+                address: alice.address(),
+                pagination: None,
+            })
+            .unwrap();
+        assert_eq!(res.balances.len(), 1);
+        assert_eq!(res.balances[0].denom, "uatom".to_string());
+        assert_eq!(res.balances[0].amount, "1000");
+
+        // Query balance
+        let res = bank
+            .query_balance(&QueryBalanceRequest {
+                address: alice.address(),
+                denom: "uatom".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap();
+        assert_eq!(res.denom, "uatom".to_string());
+        assert_eq!(res.amount, "1000");
+
+        // Query total supply
+        let res = bank.query_total_supply(&QueryTotalSupplyRequest { pagination: None }).unwrap();
+        assert_eq!(res.supply.len(), 1);
+        assert_eq!(res.supply[0].denom, "uatom".to_string());
+        assert_eq!(res.supply[0].amount, "1000");
+
+        // Query supply of
+        let supply = OsmosisQuerySupplyOfRequest {
+            denom: "uatom".to_string(),
+        }
+        .query(&app.app.borrow().wrap())
+        .unwrap()
+        .amount
+        .unwrap();
+        assert_eq!(supply.denom, "uatom".to_string());
+        assert_eq!(supply.amount, "1000");
+    }
+
+    #[test]
+    fn query_bank_through_test_tube_bank_module() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+
+        let bank = Bank::new(&app);
+
+        let res = bank
+            .query_all_balances(&QueryAllBalancesRequest {
+                address: alice.address(),
+                pagination: None,
+            })
+            .unwrap();
+
+        assert_eq!(res.balances.len(), 1);
+        assert_eq!(res.balances[0].denom, "uatom".to_string());
+        assert_eq!(res.balances[0].amount, "1000");
+    }
+
+    #[test]
+    fn test_increase_time() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+
+        let time = app.app.borrow().block_info().time;
+        app.increase_time(69).unwrap();
+        assert_eq!(app.app.borrow().block_info().time.seconds(), time.seconds() + 69);
+    }
+
+    #[test]
+    fn test_query_block_height() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+
+        let height = app.query_block_height().unwrap();
+        app.increase_time(69).unwrap();
+        assert_eq!(app.query_block_height().unwrap(), height + 1);
+    }
+
+    #[test]
+    fn block_info() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let before = app.block_info();
+
+        app.increase_time(69).unwrap();
+        app.increase_time(1).unwrap();
+
+        assert_eq!(
+            app.block_info(),
+            BlockInfo {
+                height: before.height + 2,
+                time: before.time.plus_seconds(70),
+                chain_id: before.chain_id.clone(),
+            }
+        );
+        assert_eq!(app.block_info().height, app.query_block_height().unwrap());
+        assert_eq!(app.block_info().time.nanos(), app.query_block_time_nanos());
+    }
+
+    #[test]
+    fn query_code_hash() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let code_id_1 = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+            .unwrap();
+        let code_id_2 = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+            .unwrap();
+
+        let hash_1 = app.query_code_hash(code_id_1).unwrap();
+        let hash_2 = app.query_code_hash(code_id_2).unwrap();
+        assert_eq!(hash_1.len(), 32);
+        assert_ne!(hash_1, hash_2);
+        assert_eq!(
+            hash_1,
+            app.app.borrow().wrap().query_wasm_code_info(code_id_1).unwrap().checksum.to_vec()
+        );
+        assert!(app.query_code_hash(42).is_err());
+    }
+
+    #[test]
+    fn migrate_contract() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let v1 = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+            .unwrap();
+        let contract_addr = Wasm::new(&app)
+            .instantiate(
+                v1,
+                &counter::InstantiateMsg { count: 1 },
+                Some(&alice.address()),
+                Some("counter"),
+                &[],
+                &alice,
+            )
+            .unwrap()
+            .data
+            .address;
+        let v2 = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+            .unwrap();
+
+        let msg = to_json_binary(&counter::MigrateMsg { count: Some(42) }).unwrap();
+        let res = app.migrate_contract(&contract_addr, v2, msg.as_slice(), &alice).unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "migrate" && e.attributes.iter().any(|a| a.key == "code_id" && a.value == v2.to_string())));
+
+        let res: counter::GetCountResponse = app.query_wasm_smart(&contract_addr, &counter::QueryMsg::GetCount {}).unwrap();
+        assert_eq!(res.count, 42);
+    }
+
+    #[test]
+    fn all_factory_denoms() {
+        use crate::multi_test::modules::TokenFactory;
+        use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse};
+
+        let app = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+        let alice = app.init_account(&[coin(1_000_000_000, DEFAULT_COIN_DENOM)]).unwrap();
+        assert!(app.all_factory_denoms().unwrap().is_empty());
+
+        for subdenom in ["denom1", "denom2"] {
+            app.execute::<_, MsgCreateDenomResponse>(
+                MsgCreateDenom {
+                    sender: alice.address(),
+                    subdenom: subdenom.to_string(),
+                },
+                MsgCreateDenom::TYPE_URL,
+                &alice,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            app.all_factory_denoms().unwrap(),
+            vec![
+                format!("factory/{}/denom1", alice.address()),
+                format!("factory/{}/denom2", alice.address()),
+            ]
+        );
+    }
+
+    #[test]
+    fn holder_count() {
+        use crate::multi_test::modules::TokenFactory;
+        use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgMintResponse};
+
+        let app = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+        let accounts = app.init_accounts(&[coin(1_000_000_000, DEFAULT_COIN_DENOM)], 4).unwrap();
+        let creator = &accounts[0];
+        let denom = app
+            .execute::<_, MsgCreateDenomResponse>(
+                MsgCreateDenom {
+                    sender: creator.address(),
+                    subdenom: "holders".to_string(),
+                },
+                MsgCreateDenom::TYPE_URL,
+                creator,
+            )
+            .unwrap()
+            .data
+            .new_token_denom;
+        assert_eq!(app.holder_count(&denom), 0);
+
+        for holder in &accounts[1..] {
+            app.execute::<_, MsgMintResponse>(
+                MsgMint {
+                    sender: creator.address(),
+                    amount: Some(coin(100, &denom).into()),
+                    mint_to_address: holder.address(),
+                },
+                MsgMint::TYPE_URL,
+                creator,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(app.holder_count(&denom), 3);
+        assert_eq!(app.holder_count(DEFAULT_COIN_DENOM), 4);
+    }
+
+    #[test]
+    fn set_block_time() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let height = app.query_block_height().unwrap();
+
+        app.set_block_time(1_700_000_000).unwrap();
+        assert_eq!(app.query_block_time_nanos(), 1_700_000_000_000_000_000);
+        assert_eq!(app.query_block_height().unwrap(), height);
+
+        // Moving time backwards is rejected, like on the other runners
+        let err = app.set_block_time(1_600_000_000).unwrap_err();
+        assert!(err.to_string().contains("before the current block time"));
+        assert_eq!(app.query_block_time_nanos(), 1_700_000_000_000_000_000);
+    }
+
+    #[test]
+    fn snapshot_and_restore() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        let block = app.block_info();
+
+        let snapshot = app.snapshot();
+
+        let bob = app.init_account(&[coin(500, "uatom")]).unwrap();
+        app.execute_cosmos_msgs::<MsgSendResponse>(
+            &[CosmosMsg::Bank(BankMsg::Send {
+                to_address: bob.address(),
+                amount: vec![coin(400, "uatom")],
+            })],
+            &alice,
+        )
+        .unwrap();
+        app.increase_time(10).unwrap();
+
+        let balance = |address: &str| app.app.borrow().wrap().query_balance(address, "uatom").unwrap().amount;
+        assert_eq!(balance(&alice.address()).u128(), 600);
+        assert_eq!(balance(&bob.address()).u128(), 900);
+
+        app.restore(&snapshot);
+
+        assert_eq!(balance(&alice.address()).u128(), 1000);
+        assert_eq!(balance(&bob.address()).u128(), 0);
+        assert_eq!(app.block_info(), block);
+    }
+
+    #[test]
+    fn execute_after_restore() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX)
+            .with_gas_tracking()
+            .with_block_gas_limit(120_000);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        let send = |signer: &SigningAccount, to: &SigningAccount| {
+            app.execute_cosmos_msgs::<MsgSendResponse>(
+                &[CosmosMsg::Bank(BankMsg::Send {
+                    to_address: to.address(),
+                    amount: vec![coin(100, "uatom")],
+                })],
+                signer,
+            )
+        };
+
+        let snapshot = app.snapshot();
+        let code_id = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+            .unwrap();
+        let bob = app.init_account(&[]).unwrap();
+        send(&alice, &bob).unwrap();
+        send(&alice, &bob).unwrap();
+        assert!(send(&alice, &bob).unwrap_err().to_string().contains("out of gas"));
+
+        app.restore(&snapshot);
+
+        // The sequences, account numbers and gas used are those of the snapshot again
+        assert_eq!(app.account_info(&alice.address()).unwrap(), (0, 0));
+        assert!(app.account_info(&bob.address()).is_err());
+        assert_eq!(app.last_gas_used(), None);
+        send(&alice, &bob).unwrap();
+        send(&alice, &bob).unwrap();
+        assert_eq!(app.account_info(&alice.address()).unwrap(), (0, 2));
+
+        // Code stored after the snapshot is kept
+        assert_eq!(app.stored_code_ids(), vec![code_id]);
+        app.increase_blocks(1).unwrap();
+        app.instantiate_contract(code_id, &counter::InstantiateMsg { count: 0 }, &[], None, "counter", &alice)
+            .unwrap();
+    }
+
+    #[test]
+    fn fork() {
+        use crate::multi_test::modules::TokenFactory;
+
+        let app = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        let counter = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &alice,
+            )
+            .unwrap();
+
+        let fork = app.fork().unwrap();
+        assert_eq!(fork.address_of("counter"), Some(counter.clone()));
+        assert_eq!(fork.stored_code_ids(), app.stored_code_ids());
+        assert_eq!(fork.block_info(), app.block_info());
+        assert_eq!(fork.denom_creation_fee().unwrap(), app.denom_creation_fee().unwrap());
+
+        // Mutate the fork only
+        Wasm::new(&fork)
+            .execute(&counter, &counter::ExecuteMsg::Increment {}, &[], &alice)
+            .unwrap();
+        let bob = fork.init_account(&[]).unwrap();
+        fork.execute_cosmos_msgs::<MsgSendResponse>(
+            &[CosmosMsg::Bank(BankMsg::Send {
+                to_address: bob.address(),
+                amount: vec![coin(400, "uatom")],
+            })],
+            &alice,
+        )
+        .unwrap();
+        fork.increase_time(10).unwrap();
+        let code_id = fork
+            .store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+            .unwrap();
+
+        let count = |runner: &MultiTestRunner<TokenFactory>| {
+            Wasm::new(runner)
+                .query::<_, counter::GetCountResponse>(&counter, &counter::QueryMsg::GetCount {})
+                .unwrap()
+                .count
+        };
+        let balance = |runner: &MultiTestRunner<TokenFactory>| {
+            crate::helpers::bank_balance_query(runner, alice.address(), "uatom".to_string())
+                .unwrap()
+                .u128()
+        };
+        assert_eq!(count(&fork), 1);
+        assert_eq!(balance(&fork), 600);
+
+        // The original is unaffected
+        assert_eq!(count(&app), 0);
+        assert_eq!(balance(&app), 1000);
+        assert_ne!(fork.block_info(), app.block_info());
+        assert_eq!(
+            app.store_code(ContractType::MultiTestContract(counter::contract()), &alice)
+                .unwrap(),
+            code_id
+        );
+    }
+
+    #[test]
+    fn staking_module() {
+        use crate::multi_test::modules::{Staking, DELEGATIONS};
+        use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+        use osmosis_std::types::cosmos::staking::v1beta1::{MsgDelegateResponse, MsgUndelegateResponse};
+
+        let staking = Staking::default();
+        let validator = staking.validators()[0].clone();
+        let unbonding_period = staking.unbonding_period;
+        let app = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, staking)
+            .with_stargate_msgs(&[MsgDelegate::TYPE_URL, MsgUndelegate::TYPE_URL])
+            .with_sudo_on_block_advance();
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+        let amount = Some(ProtoCoin {
+            denom: DEFAULT_COIN_DENOM.to_string(),
+            amount: "600".to_string(),
+        });
+        let balance = || {
+            bank_balance_query(&app, alice.address(), DEFAULT_COIN_DENOM.to_string())
+                .unwrap()
+                .u128()
+        };
+
+        // The staking messages are passed to the Staking module rather than the staking keeper
+        app.execute::<_, MsgDelegateResponse>(
+            MsgDelegate {
+                delegator_address: alice.address(),
+                validator_address: validator.clone(),
+                amount: amount.clone(),
+            },
+            MsgDelegate::TYPE_URL,
+            &alice,
+        )
+        .unwrap();
+        let delegated = app
+            .app
+            .borrow()
+            .read_module(|_, _, storage| DELEGATIONS.load(storage, (alice.address().as_str(), validator.as_str())))
+            .unwrap();
+        assert_eq!(delegated.u128(), 600);
+        assert_eq!(balance(), 400);
+
+        app.execute::<_, MsgUndelegateResponse>(
+            MsgUndelegate {
+                delegator_address: alice.address(),
+                validator_address: validator,
+                amount,
+            },
+            MsgUndelegate::TYPE_URL,
+            &alice,
+        )
+        .unwrap();
+        app.increase_time(unbonding_period - 1).unwrap();
+        assert_eq!(balance(), 400);
+
+        // The unbonding is paid out as soon as the block reaches its completion time
+        app.increase_time(1).unwrap();
+        assert_eq!(balance(), 1000);
     }
 
     #[test]
-    fn bank_send() {
+    fn fork_with_code_stored_on_app() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_account(&[]).unwrap();
+        app.app.borrow_mut().store_code(counter::contract());
+        app.store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+            .unwrap();
+
+        let err = app.fork().err().unwrap();
+        assert!(err.to_string().contains("can not be forked"));
+    }
+
+    #[test]
+    fn increase_blocks() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let height = app.query_block_height().unwrap();
+        let time = app.query_block_time_nanos();
+
+        app.increase_blocks(100).unwrap();
+        assert_eq!(app.query_block_height().unwrap(), height + 100);
+        assert_eq!(app.query_block_time_nanos(), time);
+
+        app.set_block_height(height + 142).unwrap();
+        assert_eq!(app.query_block_height().unwrap(), height + 142);
+        assert_eq!(app.query_block_time_nanos(), time);
+
+        let err = app.set_block_height(42).unwrap_err();
+        assert!(err.to_string().contains("Cannot set block height to 42"), "{}", err);
+        assert_eq!(app.query_block_height().unwrap(), height + 142);
+    }
+
+    #[test]
+    fn denom_creation_fee() {
+        use crate::multi_test::modules::TokenFactory;
+
+        let app = MultiTestRunner::new_with_stargate(DEFAULT_ADDRESS_PREFIX, TokenFactory::default());
+        assert_eq!(app.denom_creation_fee().unwrap(), Some(coin(10000000, DEFAULT_COIN_DENOM)));
+
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        assert_eq!(app.denom_creation_fee().unwrap(), None);
+    }
+
+    #[test]
+    fn stored_code_ids() {
         let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_default_account().unwrap();
+        assert!(app.stored_code_ids().is_empty());
+
+        let code_ids = (0..3)
+            .map(|_| {
+                app.store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(app.stored_code_ids(), code_ids);
+        assert_eq!(app.stored_code_ids(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn block_gas_limit() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_block_gas_limit(60_000);
         let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
         let bob = app.init_account(&[]).unwrap();
+        let send = || {
+            app.execute_cosmos_msgs::<MsgSendResponse>(
+                &[CosmosMsg::Bank(BankMsg::Send {
+                    to_address: bob.address(),
+                    amount: vec![coin(100, "uatom")],
+                })],
+                &alice,
+            )
+        };
 
-        let msgs = vec![cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
-            to_address: bob.address(),
-            amount: vec![cosmwasm_std::Coin {
-                denom: "uatom".to_string(),
-                amount: 100u128.into(),
-            }],
-        })];
+        // The first send fits in the block, the second one doesn't
+        send().unwrap();
+        let err = send().unwrap_err();
+        assert!(err.to_string().contains("out of gas"));
 
-        let res = app.execute_cosmos_msgs::<MsgSendResponse>(&msgs, &alice).unwrap();
+        // The gas used is reset in the next block
+        app.increase_blocks(1).unwrap();
+        send().unwrap();
+    }
 
-        assert_eq!(res.events.len(), 1);
+    #[test]
+    fn gas_tracking() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_gas_tracking();
+        let admin = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+        assert_eq!(app.last_gas_used(), None);
+
+        let code_id = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &admin)
+            .unwrap();
+        let contract: String = crate::helpers::instantiate_contract(&app, &admin, code_id, &counter::InstantiateMsg { count: 0 }).unwrap();
+        let res = Wasm::new(&app)
+            .execute(&contract, &counter::ExecuteMsg::Increment {}, &[], &admin)
+            .unwrap();
+
+        let gas_used = app.last_gas_used().unwrap();
+        assert!(gas_used > 0);
+        assert_eq!(res.gas_info.gas_used, gas_used);
+
+        // Without gas tracking nothing is recorded
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        crate::helpers::bank_send(&app, &alice, &alice.address(), vec![coin(100, "uatom")]).unwrap();
+        assert_eq!(app.last_gas_used(), None);
+    }
+
+    #[test]
+    fn fee_denom() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        assert_eq!(app.fee_denom().unwrap(), DEFAULT_COIN_DENOM);
+
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_fee_denom("ujuno");
+        assert_eq!(app.fee_denom().unwrap(), "ujuno");
+    }
+
+    #[test]
+    fn tx_fee() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_tx_fee(100);
+        let alice = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM), coin(1000, "uatom")]).unwrap();
+        let bob = app.init_account(&[coin(50, DEFAULT_COIN_DENOM), coin(1000, "uatom")]).unwrap();
+
+        // The fee is deducted from signers that can afford it
+        crate::helpers::bank_send(&app, &alice, &bob.address(), vec![coin(100, "uatom")]).unwrap();
         assert_eq!(
-            res.events[0],
-            Event::new("transfer")
-                .add_attribute("recipient", bob.address())
-                .add_attribute("sender", alice.address())
-                .add_attribute("amount", "100uatom")
+            bank_balance_query(&app, alice.address(), DEFAULT_COIN_DENOM.to_string()).unwrap(),
+            Uint128::new(900)
         );
 
-        let bank = Bank::new(&app);
-        let res = bank
-            .send(
-                MsgSend {
-                    from_address: alice.address(),
-                    to_address: bob.address(),
-                    amount: vec![coin(100, "uatom").into()],
-                },
+        // An underfunded signer is rejected before anything is executed
+        let err = crate::helpers::bank_send(&app, &bob, &alice.address(), vec![coin(100, "uatom")]).unwrap_err();
+        let expected = format!(
+            "insufficient fee: {} has 50{}, but the fee is 100{}",
+            bob.address(),
+            DEFAULT_COIN_DENOM,
+            DEFAULT_COIN_DENOM
+        );
+        assert_eq!(err.to_string(), RunnerError::GenericError(expected).to_string());
+        assert_eq!(
+            bank_balance_query(&app, bob.address(), DEFAULT_COIN_DENOM.to_string()).unwrap(),
+            Uint128::new(50)
+        );
+        assert_eq!(
+            bank_balance_query(&app, bob.address(), "uatom".to_string()).unwrap(),
+            Uint128::new(1100)
+        );
+        assert_eq!(app.account_info(&bob.address()).unwrap().1, 0);
+    }
+
+    #[test]
+    fn chain_id() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        assert_eq!(app.chain_id().unwrap(), app.block_info().chain_id);
+
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_chain_id("juno-1");
+        assert_eq!(app.chain_id().unwrap(), "juno-1");
+        assert_eq!(app.block_info().chain_id, "juno-1");
+    }
+
+    #[test]
+    fn tiny_block_gas_limit() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_block_gas_limit(1);
+        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+
+        let err = app
+            .execute_cosmos_msgs::<MsgSendResponse>(
+                &[CosmosMsg::Bank(BankMsg::Send {
+                    to_address: alice.address(),
+                    amount: vec![coin(100, "uatom")],
+                })],
                 &alice,
             )
+            .unwrap_err();
+        assert!(err.to_string().contains("out of gas"));
+    }
+
+    #[test]
+    fn debug_execute() {
+        use osmosis_std::types::cosmwasm::wasm::v1::MsgExecuteContract;
+
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_default_account().unwrap();
+        let contract = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
+            .unwrap();
+        let execute_msg = |msg: &[u8]| MsgExecuteContract {
+            sender: signer.address(),
+            contract: contract.clone(),
+            msg: msg.to_vec(),
+            funds: vec![],
+        };
+
+        let report = app.debug_execute(execute_msg(br#"{"decrement":{}}"#), MsgExecuteContract::TYPE_URL, &signer);
+        assert!(report.failed());
+        assert!(report.events.is_empty() && report.state_diff.is_empty());
+        assert!(report.error_chain.iter().any(|cause| cause.contains("unknown variant `decrement`")));
+        let printed = report.to_string();
+        assert!(printed.contains(MsgExecuteContract::TYPE_URL));
+        assert!(printed.contains("unknown variant `decrement`"));
+
+        let report = app.debug_execute(execute_msg(br#"{"increment":{}}"#), MsgExecuteContract::TYPE_URL, &signer);
+        assert!(!report.failed());
+        assert!(report.events.iter().any(|e| e.ty == "execute"));
+        assert!(!report.state_diff.is_empty());
+    }
+
+    #[test]
+    fn is_contract() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_default_account().unwrap();
+
+        let contract = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
+            .unwrap();
+
+        assert!(app.is_contract(&contract).unwrap());
+        assert!(!app.is_contract(&signer.address()).unwrap());
+    }
+
+    #[test]
+    fn contract_addresses_use_prefix() {
+        let app = MultiTestRunner::new("osmo");
+        let signer = app.init_default_account().unwrap();
+        assert!(signer.address().starts_with("osmo1"));
+
+        let first = app
+            .deploy_named(
+                "first",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
+            .unwrap();
+        let second = app
+            .deploy_named(
+                "second",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
+            .unwrap();
+
+        assert!(first.starts_with("osmo1"), "{}", first);
+        assert!(second.starts_with("osmo1"), "{}", second);
+        assert_ne!(first, second);
+        app.app.borrow().api().addr_validate(&first).unwrap();
+    }
+
+    #[test]
+    fn prefix_shared_by_api_and_contract_addresses() {
+        let app = MultiTestRunner::new("juno");
+        let signer = app.init_default_account().unwrap();
+        let contract = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
             .unwrap();
-        assert_eq!(res.events.len(), 1);
         assert_eq!(
-            res.events[0],
-            Event::new("transfer")
-                .add_attribute("recipient", bob.address())
-                .add_attribute("sender", alice.address())
-                .add_attribute("amount", "100uatom")
+            contract,
+            Bech32AddressGenerator::bech32_contract_address("juno", app.stored_code_ids()[0], 0).unwrap()
         );
+
+        let app = app.app.borrow();
+        let api = app.api();
+        assert_eq!(api.addr_validate(&contract).unwrap().as_str(), contract);
+        assert!(api.addr_validate(&signer.address()).unwrap().as_str().starts_with("juno1"));
+        let other_prefix = Bech32AddressGenerator::bech32_contract_address("osmo", 1, 0).unwrap();
+        assert!(api.addr_validate(&other_prefix).is_err());
     }
 
     #[test]
-    fn bank_queries() {
+    fn instantiate_contract() {
         let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
-        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        let signer = app.init_default_account().unwrap();
+        let code_id = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+            .unwrap();
 
-        let bank = Bank::new(&app);
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                &counter::InstantiateMsg { count: 7 },
+                &[],
+                Some(&signer.address()),
+                "counter",
+                &signer,
+            )
+            .unwrap();
+        assert!(contract.starts_with(DEFAULT_ADDRESS_PREFIX));
 
-        // Query all balances
-        let res = bank
-            .query_all_balances(&QueryAllBalancesRequest {
-                address: alice.address(),
-                pagination: None,
-            })
+        let info = app.app.borrow().contract_data(&Addr::unchecked(&contract)).unwrap();
+        assert_eq!(info.label, "counter");
+        assert_eq!(info.admin, Some(Addr::unchecked(signer.address())));
+
+        let count: counter::GetCountResponse = Wasm::new(&app).query(&contract, &counter::QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 7);
+    }
+
+    #[test]
+    fn cw_it_query_wasm_smart() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_default_account().unwrap();
+
+        let contract = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 3 },
+                &signer,
+            )
             .unwrap();
-        assert_eq!(res.balances.len(), 1);
-        assert_eq!(res.balances[0].denom, "uatom".to_string());
-        assert_eq!(res.balances[0].amount, "1000");
 
-        // Query balance
-        let res = bank
-            .query_balance(&QueryBalanceRequest {
-                address: alice.address(),
-                denom: "uatom".to_string(),
-            })
-            .unwrap()
-            .balance
+        let res: counter::GetCountResponse = CwItRunner::query_wasm_smart(&app, &contract, &counter::QueryMsg::GetCount {}).unwrap();
+        assert_eq!(res, counter::GetCountResponse { count: 3 });
+
+        let err = CwItRunner::query_wasm_smart::<_, counter::GetCountResponse>(&app, &signer.address(), &counter::QueryMsg::GetCount {})
+            .unwrap_err();
+        assert!(err.downcast_ref::<cosmwasm_std::StdError>().is_some());
+    }
+
+    #[test]
+    fn execute_contract() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_default_account().unwrap();
+
+        let contract = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
             .unwrap();
-        assert_eq!(res.denom, "uatom".to_string());
-        assert_eq!(res.amount, "1000");
 
-        // Query total supply should fail since there is no cosmwasm bank query for it
-        let _res = bank.query_total_supply(&QueryTotalSupplyRequest { pagination: None }).unwrap_err();
+        let res = app
+            .execute_contract(&contract, &counter::ExecuteMsg::Increment {}, &[], &signer)
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm" && e.attributes.iter().any(|a| a.value == "increment")));
 
-        // Query supply of
-        let supply = OsmosisQuerySupplyOfRequest {
-            denom: "uatom".to_string(),
-        }
-        .query(&app.app.borrow().wrap())
-        .unwrap()
-        .amount
-        .unwrap();
-        assert_eq!(supply.denom, "uatom".to_string());
-        assert_eq!(supply.amount, "1000");
+        let count: counter::GetCountResponse = Wasm::new(&app).query(&contract, &counter::QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 1);
     }
 
     #[test]
-    fn query_bank_through_test_tube_bank_module() {
+    fn cw_it_contract_methods_are_transactions() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX).with_tx_fee(100).with_gas_tracking();
+        let signer = app.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+        let code_id = app
+            .store_code(ContractType::MultiTestContract(counter::contract()), &signer)
+            .unwrap();
+
+        let contract = app
+            .instantiate_contract(code_id, &counter::InstantiateMsg { count: 0 }, &[], None, "counter", &signer)
+            .unwrap();
+        app.execute_contract(&contract, &counter::ExecuteMsg::Increment {}, &[], &signer)
+            .unwrap();
+        assert!(app.last_gas_used().unwrap() > 0);
+        app.migrate_contract(
+            &contract,
+            code_id,
+            &to_json_vec(&counter::MigrateMsg { count: None }).unwrap(),
+            &signer,
+        )
+        .unwrap_err();
+
+        // Every call is charged the fee and increments the sequence, even the migration that failed
+        // as the contract has no admin
+        assert_eq!(app.account_info(&signer.address()).unwrap().1, 3);
+        assert_eq!(
+            bank_balance_query(&app, signer.address(), DEFAULT_COIN_DENOM.to_string()).unwrap(),
+            Uint128::new(700)
+        );
+    }
+
+    #[test]
+    fn simulate() {
+        use osmosis_std::types::cosmwasm::wasm::v1::MsgExecuteContract;
+
         let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
-        let alice = app.init_account(&[coin(1000, "uatom")]).unwrap();
+        let signer = app.init_default_account().unwrap();
+        let contract = app
+            .deploy_named(
+                "counter",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 0 },
+                &signer,
+            )
+            .unwrap();
+        let sequence = app.account_info(&signer.address()).unwrap().1;
 
-        let bank = Bank::new(&app);
+        let msg = MsgExecuteContract {
+            sender: signer.address(),
+            contract: contract.clone(),
+            msg: to_json_vec(&counter::ExecuteMsg::Increment {}).unwrap(),
+            funds: vec![],
+        };
+        let any = cosmrs::Any {
+            type_url: MsgExecuteContract::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+        let gas_info = app.simulate(vec![any], &signer).unwrap();
+        assert!(gas_info.gas_used > 0);
+        assert_eq!(gas_info.gas_wanted, gas_info.gas_used);
+
+        // Neither the state nor the sequence of the signer changed
+        let count: counter::GetCountResponse = Wasm::new(&app).query(&contract, &counter::QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 0);
+        assert_eq!(app.account_info(&signer.address()).unwrap().1, sequence);
+
+        // Failing executions error like on chain
+        let msg = MsgExecuteContract {
+            sender: signer.address(),
+            contract,
+            msg: b"{}".to_vec(),
+            funds: vec![],
+        };
+        let any = cosmrs::Any {
+            type_url: MsgExecuteContract::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+        assert!(app.simulate(vec![any], &signer).is_err());
+    }
 
-        let res = bank
-            .query_all_balances(&QueryAllBalancesRequest {
-                address: alice.address(),
-                pagination: None,
-            })
+    #[test]
+    fn simulate_checks_fee_and_block_gas() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX)
+            .with_tx_fee(100)
+            .with_block_gas_limit(60_000);
+        let alice = app.init_account(&[coin(150, DEFAULT_COIN_DENOM)]).unwrap();
+        let bob = app.init_account(&[coin(50, DEFAULT_COIN_DENOM)]).unwrap();
+        let send = |from: &SigningAccount, amount: u128| {
+            let msg = MsgSend {
+                from_address: from.address(),
+                to_address: alice.address(),
+                amount: vec![coin(amount, DEFAULT_COIN_DENOM).into()],
+            };
+            vec![cosmrs::Any {
+                type_url: MsgSend::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }]
+        };
+
+        // The messages see the balance after the fee, which is not burned by the simulation
+        app.simulate(send(&alice, 50), &alice).unwrap();
+        assert!(app.simulate(send(&alice, 100), &alice).is_err());
+        assert_eq!(
+            bank_balance_query(&app, alice.address(), DEFAULT_COIN_DENOM.to_string()).unwrap(),
+            Uint128::new(150)
+        );
+
+        let err = app.simulate(send(&bob, 10), &bob).unwrap_err();
+        assert!(err.to_string().contains("insufficient fee"));
+
+        let err = app.simulate([send(&alice, 10), send(&alice, 10)].concat(), &alice).unwrap_err();
+        assert!(err.to_string().contains("out of gas"));
+    }
+
+    #[test]
+    fn account_info() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let accounts = app.init_default_accounts().unwrap();
+
+        assert_eq!(app.account_info(&accounts[0].address()).unwrap(), (0, 0));
+        assert_eq!(app.account_info(&accounts[1].address()).unwrap(), (1, 0));
+
+        crate::helpers::bank_send(&app, &accounts[0], &accounts[1].address(), vec![coin(100, DEFAULT_COIN_DENOM)]).unwrap();
+        assert_eq!(app.account_info(&accounts[0].address()).unwrap(), (0, 1));
+        assert_eq!(app.account_info(&accounts[1].address()).unwrap(), (1, 0));
+    }
+
+    #[test]
+    fn account_info_of_unknown_account() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let unknown = app.app.borrow().api().addr_make("unknown").to_string();
+
+        assert!(app.account_info(&unknown).is_err());
+
+        // Querying the unknown account must not have assigned it an account number
+        let alice = app.init_account(&[]).unwrap();
+        assert_eq!(app.account_info(&alice.address()).unwrap(), (0, 0));
+        assert!(app.account_info(&unknown).is_err());
+    }
+
+    #[test]
+    fn query_denom_metadata() {
+        let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        assert_eq!(app.query_denom_metadata("uatom").unwrap(), None);
+
+        let metadata = cosmwasm_std::DenomMetadata {
+            description: "The native token of the Cosmos Hub".to_string(),
+            denom_units: vec![
+                cosmwasm_std::DenomUnit {
+                    denom: "uatom".to_string(),
+                    exponent: 0,
+                    aliases: vec!["microatom".to_string()],
+                },
+                cosmwasm_std::DenomUnit {
+                    denom: "atom".to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            base: "uatom".to_string(),
+            display: "atom".to_string(),
+            name: "Cosmos Hub Atom".to_string(),
+            symbol: "ATOM".to_string(),
+            uri: "".to_string(),
+            uri_hash: "".to_string(),
+        };
+        app.app
+            .borrow_mut()
+            .init_modules(|router, _, storage| router.bank.set_denom_metadata(storage, "uatom".to_string(), metadata))
             .unwrap();
 
-        assert_eq!(res.balances.len(), 1);
-        assert_eq!(res.balances[0].denom, "uatom".to_string());
-        assert_eq!(res.balances[0].amount, "1000");
+        let metadata = app.query_denom_metadata("uatom").unwrap().unwrap();
+        assert_eq!(metadata.base, "uatom");
+        assert_eq!(metadata.display, "atom");
+        assert_eq!(metadata.symbol, "ATOM");
+        assert_eq!(
+            metadata.denom_units[0],
+            DenomUnit {
+                denom: "uatom".to_string(),
+                exponent: 0,
+                aliases: vec!["microatom".to_string()],
+            }
+        );
+        assert_eq!(metadata.denom_units[1].exponent, 6);
     }
 
     #[test]
-    fn test_increase_time() {
+    fn deploy_named() {
         let app = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let signer = app.init_default_account().unwrap();
+
+        let first = app
+            .deploy_named(
+                "first",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 1 },
+                &signer,
+            )
+            .unwrap();
+        let second = app
+            .deploy_named(
+                "second",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 2 },
+                &signer,
+            )
+            .unwrap();
 
-        let time = app.app.borrow().block_info().time;
-        app.increase_time(69).unwrap();
-        assert_eq!(app.app.borrow().block_info().time.seconds(), time.seconds() + 69);
+        assert_ne!(first, second);
+        assert_eq!(app.address_of("first"), Some(first));
+        assert_eq!(app.address_of("third"), None);
+        let count: counter::GetCountResponse = app
+            .query_wasm_smart(app.address_of("second").unwrap(), &counter::QueryMsg::GetCount {})
+            .unwrap();
+        assert_eq!(count.count, 2);
+
+        // Names can only be registered once
+        let err = app
+            .deploy_named(
+                "first",
+                ContractType::MultiTestContract(counter::contract()),
+                &counter::InstantiateMsg { count: 3 },
+                &signer,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("already deployed"));
     }
 }
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use cosmwasm_std::{Order, Record, Storage};
+
+/// A write cache on top of a read-only storage. Reads see the writes made through the cache, while
+/// the underlying storage is left untouched, so all writes are discarded with the cache.
+pub(crate) struct StorageCache<'a> {
+    base: &'a dyn Storage,
+    /// The values written through the cache, `None` for removed keys.
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> StorageCache<'a> {
+    pub(crate) fn new(base: &'a dyn Storage) -> Self {
+        Self {
+            base,
+            writes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Storage for StorageCache<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn range<'b>(&'b self, start: Option<&[u8]>, end: Option<&[u8]>, order: Order) -> Box<dyn Iterator<Item = Record> + 'b> {
+        // BTreeMap::range panics for inverted ranges, which are empty for a storage
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                return Box::new(std::iter::empty());
+            }
+        }
+
+        let mut records = self.base.range(start, end, Order::Ascending).collect::<BTreeMap<_, _>>();
+        let bounds = (
+            start.map_or(Bound::Unbounded, |start| Bound::Included(start.to_vec())),
+            end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+        );
+        for (key, value) in self.writes.range(bounds) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[test]
+    fn writes_are_cached() {
+        let mut base = MockStorage::new();
+        base.set(b"a", b"1");
+        base.set(b"b", b"2");
+        base.set(b"c", b"3");
+
+        let mut cache = StorageCache::new(&base);
+        cache.set(b"b", b"20");
+        cache.remove(b"c");
+        cache.set(b"d", b"4");
+
+        assert_eq!(cache.get(b"a"), Some(b"1".to_vec()));
+        assert_eq!(cache.get(b"b"), Some(b"20".to_vec()));
+        assert_eq!(cache.get(b"c"), None);
+        assert_eq!(
+            cache.range(Some(b"b"), None, Order::Descending).collect::<Vec<_>>(),
+            vec![(b"d".to_vec(), b"4".to_vec()), (b"b".to_vec(), b"20".to_vec())]
+        );
+        assert_eq!(cache.range(Some(b"c"), Some(b"b"), Order::Ascending).count(), 0);
+
+        // The base storage is untouched
+        assert_eq!(base.get(b"b"), Some(b"2".to_vec()));
+        assert_eq!(base.get(b"c"), Some(b"3".to_vec()));
+        assert_eq!(base.get(b"d"), None);
+    }
+}
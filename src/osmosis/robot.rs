@@ -1,12 +1,14 @@
+use std::str::FromStr;
+
 use crate::osmosis::utils::is_osmosis_lp_token;
 use cosmrs::proto::Any;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, Uint128};
 use osmosis_std::{
     shim::Duration,
     types::osmosis::{
         gamm::v1beta1::{
             MsgJoinSwapExternAmountIn, MsgJoinSwapExternAmountInResponse, MsgSwapExactAmountIn,
-            MsgSwapExactAmountInResponse,
+            MsgSwapExactAmountInResponse, QueryTotalSharesRequest, QueryTotalSharesResponse,
         },
         lockup::Params as LockupParams,
         lockup::{MsgLockTokens, MsgLockTokensResponse},
@@ -143,6 +145,25 @@ pub trait OsmosisTestRobot<'a>: TestRobot<'a, OsmosisTestApp> {
         self
     }
 
+    /// Queries the total amount of LP shares of a pool
+    /// ## Args:
+    ///   - `pool_id`: The pool ID
+    ///
+    /// ## Returns:
+    ///  - `Uint128`: The total amount of `gamm/pool/{pool_id}` shares
+    fn pool_total_shares(&self, pool_id: u64) -> Uint128 {
+        let total_shares = self
+            .runner()
+            .query::<_, QueryTotalSharesResponse>(
+                "/osmosis.gamm.v1beta1.Query/TotalShares",
+                &QueryTotalSharesRequest { pool_id },
+            )
+            .unwrap()
+            .total_shares
+            .unwrap();
+        Uint128::from_str(&total_shares.amount).unwrap()
+    }
+
     /// Locks LP shares for a given duration in the osmosis lockup module
     fn lock_tokens(&self, signer: &SigningAccount, coin: Coin, duration: u32) -> &Self {
         if !is_osmosis_lp_token(&coin.denom) {
@@ -169,6 +190,7 @@ pub trait OsmosisTestRobot<'a>: TestRobot<'a, OsmosisTestApp> {
 mod tests {
     use apollo_utils::iterators::IntoElementwise;
     use cosmwasm_std::Coin;
+    use osmosis_std::types::osmosis::gamm::v1beta1::{MsgJoinPool, MsgJoinPoolResponse};
     use osmosis_test_tube::{FeeSetting, Gamm, Module, OsmosisTestApp};
 
     use crate::const_coin::ConstCoin;
@@ -249,6 +271,53 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_pool_total_shares() {
+        let app = OsmosisTestApp::new();
+        let account = app
+            .init_account(&INITIAL_BALANCES.into_elementwise())
+            .unwrap();
+        let gamm = Gamm::new(&app);
+        let pool_id = gamm
+            .create_basic_pool(
+                &[
+                    Coin::new(1_000_000_000, "uosmo"),
+                    Coin::new(1_000_000_000, "uatom"),
+                ],
+                &account,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+
+        let robot = TestingRobot(&app);
+        let total_shares_before = robot.pool_total_shares(pool_id);
+        assert!(!total_shares_before.is_zero());
+
+        let minted = app
+            .execute::<_, MsgJoinPoolResponse>(
+                MsgJoinPool {
+                    sender: account.address(),
+                    pool_id,
+                    share_out_amount: "1000000000000000000".to_string(),
+                    token_in_maxs: vec![
+                        Coin::new(1_000_000_000, "uatom").into(),
+                        Coin::new(1_000_000_000, "uosmo").into(),
+                    ],
+                },
+                MsgJoinPool::TYPE_URL,
+                &account,
+            )
+            .unwrap()
+            .data
+            .share_out_amount;
+
+        assert_eq!(
+            robot.pool_total_shares(pool_id),
+            total_shares_before + Uint128::from_str(&minted).unwrap()
+        );
+    }
+
     #[test]
     fn test_swap_exact_amount_in() {
         let app = OsmosisTestApp::new();
@@ -1,9 +1,17 @@
 use anyhow::Error;
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
 use cosmwasm_std::Coin;
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
 use osmosis_std::{shim::Any, types::osmosis::lockup};
 use osmosis_test_tube::{Module, OsmosisTestApp, SigningAccount, Wasm};
 use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
 
+use crate::helpers::{
+    execute_contract, fund_from_new_account, migrate_contract, query_account_info, query_code_hash, query_denom_creation_fee,
+    query_denom_metadata, query_is_contract,
+};
+use crate::traits::CwItResponse;
 use crate::{traits::CwItRunner, ContractType};
 
 #[cfg(feature = "multi-test")]
@@ -42,9 +50,89 @@ impl CwItRunner<'_> for OsmosisTestApp {
         Ok(())
     }
 
+    fn increase_blocks(&self, blocks: u64) -> Result<(), Error> {
+        // Every time increase finalizes a new block, so increasing by zero seconds yields an empty block
+        for _ in 0..blocks {
+            OsmosisTestApp::increase_time(self, 0);
+        }
+        Ok(())
+    }
+
     fn query_block_time_nanos(&self) -> u64 {
         self.get_block_time_nanos() as u64
     }
+
+    fn query_block_height(&self) -> Result<u64, Error> {
+        Ok(self.get_block_height() as u64)
+    }
+
+    fn migrate_contract(&self, contract: &str, new_code_id: u64, msg: &[u8], signer: &SigningAccount) -> Result<CwItResponse, Error> {
+        Ok(migrate_contract(self, contract, new_code_id, msg, signer)?)
+    }
+
+    fn query_code_hash(&self, code_id: u64) -> Result<Vec<u8>, Error> {
+        Ok(query_code_hash(self, code_id)?)
+    }
+
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, Error> {
+        Ok(query_denom_creation_fee(self)?)
+    }
+
+    fn is_contract(&self, address: &str) -> Result<bool, Error> {
+        Ok(query_is_contract(self, address)?)
+    }
+
+    fn account_info(&self, address: &str) -> Result<(u64, u64), Error> {
+        Ok(query_account_info(self, address)?)
+    }
+
+    fn execute_contract<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, Error> {
+        Ok(execute_contract(self, contract, msg, funds, signer)?)
+    }
+
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        funds: &[Coin],
+        admin: Option<&str>,
+        label: &str,
+        signer: &SigningAccount,
+    ) -> Result<String, Error> {
+        let res = Wasm::new(self).instantiate(code_id, msg, admin, Some(label), funds, signer)?;
+        Ok(res.data.address)
+    }
+
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, contract: &str, query: &Q) -> Result<R, Error> {
+        Ok(Wasm::new(self).query(contract, query)?)
+    }
+
+    fn query_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, Error> {
+        Ok(query_denom_metadata(self, denom)?)
+    }
+
+    fn fund_account(&self, address: &str, coins: &[Coin]) -> Result<(), Error> {
+        fund_from_new_account(self, address, coins, Coin::new(1_000_000_000_000, "uosmo"))
+    }
+
+    fn simulate(&self, msgs: Vec<cosmrs::Any>, signer: &SigningAccount) -> Result<GasInfo, Error> {
+        Ok(self.simulate_tx(msgs, signer)?)
+    }
+
+    fn fee_denom(&self) -> Result<&str, Error> {
+        Ok("uosmo")
+    }
+
+    /// The chain id [`OsmosisTestApp`] is always created with.
+    fn chain_id(&self) -> Result<String, Error> {
+        Ok("osmosis-1".to_string())
+    }
 }
 
 /// A trait for enabling the functionality of whitelisting an address for force unlock of a locked
@@ -90,6 +178,40 @@ mod tests {
 
     const TEST_ARTIFACT: &str = "artifacts/counter.wasm";
 
+    #[derive(serde::Serialize)]
+    struct InstantiateMsg {
+        count: i32,
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Increment {},
+    }
+
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum QueryMsg {
+        GetCount {},
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct GetCountResponse {
+        count: i32,
+    }
+
+    /// Stores the counter artifact and instantiates it with a count of zero, returning the funded
+    /// admin of the contract and its address.
+    fn setup_counter(app: &OsmosisTestApp) -> (SigningAccount, String) {
+        let admin = app.init_account(&[Coin::new(1000000000000, "uosmo")]).unwrap();
+        let code_id = app
+            .store_code(ContractType::Artifact(Artifact::Local(TEST_ARTIFACT.to_string())), &admin)
+            .unwrap();
+        let contract: String = crate::helpers::instantiate_contract(app, &admin, code_id, &InstantiateMsg { count: 0 }).unwrap();
+
+        (admin, contract)
+    }
+
     #[test]
     fn osmosis_test_app_store_code() {
         let app = OsmosisTestApp::new();
@@ -123,6 +245,13 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_fee_denom() {
+        let app = OsmosisTestApp::new();
+
+        assert_eq!(CwItRunner::fee_denom(&app).unwrap(), "uosmo");
+    }
+
     #[test]
     fn test_increase_time() {
         let app = OsmosisTestApp::new();
@@ -132,6 +261,295 @@ mod tests {
         assert_eq!(app.get_block_time_nanos(), time + 69000000000);
     }
 
+    #[test]
+    fn test_query_block_height() {
+        let app = OsmosisTestApp::new();
+
+        let height = app.query_block_height().unwrap();
+        CwItRunner::increase_time(&app, 69).unwrap();
+        assert!(app.query_block_height().unwrap() > height);
+    }
+
+    #[test]
+    fn test_query_code_hash() {
+        use sha2::{Digest, Sha256};
+
+        let app = OsmosisTestApp::new();
+        let admin = app.init_account(&[Coin::new(1000000000000, "uosmo")]).unwrap();
+        let code_id = app
+            .store_code(ContractType::Artifact(Artifact::Local(TEST_ARTIFACT.to_string())), &admin)
+            .unwrap();
+
+        let expected = Sha256::digest(std::fs::read(TEST_ARTIFACT).unwrap()).to_vec();
+        assert_eq!(app.query_code_hash(code_id).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_set_block_time() {
+        let app = OsmosisTestApp::new();
+
+        let target = app.query_block_time_nanos() / 1_000_000_000 + 3600;
+        CwItRunner::set_block_time(&app, target).unwrap();
+        assert_eq!(app.query_block_time_nanos() / 1_000_000_000, target);
+
+        // Time can't be moved backwards in test-tube
+        CwItRunner::set_block_time(&app, target - 1).unwrap_err();
+    }
+
+    #[test]
+    fn test_increase_blocks() {
+        let app = OsmosisTestApp::new();
+
+        let height = app.query_block_height().unwrap();
+        CwItRunner::increase_blocks(&app, 100).unwrap();
+        assert_eq!(app.query_block_height().unwrap(), height + 100);
+    }
+
+    #[test]
+    fn test_gas_meter() {
+        use crate::helpers::{bank_send, GasMeter};
+
+        let app = OsmosisTestApp::new();
+        let accounts = app.init_accounts(&[Coin::new(1_000_000_000_000, "uosmo")], 2).unwrap();
+
+        let mut meter = GasMeter::new();
+        for i in 0..3 {
+            let res = bank_send(&app, &accounts[0], &accounts[1].address(), vec![Coin::new(1000, "uosmo")]).unwrap();
+            assert!(meter.record(format!("send {}", i), &res) > 0);
+        }
+
+        assert_eq!(meter.steps().len(), 3);
+        assert_eq!(meter.total(), meter.steps().iter().map(|(_, gas)| gas).sum::<u64>());
+        meter.assert_within_budget(1_000_000);
+    }
+
+    #[test]
+    fn test_is_contract() {
+        let app = OsmosisTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        assert!(CwItRunner::is_contract(&app, &contract).unwrap());
+        assert!(!CwItRunner::is_contract(&app, &admin.address()).unwrap());
+        assert!(CwItRunner::is_contract(&app, "invalid").is_err());
+    }
+
+    #[test]
+    fn test_execute_contract() {
+        let app = OsmosisTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        let res = CwItRunner::execute_contract(&app, &contract, &ExecuteMsg::Increment {}, &[], &admin).unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "wasm"));
+
+        let count: GetCountResponse = Wasm::new(&app).query(&contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 1);
+    }
+
+    #[test]
+    fn test_simulate() {
+        use osmosis_std::types::cosmwasm::wasm::v1::MsgExecuteContract;
+
+        let app = OsmosisTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        let msg = MsgExecuteContract {
+            sender: admin.address(),
+            contract: contract.clone(),
+            msg: cosmwasm_std::to_json_vec(&ExecuteMsg::Increment {}).unwrap(),
+            funds: vec![],
+        };
+        let any = cosmrs::Any {
+            type_url: MsgExecuteContract::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+        let gas_info = CwItRunner::simulate(&app, vec![any], &admin).unwrap();
+        assert!(gas_info.gas_used > 0);
+
+        // The simulation does not change any state
+        let count: GetCountResponse = Wasm::new(&app).query(&contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 0);
+    }
+
+    #[test]
+    fn test_instantiate_contract() {
+        let app = OsmosisTestApp::new();
+        let admin = app.init_account(&[Coin::new(1000000000000, "uosmo")]).unwrap();
+        let code_id = app
+            .store_code(ContractType::Artifact(Artifact::Local(TEST_ARTIFACT.to_string())), &admin)
+            .unwrap();
+
+        let contract = CwItRunner::instantiate_contract(
+            &app,
+            code_id,
+            &InstantiateMsg { count: 7 },
+            &[],
+            Some(&admin.address()),
+            "counter",
+            &admin,
+        )
+        .unwrap();
+        assert!(contract.starts_with("osmo1"));
+
+        let count: GetCountResponse = Wasm::new(&app).query(&contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(count.count, 7);
+    }
+
+    #[test]
+    fn test_query_wasm_smart() {
+        let app = OsmosisTestApp::new();
+        let (admin, contract) = setup_counter(&app);
+
+        let res: GetCountResponse = CwItRunner::query_wasm_smart(&app, &contract, &QueryMsg::GetCount {}).unwrap();
+        assert_eq!(res, GetCountResponse { count: 0 });
+
+        let err = CwItRunner::query_wasm_smart::<_, GetCountResponse>(&app, &admin.address(), &QueryMsg::GetCount {}).unwrap_err();
+        assert!(err.downcast_ref::<test_tube::RunnerError>().is_some());
+    }
+
+    #[test]
+    fn test_denom_creation_fee() {
+        let app = OsmosisTestApp::new();
+
+        let fee = app.denom_creation_fee().unwrap().unwrap();
+        assert_eq!(fee.denom, "uosmo");
+        assert!(!fee.amount.is_zero());
+    }
+
+    #[test]
+    fn test_account_info() {
+        let app = OsmosisTestApp::new();
+        let accounts = app.init_default_accounts().unwrap();
+        let address = accounts[0].address();
+
+        let (account_number, sequence) = CwItRunner::account_info(&app, &address).unwrap();
+        crate::helpers::bank_send(&app, &accounts[0], &accounts[1].address(), vec![Coin::new(100, "uosmo")]).unwrap();
+
+        assert_eq!(CwItRunner::account_info(&app, &address).unwrap(), (account_number, sequence + 1));
+        assert!(CwItRunner::account_info(&app, "osmo1unknown").is_err());
+    }
+
+    #[test]
+    fn test_query_denom_metadata() {
+        use osmosis_std::types::cosmos::bank::v1beta1::DenomUnit;
+        use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+            MsgCreateDenom, MsgCreateDenomResponse, MsgSetDenomMetadata, MsgSetDenomMetadataResponse,
+        };
+
+        let app = OsmosisTestApp::new();
+        let creator = app.init_account(&[Coin::new(1_000_000_000_000, "uosmo")]).unwrap();
+        let denom = app
+            .execute::<_, MsgCreateDenomResponse>(
+                MsgCreateDenom {
+                    sender: creator.address(),
+                    subdenom: "token".to_string(),
+                },
+                MsgCreateDenom::TYPE_URL,
+                &creator,
+            )
+            .unwrap()
+            .data
+            .new_token_denom;
+        assert_eq!(app.query_denom_metadata("unknown").unwrap(), None);
+
+        let metadata = Metadata {
+            description: "A test token".to_string(),
+            denom_units: vec![DenomUnit {
+                denom: denom.clone(),
+                exponent: 0,
+                aliases: vec![],
+            }],
+            base: denom.clone(),
+            display: denom.clone(),
+            name: "Token".to_string(),
+            symbol: "TOKEN".to_string(),
+            ..Default::default()
+        };
+        app.execute::<_, MsgSetDenomMetadataResponse>(
+            MsgSetDenomMetadata {
+                sender: creator.address(),
+                metadata: Some(metadata.clone()),
+            },
+            MsgSetDenomMetadata::TYPE_URL,
+            &creator,
+        )
+        .unwrap();
+
+        assert_eq!(app.query_denom_metadata(&denom).unwrap(), Some(metadata));
+    }
+
+    #[test]
+    fn test_skip_simulation() {
+        use crate::helpers::{bank_send, skip_simulation, FIXED_GAS_LIMIT};
+
+        let app = OsmosisTestApp::new();
+        let sender = app.init_account(&[Coin::new(1_000_000_000_000, "uosmo")]).unwrap();
+        let sender = skip_simulation(sender, Coin::new(1_000_000, "uosmo"));
+        let recipient = app.init_account(&[]).unwrap();
+
+        for _ in 0..3 {
+            let res = bank_send(&app, &sender, &recipient.address(), vec![Coin::new(100, "uosmo")]).unwrap();
+            assert_eq!(res.gas_info.gas_wanted, FIXED_GAS_LIMIT);
+        }
+    }
+
+    #[test]
+    fn test_assert_balances_ignoring_fees() {
+        use crate::helpers::{assert_balances_ignoring_fees, bank_send};
+
+        let app = OsmosisTestApp::new();
+        assert_eq!(CwItRunner::fee_denom(&app).unwrap(), "uosmo");
+        let initial_balance = [Coin::new(1_000_000_000_000, "uion"), Coin::new(1_000_000_000_000, "uosmo")];
+        let sender = app.init_account(&initial_balance).unwrap();
+        let recipient = app.init_account(&[]).unwrap();
+
+        bank_send(&app, &sender, &recipient.address(), vec![Coin::new(100, "uion")]).unwrap();
+
+        // The sender paid fees in uosmo, but the uion balance is exact
+        assert_balances_ignoring_fees(
+            &app,
+            &sender.address(),
+            &[Coin::new(999_999_999_900, "uion"), Coin::new(1_000_000_000_000, "uosmo")],
+            10_000_000,
+        );
+        assert_balances_ignoring_fees(&app, &recipient.address(), &[Coin::new(100, "uion")], 0);
+    }
+
+    #[test]
+    fn test_assert_amount_emitted() {
+        use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgMint};
+        use osmosis_test_tube::TokenFactory;
+
+        use crate::helpers::assert_amount_emitted;
+
+        let app = OsmosisTestApp::new();
+        let creator = app.init_account(&[Coin::new(1_000_000_000_000, "uosmo")]).unwrap();
+        let token_factory = TokenFactory::new(&app);
+
+        let denom = token_factory
+            .create_denom(
+                MsgCreateDenom {
+                    sender: creator.address(),
+                    subdenom: "mydenom".to_string(),
+                },
+                &creator,
+            )
+            .unwrap()
+            .data
+            .new_token_denom;
+        let res = token_factory
+            .mint(
+                MsgMint {
+                    sender: creator.address(),
+                    amount: Some(Coin::new(1234, &denom).into()),
+                    mint_to_address: creator.address(),
+                },
+                &creator,
+            )
+            .unwrap();
+
+        assert_amount_emitted(&res, &denom, 1234u128);
+    }
+
     #[test]
     fn whitelist_address_for_force_unlock_works() {
         let app = OsmosisTestApp::new();
@@ -215,4 +633,15 @@ mod tests {
         // should succeed
         assert!(res.data.success);
     }
+
+    #[test]
+    fn test_assert_token_lifecycle() {
+        use crate::helpers::{assert_token_lifecycle, factory_denom};
+
+        let app = OsmosisTestApp::new();
+        let creator = app.init_account(&[Coin::new(1_000_000_000_000, "uosmo")]).unwrap();
+
+        let denom = assert_token_lifecycle(&app, &creator, "lifecycle");
+        assert_eq!(denom, factory_denom(&creator.address(), "lifecycle"));
+    }
 }
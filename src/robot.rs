@@ -1,7 +1,8 @@
-use cosmwasm_std::{Coin, Uint128};
+use cosmwasm_std::{Coin, Event, Uint128};
 use test_tube::{Bank, Module, Runner, SigningAccount, Wasm};
 
 use crate::helpers::{bank_balance_query, bank_send};
+use crate::traits::CwItRunner;
 
 /// Implements a collection of common interactions with a `Runner`, that are all applicable to any
 /// cosmos chain.
@@ -69,6 +70,64 @@ pub trait TestRobot<'a, R: Runner<'a> + 'a> {
         self
     }
 
+    /// Asserts that the given account holds none of `denom`. Panics on error or if the balance
+    /// is non-zero. Returns `self` to allow for chaining.
+    fn assert_zero_balance(&self, account: impl Into<String>, denom: impl Into<String>) -> &Self {
+        let account = account.into();
+        let denom = denom.into();
+        let actual = self.query_native_token_balance(account.clone(), denom.clone());
+        assert!(
+            actual.is_zero(),
+            "Expected {} to hold no {}, but it holds {}{}",
+            account,
+            denom,
+            actual,
+            denom
+        );
+
+        self
+    }
+
+    /// Asserts that the bank balance of the given account equals `expected`, in the denom of
+    /// `expected`. Panics on error or mismatch. Returns `self` to allow for chaining.
+    fn assert_balance(&self, account: impl Into<String>, expected: Coin) -> &Self {
+        let account = account.into();
+        let actual = self.query_native_token_balance(account.clone(), expected.denom.clone());
+        assert!(
+            actual == expected.amount,
+            "Expected {} to hold {}, but it holds {}{}",
+            account,
+            expected,
+            actual,
+            expected.denom
+        );
+
+        self
+    }
+
+    /// Asserts that one of the `events` of type `ty`, usually those of the last response, has an
+    /// attribute `key` with value `value`. Panics listing the attributes of all events of type `ty`
+    /// otherwise. Returns `self` to allow for chaining.
+    fn assert_event_attr(&self, events: &[Event], ty: &str, key: &str, value: &str) -> &Self {
+        let candidates = events.iter().filter(|e| e.ty == ty).collect::<Vec<_>>();
+        let found = candidates
+            .iter()
+            .any(|e| e.attributes.iter().any(|a| a.key == key && a.value == value));
+        assert!(
+            found,
+            "Expected a {} event with {}={}, but found {:?}",
+            ty,
+            key,
+            value,
+            candidates
+                .iter()
+                .map(|e| e.attributes.iter().map(|a| format!("{}={}", a.key, a.value)).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+
+        self
+    }
+
     /// Sends `amount` of `denom` from `from` to `to`. Panics on error. Returns `self` to allow for
     /// chaining.
     fn send_native_tokens(
@@ -86,6 +145,119 @@ pub trait TestRobot<'a, R: Runner<'a> + 'a> {
 
         self
     }
+
+    /// Adds `coins` to the balance of the given account, by minting them on multi-test and by
+    /// sending them from a pre-funded account on other runners. Panics on error. Returns `self` to
+    /// allow for chaining.
+    fn fund_account(&self, account: impl Into<String>, coins: &[Coin]) -> &Self
+    where
+        R: CwItRunner<'a>,
+    {
+        self.runner().fund_account(&account.into(), coins).unwrap();
+
+        self
+    }
+}
+
+#[cfg(all(feature = "multi-test", not(feature = "coreum")))]
+#[cfg(test)]
+mod multi_test_robot_tests {
+    use cosmwasm_std::coin;
+    use test_tube::Account;
+
+    use crate::multi_test::MultiTestRunner;
+    use crate::test_helpers::counter;
+    use crate::traits::{CwItRunner, DEFAULT_ADDRESS_PREFIX, DEFAULT_COIN_DENOM};
+
+    use super::*;
+
+    struct MultiTestRobot<'a>(&'a MultiTestRunner);
+
+    impl<'a> TestRobot<'a, MultiTestRunner> for MultiTestRobot<'a> {
+        fn runner(&self) -> &'a MultiTestRunner {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_assert_zero_balance() {
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let robot = MultiTestRobot(&runner);
+        let admin = runner.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+
+        let contract_addr = counter::deploy_test_counter(&runner, &admin).unwrap().address;
+
+        robot
+            .assert_zero_balance(&contract_addr, "uatom")
+            .assert_zero_balance(&contract_addr, DEFAULT_COIN_DENOM);
+    }
+
+    #[test]
+    #[should_panic(expected = "but it holds 1000uatom")]
+    fn test_assert_zero_balance_fails_on_non_zero_balance() {
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let robot = MultiTestRobot(&runner);
+        let account = runner.init_account(&[coin(1000, "uatom")]).unwrap();
+
+        robot.assert_zero_balance(account.address(), "uatom");
+    }
+
+    #[test]
+    fn test_assert_balance_and_event_attr() {
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let robot = MultiTestRobot(&runner);
+        let admin = runner.init_account(&[coin(1000, DEFAULT_COIN_DENOM), coin(1000, "uatom")]).unwrap();
+        let contract_addr = counter::deploy_test_counter(&runner, &admin).unwrap().address;
+
+        let res = robot
+            .wasm()
+            .execute(&contract_addr, &counter::ExecuteMsg::Increment {}, &[coin(100, "uatom")], &admin)
+            .unwrap();
+        robot
+            .assert_event_attr(&res.events, "wasm", "action", "increment")
+            .assert_event_attr(&res.events, "execute", "_contract_address", &contract_addr)
+            .assert_balance(&contract_addr, coin(100, "uatom"))
+            .assert_balance(admin.address(), coin(900, "uatom"))
+            .send_native_tokens(&admin, &contract_addr, 50u128, "uatom")
+            .assert_balance(&contract_addr, coin(150, "uatom"));
+    }
+
+    #[test]
+    fn test_fund_account() {
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let robot = MultiTestRobot(&runner);
+        let account = runner.init_account(&[coin(1000, "uatom")]).unwrap();
+
+        robot
+            .fund_account(account.address(), &[coin(500, "uatom"), coin(200, DEFAULT_COIN_DENOM)])
+            .assert_balance(account.address(), coin(1500, "uatom"))
+            .assert_balance(account.address(), coin(200, DEFAULT_COIN_DENOM));
+    }
+
+    #[test]
+    #[should_panic(expected = "but it holds 1000uatom")]
+    fn test_assert_balance_mismatch() {
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let robot = MultiTestRobot(&runner);
+        let account = runner.init_account(&[coin(1000, "uatom")]).unwrap();
+
+        robot.assert_balance(account.address(), coin(999, "uatom"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a wasm event with action=reset")]
+    fn test_assert_event_attr_mismatch() {
+        let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+        let robot = MultiTestRobot(&runner);
+        let admin = runner.init_account(&[coin(1000, DEFAULT_COIN_DENOM)]).unwrap();
+        let contract_addr = counter::deploy_test_counter(&runner, &admin).unwrap().address;
+
+        let res = robot
+            .wasm()
+            .execute(&contract_addr, &counter::ExecuteMsg::Increment {}, &[], &admin)
+            .unwrap();
+        robot.assert_event_attr(&res.events, "wasm", "action", "reset");
+    }
 }
 
 #[cfg(feature = "osmosis-test-tube")]
@@ -146,6 +318,19 @@ mod osmosis_robot_tests {
                 99_000_000_000_000_000u128,
             );
     }
+
+    #[test]
+    fn test_fund_account() {
+        let app = OsmosisTestApp::new();
+        let robot = OsmosisTestAppRobot(&app);
+
+        let account = app.init_account(&[]).unwrap();
+
+        robot
+            .fund_account(account.address(), &[Coin::new(1_000u128, "uatom"), Coin::new(1_000u128, "uosmo")])
+            .assert_balance(account.address(), Coin::new(1_000u128, "uatom"))
+            .assert_balance(account.address(), Coin::new(1_000u128, "uosmo"));
+    }
 }
 
 #[cfg(feature = "coreum-test-tube")]
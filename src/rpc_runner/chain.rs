@@ -13,16 +13,20 @@ use config::Config;
 use crate::helpers::block_on;
 
 use super::config::FeeSetting;
+use super::transport::Failover;
 
 #[derive(Debug, Error)]
 pub enum ChainError {
     #[error("{0}")]
     RpcError(#[from] RpcError),
+
+    #[error("At least one RPC endpoint is required")]
+    NoEndpoints,
 }
 
 #[derive(Debug)]
 pub struct Chain {
-    http_client: HttpClient,
+    endpoints: Failover<HttpClient>,
     chain_cfg: ChainConfig,
 }
 
@@ -74,16 +78,37 @@ impl Chain {
     pub fn new(chain_cfg: ChainConfig) -> Result<Self, ChainError> {
         // To run with docker-compose externally
         //let rpc_endpoint="http://localhost:26657".to_string();
-        let http_client = HttpClient::new(chain_cfg.rpc_endpoint.as_str())?;
+        let endpoints = vec![chain_cfg.rpc_endpoint.clone()];
+        Self::with_endpoints(chain_cfg, endpoints)
+    }
+
+    /// Creates a chain connected to the first of the given RPC `endpoints`, which fails over to
+    /// the next endpoint whenever a query fails with a connection error.
+    pub fn with_endpoints(chain_cfg: ChainConfig, endpoints: Vec<String>) -> Result<Self, ChainError> {
+        if endpoints.is_empty() {
+            return Err(ChainError::NoEndpoints);
+        }
+        let transports = endpoints
+            .into_iter()
+            .map(|endpoint| {
+                let client = HttpClient::new(endpoint.as_str())?;
+                Ok((endpoint, client))
+            })
+            .collect::<Result<Vec<_>, RpcError>>()?;
 
         Ok(Self {
-            http_client,
+            endpoints: Failover::new(transports),
             chain_cfg,
         })
     }
 
+    /// Returns the client of the endpoint that is currently in use.
     pub fn client(&self) -> &HttpClient {
-        &self.http_client
+        self.endpoints.current()
+    }
+
+    pub(crate) fn endpoints(&self) -> &Failover<HttpClient> {
+        &self.endpoints
     }
 
     pub fn chain_cfg(&self) -> &ChainConfig {
@@ -91,7 +116,7 @@ impl Chain {
     }
 
     pub fn current_height(&self) -> Result<u64, RpcError> {
-        block_on(self.http_client.latest_block()).map(|res| res.block.header.height.into())
+        block_on(self.client().latest_block()).map(|res| res.block.header.height.into())
     }
 
     pub fn wait(&self, n_block: u64) -> Result<(), RpcError> {
@@ -3,6 +3,7 @@ use cosmwasm_std::Coin;
 use serde::{Deserialize, Serialize};
 
 use super::chain::ChainConfig;
+use super::error::RpcRunnerError;
 use crate::helpers::get_current_working_dir;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -61,4 +62,75 @@ impl RpcRunnerConfig {
             .unwrap();
         settings.try_deserialize::<Self>().unwrap()
     }
+
+    /// Reads the config from the TOML file at `file`. Returns a descriptive error if the file
+    /// can not be read, a required key is missing or a required value is empty.
+    pub fn from_toml(file: &str) -> Result<Self, RpcRunnerError> {
+        let config = Config::builder()
+            .add_source(config::File::new(file, config::FileFormat::Toml))
+            .build()
+            .and_then(|settings| settings.try_deserialize::<Self>())
+            .map_err(|e| RpcRunnerError::Generic(format!("Invalid RpcRunner config file {}: {}", file, e)))?;
+
+        let required = [
+            ("chain_config.chain_id", &config.chain_config.chain_id),
+            ("chain_config.rpc_endpoint", &config.chain_config.rpc_endpoint),
+            ("funding_account_mnemonic", &config.funding_account_mnemonic),
+        ];
+        if let Some((key, _)) = required.iter().find(|(_, value)| value.trim().is_empty()) {
+            return Err(RpcRunnerError::Generic(format!(
+                "Invalid RpcRunner config file {}: {} must not be empty",
+                file, key
+            )));
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn write_config(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("cw-it-rpc-runner-{}-{}.toml", name, std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn from_toml_missing_key() {
+        let contents = r#"
+            funding_account_mnemonic = "notice oak worry limit wrap speak medal online prefer cluster roof addict"
+
+            [chain_config]
+            name            = "osmosis"
+            prefix          = "osmo"
+            denom           = "uosmo"
+            gas_price       = 1000000
+            gas_adjustment  = 1.2
+            derivation_path = "m/44'/118'/0'/0/0"
+            rpc_endpoint    = "http://localhost:26657"
+            grpc_endpoint   = "http://localhost:9090"
+        "#;
+        let path = write_config("missing-key", contents);
+
+        let err = RpcRunnerConfig::from_toml(&path).unwrap_err();
+        assert!(err.to_string().contains("chain_id"), "Unexpected error: {}", err);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn from_toml_empty_value() {
+        let contents = fs::read_to_string("configs/rpc_runner.toml").unwrap();
+        let path = write_config("empty-value", &contents.replace("\"http://localhost:26657\"", "\"\""));
+
+        let err = RpcRunnerConfig::from_toml(&path).unwrap_err();
+        assert!(err.to_string().ends_with("chain_config.rpc_endpoint must not be empty"));
+
+        fs::remove_file(path).unwrap();
+    }
 }
@@ -5,3 +5,85 @@ pub fn mnemonic_to_signing_key(
     let seed = bip32::Mnemonic::new(mnemonic, bip32::Language::English)?.to_seed("");
     cosmrs::crypto::secp256k1::SigningKey::derive_from_path(seed, path)
 }
+
+/// Returns true if `err` is the error the SDK returns for a tx signed with an outdated sequence.
+pub fn is_sequence_mismatch(err: &test_tube::RunnerError) -> bool {
+    let msg = match err {
+        test_tube::RunnerError::ExecuteError { msg } | test_tube::RunnerError::GenericError(msg) => msg,
+        _ => return false,
+    };
+    msg.contains("account sequence mismatch") || msg.contains("incorrect account sequence")
+}
+
+/// Calls `broadcast` and retries it up to `max_retries` times as long as it fails with an account
+/// sequence mismatch. `broadcast` is expected to query the current sequence of the signer and
+/// sign the tx again on every call. Any other error is returned immediately.
+pub fn retry_on_sequence_mismatch<T>(
+    max_retries: u32,
+    mut broadcast: impl FnMut() -> test_tube::RunnerResult<T>,
+) -> test_tube::RunnerResult<T> {
+    let mut retries = 0;
+    loop {
+        match broadcast() {
+            Err(err) if retries < max_retries && is_sequence_mismatch(&err) => retries += 1,
+            res => return res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use test_tube::RunnerError;
+
+    use super::*;
+
+    fn mismatch() -> RunnerError {
+        RunnerError::ExecuteError {
+            msg: "account sequence mismatch, expected 5, got 4: incorrect account sequence".to_string(),
+        }
+    }
+
+    #[test]
+    fn retries_once_on_sequence_mismatch() {
+        // A mocked broadcast that fails with a sequence mismatch once and then succeeds
+        let calls = Cell::new(0);
+        let res = retry_on_sequence_mismatch(3, || {
+            calls.set(calls.get() + 1);
+            match calls.get() {
+                1 => Err(mismatch()),
+                _ => Ok("tx hash"),
+            }
+        });
+
+        assert_eq!(res.unwrap(), "tx hash");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let calls = Cell::new(0);
+        let res: test_tube::RunnerResult<()> = retry_on_sequence_mismatch(2, || {
+            calls.set(calls.get() + 1);
+            Err(mismatch())
+        });
+
+        assert!(is_sequence_mismatch(&res.unwrap_err()));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn does_not_retry_other_errors() {
+        let calls = Cell::new(0);
+        let res: test_tube::RunnerResult<()> = retry_on_sequence_mismatch(2, || {
+            calls.set(calls.get() + 1);
+            Err(RunnerError::ExecuteError {
+                msg: "insufficient funds".to_string(),
+            })
+        });
+
+        assert!(res.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -14,5 +14,6 @@ pub mod config;
 pub mod error;
 mod helpers;
 mod runner;
+mod transport;
 
 pub use runner::*;
@@ -5,8 +5,10 @@ use anyhow::bail;
 use cosmrs::crypto::secp256k1;
 use cosmrs::proto::cosmos::auth::v1beta1::BaseAccount;
 use cosmrs::proto::cosmos::auth::v1beta1::{QueryAccountRequest, QueryAccountResponse};
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
 use cosmrs::tendermint::Time;
 use cosmwasm_std::{from_json, Coin, ContractResult, Empty, Querier, QuerierResult, QueryRequest, SystemResult, WasmQuery};
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
 use osmosis_std::types::cosmwasm::wasm::v1::{QuerySmartContractStateRequest, QuerySmartContractStateResponse};
 use test_tube::{
     account::FeeSetting, Account, DecodeError, EncodeError, Module, Runner, RunnerError, RunnerExecuteResult, RunnerResult, SigningAccount,
@@ -17,8 +19,12 @@ use super::chain::Chain;
 use super::config::RpcRunnerConfig;
 use super::error::RpcRunnerError;
 use super::helpers;
-use crate::helpers::{bank_send, block_on};
-use crate::traits::CwItRunner;
+use super::transport::{self, AbciTransport};
+use crate::helpers::{
+    bank_send, block_on, execute_contract, migrate_contract, query_account_info, query_code_hash, query_denom_creation_fee,
+    query_denom_metadata, query_is_contract,
+};
+use crate::traits::{CwItResponse, CwItRunner};
 use crate::ContractType;
 
 use cosmrs::rpc::endpoint::abci_query::AbciQuery;
@@ -28,11 +34,21 @@ use cosmrs::tx::{self, Raw};
 use cosmrs::tx::{Fee, SignerInfo};
 use cosmrs::AccountId;
 use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The default number of times a tx is retried when it fails with an account sequence mismatch.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
 
 pub struct RpcRunner {
     chain: Chain,
     funding_account: SigningAccount,
     pub config: RpcRunnerConfig,
+    /// The number of times a tx is signed again with the current sequence of the signer and
+    /// rebroadcast when it fails with an account sequence mismatch, e.g. because of concurrent txs.
+    pub max_retries: u32,
+    /// Replaces the endpoints of the chain in tests, see [`RpcRunner::transport`].
+    #[cfg(test)]
+    mock_transport: Option<Box<dyn AbciTransport>>,
 }
 
 impl RpcRunner {
@@ -59,8 +75,43 @@ impl RpcRunner {
             chain,
             config: rpc_runner_config,
             funding_account,
+            max_retries: DEFAULT_MAX_RETRIES,
+            #[cfg(test)]
+            mock_transport: None,
         })
     }
+
+    /// Like [`Runner::query`], but queries the state at the past block `height` instead of the
+    /// latest block. Errors if the node has already pruned the state at that height.
+    pub fn query_at_height<Q, R>(&self, path: &str, msg: &Q, height: u64) -> RunnerResult<R>
+    where
+        Q: test_tube::cosmrs::proto::prost::Message,
+        R: test_tube::cosmrs::proto::prost::Message + Default,
+    {
+        transport::abci_query(self.transport(), path, msg, Some(height))
+    }
+
+    /// Sets the RPC endpoints to connect to, in order of preference. Queries and transactions fail
+    /// over to the next endpoint when the current one can not be reached, so a flaky endpoint
+    /// doesn't abort the whole test run. The first endpoint replaces the `rpc_endpoint` of the config.
+    pub fn with_endpoints(mut self, endpoints: Vec<String>) -> Result<Self, RpcRunnerError> {
+        let chain_config = self.chain.chain_cfg().clone();
+        self.chain = Chain::with_endpoints(chain_config, endpoints.clone())?;
+        self.config.chain_config.rpc_endpoint = endpoints[0].clone();
+        Ok(self)
+    }
+
+    /// Sets the number of retries on account sequence mismatches, see [`RpcRunner::max_retries`].
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Creates a new RpcRunner from the TOML config file at `path`. See
+    /// [`RpcRunnerConfig::from_toml`].
+    pub fn from_config_file(path: &str) -> Result<Self, RpcRunnerError> {
+        Self::new(RpcRunnerConfig::from_toml(path)?)
+    }
 }
 
 impl Querier for RpcRunner {
@@ -88,6 +139,16 @@ impl Querier for RpcRunner {
 }
 
 impl RpcRunner {
+    /// Returns the transport ABCI queries and transactions are sent through, i.e. the endpoints of
+    /// the chain.
+    fn transport(&self) -> &dyn AbciTransport {
+        #[cfg(test)]
+        if let Some(transport) = &self.mock_transport {
+            return transport.as_ref();
+        }
+        self.chain.endpoints()
+    }
+
     fn create_signed_tx<I>(&self, msgs: I, signer: &SigningAccount, fee: Fee) -> RunnerResult<Vec<u8>>
     where
         I: IntoIterator<Item = cosmrs::Any>,
@@ -182,7 +243,7 @@ impl RpcRunner {
     fn abci_query<T: Message>(&self, req: T, path: &str) -> RunnerResult<AbciQuery> {
         let mut buf = Vec::with_capacity(req.encoded_len());
         req.encode(&mut buf).map_err(EncodeError::ProtoEncodeError)?;
-        Ok(block_on(self.chain.client().abci_query(Some(path.to_string()), buf, None, false))?)
+        Ok(self.transport().abci_query(path, buf, None)?)
     }
 }
 
@@ -237,15 +298,20 @@ impl Runner<'_> for RpcRunner {
             25_000_000u64,
         );
 
-        let tx_raw = self.create_signed_tx(msgs, signer, fee)?;
+        // The tx is signed again on every attempt, so that it uses the current sequence of the signer
+        let tx_commit_response = helpers::retry_on_sequence_mismatch(self.max_retries, || {
+            let tx_raw = self.create_signed_tx(msgs.clone(), signer, fee.clone())?;
 
-        let tx_commit_response: TxCommitResponse = block_on(self.chain.client().broadcast_tx_commit(tx_raw))?;
+            let tx_commit_response: TxCommitResponse = self.transport().broadcast_tx_commit(tx_raw)?;
+
+            if tx_commit_response.check_tx.code.is_err() {
+                return Err(RunnerError::ExecuteError {
+                    msg: tx_commit_response.check_tx.log,
+                });
+            }
+            Ok(tx_commit_response)
+        })?;
 
-        if tx_commit_response.check_tx.code.is_err() {
-            return Err(RunnerError::ExecuteError {
-                msg: tx_commit_response.check_tx.log,
-            });
-        }
         if tx_commit_response.tx_result.code.is_err() {
             return Err(RunnerError::ExecuteError {
                 msg: tx_commit_response.tx_result.log,
@@ -259,20 +325,7 @@ impl Runner<'_> for RpcRunner {
         Q: test_tube::cosmrs::proto::prost::Message,
         R: test_tube::cosmrs::proto::prost::Message + Default,
     {
-        let mut base64_query_msg_bytes = Vec::with_capacity(msg.encoded_len());
-        msg.encode(&mut base64_query_msg_bytes).map_err(EncodeError::ProtoEncodeError)?;
-
-        let res = block_on(
-            self.chain
-                .client()
-                .abci_query(Some(path.to_string()), base64_query_msg_bytes, None, false),
-        )?;
-
-        if res.code != cosmrs::tendermint::abci::Code::Ok {
-            return Err(RunnerError::QueryError { msg: "error".to_string() });
-        }
-
-        Ok(R::decode(res.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?)
+        transport::abci_query(self.transport(), path, msg, None)
     }
 
     fn execute_tx(&self, _tx_bytes: &[u8]) -> RunnerResult<test_tube::cosmrs::proto::tendermint::v0_37::abci::ResponseDeliverTx> {
@@ -335,6 +388,226 @@ impl<'a> CwItRunner<'a> for RpcRunner {
             .unwrap()
             .as_nanos() as u64
     }
+
+    fn query_block_height(&self) -> Result<u64, anyhow::Error> {
+        Ok(self.chain.current_height()?)
+    }
+
+    fn migrate_contract(&self, contract: &str, new_code_id: u64, msg: &[u8], signer: &SigningAccount) -> Result<CwItResponse, anyhow::Error> {
+        Ok(migrate_contract(self, contract, new_code_id, msg, signer)?)
+    }
+
+    fn query_code_hash(&self, code_id: u64) -> Result<Vec<u8>, anyhow::Error> {
+        Ok(query_code_hash(self, code_id)?)
+    }
+
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, anyhow::Error> {
+        Ok(query_denom_creation_fee(self)?)
+    }
+
+    fn is_contract(&self, address: &str) -> Result<bool, anyhow::Error> {
+        Ok(query_is_contract(self, address)?)
+    }
+
+    fn account_info(&self, address: &str) -> Result<(u64, u64), anyhow::Error> {
+        Ok(query_account_info(self, address)?)
+    }
+
+    fn execute_contract<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, anyhow::Error> {
+        Ok(execute_contract(self, contract, msg, funds, signer)?)
+    }
+
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        funds: &[Coin],
+        admin: Option<&str>,
+        label: &str,
+        signer: &SigningAccount,
+    ) -> Result<String, anyhow::Error> {
+        let res = Wasm::new(self).instantiate(code_id, msg, admin, Some(label), funds, signer)?;
+        Ok(res.data.address)
+    }
+
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, contract: &str, query: &Q) -> Result<R, anyhow::Error> {
+        Ok(Wasm::new(self).query(contract, query)?)
+    }
+
+    fn query_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, anyhow::Error> {
+        Ok(query_denom_metadata(self, denom)?)
+    }
+
+    fn fund_account(&self, address: &str, coins: &[Coin]) -> Result<(), anyhow::Error> {
+        bank_send(self, &self.funding_account, address, coins.to_vec())
+            .map_err(|e| anyhow::anyhow!("Funding of account {} failed. Error: {}", address, e))?;
+        Ok(())
+    }
+
+    fn simulate(&self, _msgs: Vec<cosmrs::Any>, _signer: &SigningAccount) -> Result<GasInfo, anyhow::Error> {
+        bail!("Simulating transactions is not supported by the RpcRunner")
+    }
+
+    fn fee_denom(&self) -> Result<&str, anyhow::Error> {
+        Ok(self.chain.chain_cfg().denom())
+    }
+
+    fn chain_id(&self) -> Result<String, anyhow::Error> {
+        Ok(self.chain.chain_cfg().chain_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use cosmrs::proto::cosmos::bank::v1beta1::{MsgSend, MsgSendResponse};
+    use cosmrs::proto::cosmos::base::abci::v1beta1::{MsgData, TxMsgData};
+    use cosmrs::rpc::error::Error as RpcError;
+    use cosmrs::tendermint::abci::Code;
+    use cosmrs::tendermint::block::Height;
+
+    use super::*;
+
+    #[test]
+    fn from_config_file() {
+        let runner = RpcRunner::from_config_file("configs/rpc_runner.toml").unwrap();
+
+        assert_eq!(runner.config.chain_config.chain_id, "localosmosis");
+        assert_eq!(runner.config.chain_config.rpc_endpoint, "http://localhost:26657");
+        assert_eq!(runner.chain.chain_cfg().chain_id, "localosmosis");
+        assert_eq!(runner.fee_denom().unwrap(), "uosmo");
+        assert_eq!(runner.chain_id().unwrap(), "localosmosis");
+        assert!(runner.funding_account.address().starts_with("osmo"));
+        assert_eq!(runner.max_retries, DEFAULT_MAX_RETRIES);
+        assert_eq!(runner.with_max_retries(5).max_retries, 5);
+    }
+
+    #[test]
+    fn with_endpoints() {
+        let runner = RpcRunner::from_config_file("configs/rpc_runner.toml")
+            .unwrap()
+            .with_endpoints(vec!["http://first:26657".to_string(), "http://second:26657".to_string()])
+            .unwrap();
+
+        assert_eq!(runner.config.chain_config.rpc_endpoint, "http://first:26657");
+        assert_eq!(runner.chain.endpoints().current_endpoint(), "http://first:26657");
+        assert!(RpcRunner::from_config_file("configs/rpc_runner.toml")
+            .unwrap()
+            .with_endpoints(vec![])
+            .is_err());
+    }
+
+    /// A node answering account queries with the current sequence of the signer, which rejects
+    /// the first `mismatches` txs with an account sequence mismatch, as if another tx of the
+    /// signer was committed in the meantime.
+    struct MockNode {
+        sequence: Cell<u64>,
+        mismatches: Cell<u32>,
+        txs: Rc<RefCell<Vec<Vec<u8>>>>,
+    }
+
+    impl AbciTransport for MockNode {
+        fn abci_query(&self, path: &str, _data: Vec<u8>, _height: Option<Height>) -> Result<AbciQuery, RpcError> {
+            assert_eq!(path, "/cosmos.auth.v1beta1.Query/Account");
+            let account = BaseAccount {
+                account_number: 1,
+                sequence: self.sequence.get(),
+                ..Default::default()
+            };
+            let res = QueryAccountResponse {
+                account: Some(cosmrs::Any {
+                    type_url: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+                    value: account.encode_to_vec(),
+                }),
+            };
+            Ok(AbciQuery {
+                value: res.encode_to_vec(),
+                ..Default::default()
+            })
+        }
+
+        #[allow(deprecated)]
+        fn broadcast_tx_commit(&self, tx: Vec<u8>) -> Result<TxCommitResponse, RpcError> {
+            self.txs.borrow_mut().push(tx);
+            let mut res = TxCommitResponse {
+                check_tx: Default::default(),
+                tx_result: Default::default(),
+                hash: Default::default(),
+                height: Default::default(),
+            };
+            if self.mismatches.get() > 0 {
+                self.mismatches.set(self.mismatches.get() - 1);
+                self.sequence.set(self.sequence.get() + 1);
+                res.check_tx.code = Code::Err(32u32.try_into().unwrap());
+                res.check_tx.log = "account sequence mismatch, expected 1, got 0: incorrect account sequence".to_string();
+            } else {
+                let response = MsgSendResponse {}.encode_to_vec();
+                res.tx_result.data = TxMsgData {
+                    data: vec![MsgData {
+                        msg_type: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                        data: response.clone(),
+                    }],
+                    msg_responses: vec![cosmrs::Any {
+                        type_url: "/cosmos.bank.v1beta1.MsgSendResponse".to_string(),
+                        value: response,
+                    }],
+                }
+                .encode_to_vec()
+                .into();
+            }
+            Ok(res)
+        }
+    }
+
+    #[test]
+    fn execute_retries_on_sequence_mismatch() {
+        let txs = Rc::new(RefCell::new(vec![]));
+        let mut runner = RpcRunner::from_config_file("configs/rpc_runner.toml").unwrap();
+        runner.mock_transport = Some(Box::new(MockNode {
+            sequence: Cell::new(0),
+            mismatches: Cell::new(1),
+            txs: Rc::clone(&txs),
+        }));
+        let signer = SigningAccount::new(
+            "osmo".to_string(),
+            secp256k1::SigningKey::random(),
+            FeeSetting::Custom {
+                amount: Coin::new(1000, "uosmo"),
+                gas_limit: 200_000,
+            },
+        );
+        let msg = MsgSend {
+            from_address: signer.address(),
+            to_address: signer.address(),
+            amount: vec![],
+        };
+
+        runner
+            .execute_multiple_raw::<MsgSendResponse>(
+                vec![cosmrs::Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: msg.encode_to_vec(),
+                }],
+                &signer,
+            )
+            .unwrap();
+
+        // The tx was signed again with the sequence of the signer after the mismatch
+        let sequences: Vec<u64> = txs
+            .borrow()
+            .iter()
+            .map(|tx| tx::Tx::from_bytes(tx).unwrap().auth_info.signer_infos[0].sequence)
+            .collect();
+        assert_eq!(sequences, vec![0, 1]);
+    }
 }
 
 // Commenting out RPC tests so that CI doesn't break randomly when the RPC endpoint is down
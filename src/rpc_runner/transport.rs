@@ -0,0 +1,375 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeSet;
+use std::io;
+
+use cosmrs::rpc::endpoint::abci_query::AbciQuery;
+use cosmrs::rpc::endpoint::broadcast::tx_commit::Response as TxCommitResponse;
+use cosmrs::rpc::error::{Error as RpcError, ErrorDetail};
+use cosmrs::rpc::{Client, HttpClient};
+use cosmrs::tendermint::abci::Code;
+use cosmrs::tendermint::block::Height;
+use prost::Message;
+use test_tube::{DecodeError, EncodeError, RunnerError, RunnerResult};
+
+use crate::helpers::block_on;
+
+/// The transport ABCI queries and transactions of the [`super::RpcRunner`] are sent through. This
+/// is only abstracted so that the requests sent to the node can be checked in tests.
+pub(crate) trait AbciTransport {
+    /// Sends an ABCI query for `path` with the encoded request `data`, at `height` if given and
+    /// at the latest height otherwise.
+    fn abci_query(&self, path: &str, data: Vec<u8>, height: Option<Height>) -> Result<AbciQuery, RpcError>;
+
+    /// Broadcasts the encoded transaction `tx` and waits until it is committed in a block.
+    fn broadcast_tx_commit(&self, tx: Vec<u8>) -> Result<TxCommitResponse, RpcError>;
+}
+
+impl AbciTransport for HttpClient {
+    fn abci_query(&self, path: &str, data: Vec<u8>, height: Option<Height>) -> Result<AbciQuery, RpcError> {
+        block_on(Client::abci_query(self, Some(path.to_string()), data, height, false))
+    }
+
+    fn broadcast_tx_commit(&self, tx: Vec<u8>) -> Result<TxCommitResponse, RpcError> {
+        block_on(Client::broadcast_tx_commit(self, tx))
+    }
+}
+
+/// A list of endpoints queries and transactions are sent to. Requests go to the endpoint that last
+/// succeeded, and fail over to the next endpoint in order when it fails with a connection error.
+/// Endpoints that failed are marked, and only retried once all unmarked endpoints failed as well.
+/// Errors returned by the node itself are not retried on other endpoints.
+///
+/// Transactions only fail over when the connection was refused before anything was sent. On a
+/// timeout or a dropped connection the transaction may already be in the mempool of the node, and
+/// broadcasting it to another endpoint could execute it twice.
+#[derive(Debug)]
+pub(crate) struct Failover<T> {
+    /// The endpoints with their transports, in order of preference.
+    transports: Vec<(String, T)>,
+    /// The index of the endpoint that is currently in use.
+    current: Cell<usize>,
+    /// The indices of the endpoints whose last query failed with a connection error.
+    failed: RefCell<BTreeSet<usize>>,
+}
+
+impl<T> Failover<T> {
+    /// Creates a new Failover from a non-empty list of **endpoint -> transport** pairs.
+    pub fn new(transports: Vec<(String, T)>) -> Self {
+        assert!(!transports.is_empty(), "At least one endpoint is required");
+        Self {
+            transports,
+            current: Cell::new(0),
+            failed: RefCell::new(BTreeSet::new()),
+        }
+    }
+
+    /// Returns the transport of the endpoint that is currently in use.
+    pub fn current(&self) -> &T {
+        &self.transports[self.current.get()].1
+    }
+
+    /// Returns the endpoint that is currently in use.
+    pub fn current_endpoint(&self) -> &str {
+        &self.transports[self.current.get()].0
+    }
+
+    /// Returns the endpoints that are marked as failed.
+    pub fn failed_endpoints(&self) -> Vec<String> {
+        self.failed.borrow().iter().map(|i| self.transports[*i].0.clone()).collect()
+    }
+}
+
+/// Returns true if `err` is caused by the connection to the node rather than by the node itself.
+fn is_transport_error(err: &RpcError) -> bool {
+    !matches!(err.detail(), ErrorDetail::Response(_))
+}
+
+/// Returns true if `err` means the connection to the node could not be established, so the
+/// request was never sent.
+fn is_connect_error(err: &RpcError) -> bool {
+    match err.detail() {
+        ErrorDetail::Hyper(e) => e.source.is_connect(),
+        ErrorDetail::Io(e) => e.source.kind() == io::ErrorKind::ConnectionRefused,
+        _ => false,
+    }
+}
+
+impl<T> Failover<T> {
+    /// Sends a request with `send`, starting at the current endpoint and failing over to the
+    /// following ones on errors for which `retry` returns true. Endpoints marked as failed are
+    /// tried last.
+    fn send<R>(&self, retry: fn(&RpcError) -> bool, send: impl Fn(&T) -> Result<R, RpcError>) -> Result<R, RpcError> {
+        let start = self.current.get();
+        let (unmarked, marked): (Vec<usize>, Vec<usize>) = (0..self.transports.len())
+            .map(|offset| (start + offset) % self.transports.len())
+            .partition(|i| !self.failed.borrow().contains(i));
+
+        let mut last_err = None;
+        for i in unmarked.into_iter().chain(marked) {
+            match send(&self.transports[i].1) {
+                Ok(res) => {
+                    self.current.set(i);
+                    self.failed.borrow_mut().remove(&i);
+                    return Ok(res);
+                }
+                Err(e) if retry(&e) => {
+                    self.failed.borrow_mut().insert(i);
+                    last_err = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_err.expect("there is at least one endpoint"))
+    }
+}
+
+impl<T: AbciTransport> AbciTransport for Failover<T> {
+    fn abci_query(&self, path: &str, data: Vec<u8>, height: Option<Height>) -> Result<AbciQuery, RpcError> {
+        self.send(is_transport_error, |transport| transport.abci_query(path, data.clone(), height))
+    }
+
+    fn broadcast_tx_commit(&self, tx: Vec<u8>) -> Result<TxCommitResponse, RpcError> {
+        self.send(is_connect_error, |transport| transport.broadcast_tx_commit(tx.clone()))
+    }
+}
+
+/// Returns true if the `log` of a failed ABCI query says the state at the queried height is no
+/// longer available on the node.
+fn is_pruned_height_error(log: &str) -> bool {
+    log.contains("version does not exist") || log.contains("is not available") || log.contains("pruned")
+}
+
+/// Sends the query `msg` for `path` through `transport` and decodes the response, at `height` if
+/// given and at the latest height otherwise.
+pub(crate) fn abci_query<Q, R>(transport: &(impl AbciTransport + ?Sized), path: &str, msg: &Q, height: Option<u64>) -> RunnerResult<R>
+where
+    Q: Message,
+    R: Message + Default,
+{
+    let mut base64_query_msg_bytes = Vec::with_capacity(msg.encoded_len());
+    msg.encode(&mut base64_query_msg_bytes).map_err(EncodeError::ProtoEncodeError)?;
+
+    let query_height = height
+        .map(Height::try_from)
+        .transpose()
+        .map_err(|e| RunnerError::QueryError { msg: e.to_string() })?;
+    let res = transport.abci_query(path, base64_query_msg_bytes, query_height)?;
+
+    if res.code != Code::Ok {
+        let msg = match height {
+            Some(height) if is_pruned_height_error(&res.log) => {
+                format!(
+                    "State at height {} is not available, it has been pruned by the node: {}",
+                    height, res.log
+                )
+            }
+            _ => res.log,
+        };
+        return Err(RunnerError::QueryError { msg });
+    }
+
+    Ok(R::decode(res.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+    use super::*;
+
+    /// A transport recording the heights of all queries and all broadcast transactions,
+    /// answering queries with `response`. If `unreachable` is set, every request fails as if the
+    /// node could not be connected to, and if `timeout` is set, as if the node did not answer in
+    /// time.
+    #[derive(Default)]
+    struct MockTransport {
+        heights: RefCell<Vec<Option<Height>>>,
+        txs: RefCell<Vec<Vec<u8>>>,
+        response: AbciQuery,
+        unreachable: bool,
+        timeout: bool,
+    }
+
+    impl MockTransport {
+        fn check_connection(&self) -> Result<(), RpcError> {
+            if self.unreachable {
+                return Err(RpcError::io(io::Error::new(io::ErrorKind::ConnectionRefused, "connection refused")));
+            }
+            if self.timeout {
+                return Err(RpcError::timeout(Duration::from_secs(30)));
+            }
+            Ok(())
+        }
+    }
+
+    impl AbciTransport for MockTransport {
+        fn abci_query(&self, _path: &str, _data: Vec<u8>, height: Option<Height>) -> Result<AbciQuery, RpcError> {
+            self.heights.borrow_mut().push(height);
+            self.check_connection()?;
+            Ok(self.response.clone())
+        }
+
+        fn broadcast_tx_commit(&self, tx: Vec<u8>) -> Result<TxCommitResponse, RpcError> {
+            self.txs.borrow_mut().push(tx);
+            self.check_connection()?;
+            Ok(TxCommitResponse {
+                check_tx: Default::default(),
+                tx_result: Default::default(),
+                hash: Default::default(),
+                height: Default::default(),
+            })
+        }
+    }
+
+    const BALANCE_PATH: &str = "/cosmos.bank.v1beta1.Query/Balance";
+
+    fn balance_request() -> QueryBalanceRequest {
+        QueryBalanceRequest {
+            address: "osmo1address".to_string(),
+            denom: "uosmo".to_string(),
+        }
+    }
+
+    #[test]
+    fn query_attaches_height() {
+        let balance = QueryBalanceResponse {
+            balance: Some(ProtoCoin {
+                denom: "uosmo".to_string(),
+                amount: "100".to_string(),
+            }),
+        };
+        let transport = MockTransport {
+            response: AbciQuery {
+                value: balance.encode_to_vec(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let res: QueryBalanceResponse = abci_query(&transport, BALANCE_PATH, &balance_request(), Some(1234)).unwrap();
+        assert_eq!(res, balance);
+        let _: QueryBalanceResponse = abci_query(&transport, BALANCE_PATH, &balance_request(), None).unwrap();
+
+        assert_eq!(*transport.heights.borrow(), vec![Some(Height::from(1234u32)), None]);
+    }
+
+    #[test]
+    fn query_pruned_height() {
+        let transport = MockTransport {
+            response: AbciQuery {
+                code: Code::Err(26u32.try_into().unwrap()),
+                log: "failed to load state at height 10; version does not exist (latest height: 5000)".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let err = abci_query::<_, QueryBalanceResponse>(&transport, BALANCE_PATH, &balance_request(), Some(10)).unwrap_err();
+        match err {
+            RunnerError::QueryError { msg } => assert!(msg.starts_with("State at height 10 is not available"), "{}", msg),
+            e => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn failover_to_second_endpoint() {
+        let balance = QueryBalanceResponse {
+            balance: Some(ProtoCoin {
+                denom: "uosmo".to_string(),
+                amount: "100".to_string(),
+            }),
+        };
+        let failover = Failover::new(vec![
+            (
+                "http://unreachable:26657".to_string(),
+                MockTransport {
+                    unreachable: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "http://reachable:26657".to_string(),
+                MockTransport {
+                    response: AbciQuery {
+                        value: balance.encode_to_vec(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let res: QueryBalanceResponse = abci_query(&failover, BALANCE_PATH, &balance_request(), None).unwrap();
+        assert_eq!(res, balance);
+        assert_eq!(failover.current_endpoint(), "http://reachable:26657");
+        assert_eq!(failover.failed_endpoints(), vec!["http://unreachable:26657".to_string()]);
+
+        // Later queries go straight to the endpoint that works
+        let _: QueryBalanceResponse = abci_query(&failover, BALANCE_PATH, &balance_request(), None).unwrap();
+        assert_eq!(failover.transports[0].1.heights.borrow().len(), 1);
+        assert_eq!(failover.transports[1].1.heights.borrow().len(), 2);
+    }
+
+    #[test]
+    fn failover_broadcast_tx() {
+        let unreachable = || MockTransport {
+            unreachable: true,
+            ..Default::default()
+        };
+        let failover = Failover::new(vec![
+            ("a".to_string(), unreachable()),
+            ("b".to_string(), unreachable()),
+            ("c".to_string(), MockTransport::default()),
+        ]);
+        failover.failed.borrow_mut().insert(1);
+
+        // The first endpoint is dead, and the second one is marked as failed, so it is skipped
+        failover.broadcast_tx_commit(b"tx".to_vec()).unwrap();
+        assert_eq!(failover.current_endpoint(), "c");
+        assert_eq!(failover.failed_endpoints(), vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(failover.transports[0].1.txs.borrow().len(), 1);
+        assert!(failover.transports[1].1.txs.borrow().is_empty());
+        assert_eq!(*failover.transports[2].1.txs.borrow(), vec![b"tx".to_vec()]);
+    }
+
+    #[test]
+    fn no_failover_of_broadcast_tx_on_timeout() {
+        let failover = Failover::new(vec![
+            (
+                "a".to_string(),
+                MockTransport {
+                    timeout: true,
+                    ..Default::default()
+                },
+            ),
+            ("b".to_string(), MockTransport::default()),
+        ]);
+
+        // The transaction may have reached the node, so it is not sent to the next endpoint
+        let err = failover.broadcast_tx_commit(b"tx".to_vec()).unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::Timeout(_)));
+        assert_eq!(failover.current_endpoint(), "a");
+        assert!(failover.transports[1].1.txs.borrow().is_empty());
+
+        // Queries are safe to send again
+        let _: QueryBalanceResponse = abci_query(&failover, BALANCE_PATH, &balance_request(), None).unwrap();
+        assert_eq!(failover.current_endpoint(), "b");
+    }
+
+    #[test]
+    fn failover_all_endpoints_unreachable() {
+        let unreachable = || MockTransport {
+            unreachable: true,
+            ..Default::default()
+        };
+        let failover = Failover::new(vec![("a".to_string(), unreachable()), ("b".to_string(), unreachable())]);
+
+        assert!(abci_query::<_, QueryBalanceResponse>(&failover, BALANCE_PATH, &balance_request(), None).is_err());
+        assert_eq!(failover.failed_endpoints(), vec!["a".to_string(), "b".to_string()]);
+    }
+}
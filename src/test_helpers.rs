@@ -1,3 +1,4 @@
+#[cfg(test)]
 pub mod test_contract {
     use std::fmt;
 
@@ -35,6 +36,16 @@ pub mod test_contract {
 
 pub mod counter {
     use cosmwasm_schema::{cw_serde, QueryResponses};
+    use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult};
+    use cw_multi_test::{Contract, ContractWrapper};
+    use cw_storage_plus::Item;
+    #[cfg(not(feature = "coreum"))]
+    use test_tube::{Account, SigningAccount};
+
+    #[cfg(not(feature = "coreum"))]
+    use crate::traits::{CwItResponse, CwItRunner};
+    #[cfg(not(feature = "coreum"))]
+    use crate::ContractType;
 
     #[cw_serde]
     pub struct InstantiateMsg {
@@ -61,5 +72,273 @@ pub mod counter {
         pub count: i32,
     }
 
+    #[cw_serde]
+    pub struct MigrateMsg {
+        /// Overwrites the stored count when set, otherwise the count is left untouched.
+        pub count: Option<i32>,
+    }
+
+    #[cfg(test)]
     pub const WASM_PATH: &str = "artifacts/counter.wasm";
+
+    const COUNT: Item<i32> = Item::new("count");
+
+    fn instantiate(deps: DepsMut, _env: Env, _info: MessageInfo, msg: InstantiateMsg) -> StdResult<Response> {
+        COUNT.save(deps.storage, &msg.count)?;
+        Ok(Response::new().add_attribute("action", "instantiate"))
+    }
+
+    fn execute(deps: DepsMut, _env: Env, _info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::Increment {} => {
+                COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+                Ok(Response::new().add_attribute("action", "increment"))
+            }
+            ExecuteMsg::Reset { count } => {
+                COUNT.save(deps.storage, &count)?;
+                Ok(Response::new().add_attribute("action", "reset"))
+            }
+        }
+    }
+
+    fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::GetCount {} => to_json_binary(&GetCountResponse {
+                count: COUNT.load(deps.storage)?,
+            }),
+        }
+    }
+
+    fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+        if let Some(count) = msg.count {
+            COUNT.save(deps.storage, &count)?;
+        }
+        Ok(Response::new().add_attribute("action", "migrate"))
+    }
+
+    /// A multi-test version of the counter contract found at `artifacts/counter.wasm`, with an
+    /// added migrate entry point.
+    pub fn contract() -> Box<dyn Contract<Empty, Empty>> {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
+    }
+
+    /// A client for a counter contract deployed with [`deploy_test_counter`].
+    #[cfg(not(feature = "coreum"))]
+    pub struct CounterClient<'a, R> {
+        pub runner: &'a R,
+        pub address: String,
+    }
+
+    #[cfg(not(feature = "coreum"))]
+    impl<'a, R: CwItRunner<'a>> CounterClient<'a, R> {
+        /// Increments the count by one.
+        pub fn increment(&self, signer: &SigningAccount) -> Result<CwItResponse, anyhow::Error> {
+            self.runner.execute_contract(&self.address, &ExecuteMsg::Increment {}, &[], signer)
+        }
+
+        /// Sets the count to `count`.
+        pub fn reset(&self, count: i32, signer: &SigningAccount) -> Result<CwItResponse, anyhow::Error> {
+            self.runner
+                .execute_contract(&self.address, &ExecuteMsg::Reset { count }, &[], signer)
+        }
+
+        /// Returns the current count.
+        pub fn count(&self) -> Result<i32, anyhow::Error> {
+            let res: GetCountResponse = self.runner.query_wasm_smart(&self.address, &QueryMsg::GetCount {})?;
+            Ok(res.count)
+        }
+    }
+
+    /// Stores the multi-test version of the counter contract and instantiates it with a count of
+    /// zero, with `signer` as its admin. As the multi-test version is stored, this only works with
+    /// runners supporting [`ContractType::MultiTestContract`].
+    #[cfg(not(feature = "coreum"))]
+    pub fn deploy_test_counter<'a, R: CwItRunner<'a>>(
+        runner: &'a R,
+        signer: &SigningAccount,
+    ) -> Result<CounterClient<'a, R>, anyhow::Error> {
+        let code_id = runner.store_code(ContractType::MultiTestContract(contract()), signer)?;
+        let address = runner.instantiate_contract(
+            code_id,
+            &InstantiateMsg { count: 0 },
+            &[],
+            Some(&signer.address()),
+            "counter",
+            signer,
+        )?;
+
+        Ok(CounterClient { runner, address })
+    }
+
+    #[cfg(all(test, not(feature = "coreum")))]
+    mod tests {
+        use crate::multi_test::MultiTestRunner;
+        use crate::traits::DEFAULT_ADDRESS_PREFIX;
+
+        use super::*;
+
+        #[test]
+        fn deploy_and_increment() {
+            let runner = MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX);
+            let signer = runner.init_default_account().unwrap();
+
+            let counter = deploy_test_counter(&runner, &signer).unwrap();
+            assert_eq!(counter.count().unwrap(), 0);
+
+            counter.increment(&signer).unwrap();
+            counter.increment(&signer).unwrap();
+            assert_eq!(counter.count().unwrap(), 2);
+
+            counter.reset(42, &signer).unwrap();
+            assert_eq!(counter.count().unwrap(), 42);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod vault {
+    use cosmwasm_schema::{cw_serde, QueryResponses};
+    use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult, Uint128};
+    use cw_multi_test::{Contract, ContractWrapper};
+    use cw_storage_plus::{Item, Map};
+
+    #[cw_serde]
+    pub struct InstantiateMsg {
+        /// The only denom the vault accepts deposits of.
+        pub denom: String,
+    }
+
+    #[cw_serde]
+    pub enum ExecuteMsg {
+        Deposit {},
+    }
+
+    #[cw_serde]
+    #[derive(QueryResponses)]
+    pub enum QueryMsg {
+        #[returns(Uint128)]
+        Deposited { address: String },
+    }
+
+    const DENOM: Item<String> = Item::new("denom");
+    const DEPOSITS: Map<&str, Uint128> = Map::new("deposits");
+
+    fn instantiate(deps: DepsMut, _env: Env, _info: MessageInfo, msg: InstantiateMsg) -> StdResult<Response> {
+        DENOM.save(deps.storage, &msg.denom)?;
+        Ok(Response::new())
+    }
+
+    fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::Deposit {} => {
+                let denom = DENOM.load(deps.storage)?;
+                let amount = info.funds.iter().find(|c| c.denom == denom).map(|c| c.amount).unwrap_or_default();
+                if amount.is_zero() {
+                    return Err(StdError::generic_err("Deposit amount must be greater than zero"));
+                }
+
+                DEPOSITS.update(deps.storage, info.sender.as_str(), |deposited| -> StdResult<_> {
+                    Ok(deposited.unwrap_or_default() + amount)
+                })?;
+                Ok(Response::new().add_attribute("action", "deposit"))
+            }
+        }
+    }
+
+    fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::Deposited { address } => to_json_binary(&DEPOSITS.may_load(deps.storage, &address)?.unwrap_or_default()),
+        }
+    }
+
+    /// A minimal vault contract accepting deposits of a single denom, which rejects deposits
+    /// without a positive amount.
+    pub fn contract() -> Box<dyn Contract<Empty, Empty>> {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+    }
+}
+
+#[cfg(test)]
+pub mod reply_catcher {
+    use cosmwasm_schema::{cw_serde, QueryResponses};
+    use cosmwasm_std::{
+        to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, ReplyOn, Response, StdResult, SubMsg, SubMsgResult, WasmMsg,
+    };
+    use cw_multi_test::{Contract, ContractWrapper};
+    use cw_storage_plus::Item;
+
+    #[cw_serde]
+    pub struct InstantiateMsg {}
+
+    #[cw_serde]
+    pub enum ExecuteMsg {
+        /// Dispatches `msg` as a submessage replying on error, so that its failure is caught.
+        Dispatch { msg: WasmMsg },
+        /// Dispatches `msg` as a submessage with the given reply mode.
+        DispatchWith { msg: WasmMsg, reply_on: ReplyOn },
+    }
+
+    #[cw_serde]
+    #[derive(QueryResponses)]
+    pub enum QueryMsg {
+        #[returns(Vec<String>)]
+        CaughtErrors {},
+        /// Returns the number of times the reply handler was called.
+        #[returns(u64)]
+        Replies {},
+    }
+
+    /// The attribute the reply handler emits the caught errors under.
+    pub const CAUGHT_ERROR_ATTR: &str = "caught_error";
+
+    const REPLY_ID: u64 = 1;
+    const CAUGHT_ERRORS: Item<Vec<String>> = Item::new("caught_errors");
+    const REPLIES: Item<u64> = Item::new("replies");
+
+    fn instantiate(deps: DepsMut, _env: Env, _info: MessageInfo, _msg: InstantiateMsg) -> StdResult<Response> {
+        CAUGHT_ERRORS.save(deps.storage, &vec![])?;
+        REPLIES.save(deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::Dispatch { msg } => Ok(Response::new()
+                .add_submessage(SubMsg::reply_on_error(msg, REPLY_ID))
+                .add_attribute("action", "dispatch")),
+            ExecuteMsg::DispatchWith { msg, reply_on } => {
+                let mut submsg = SubMsg::new(msg);
+                submsg.id = REPLY_ID;
+                submsg.reply_on = reply_on;
+                Ok(Response::new().add_submessage(submsg).add_attribute("action", "dispatch"))
+            }
+        }
+    }
+
+    fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+        REPLIES.update(deps.storage, |replies| -> StdResult<_> { Ok(replies + 1) })?;
+        match msg.result {
+            SubMsgResult::Err(err) => {
+                CAUGHT_ERRORS.update(deps.storage, |mut errors| -> StdResult<_> {
+                    errors.push(err.clone());
+                    Ok(errors)
+                })?;
+                Ok(Response::new().add_attribute(CAUGHT_ERROR_ATTR, err))
+            }
+            SubMsgResult::Ok(_) => Ok(Response::new()),
+        }
+    }
+
+    fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::CaughtErrors {} => to_json_binary(&CAUGHT_ERRORS.load(deps.storage)?),
+            QueryMsg::Replies {} => to_json_binary(&REPLIES.load(deps.storage)?),
+        }
+    }
+
+    /// A contract dispatching wasm messages as submessages, which counts the replies it receives,
+    /// and records the errors of the failed submessages and emits them under [`CAUGHT_ERROR_ATTR`].
+    pub fn contract() -> Box<dyn Contract<Empty, Empty>> {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_reply(reply))
+    }
 }
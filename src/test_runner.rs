@@ -1,6 +1,11 @@
-use crate::{traits::CwItRunner, ContractType, MultiTestStargateBound};
+use crate::{
+    traits::{CwItResponse, CwItRunner},
+    ContractType, MultiTestStargateBound,
+};
 use anyhow::Result as AnyResult;
-use cosmwasm_std::{to_json_binary, StdResult, WasmQuery};
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
+use cosmwasm_std::{to_json_binary, Coin, StdResult};
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
 use prost::Message;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
@@ -84,27 +89,50 @@ where
     }
 }
 
+impl OwnedTestRunner {
+    /// Returns the names accepted by [`OwnedTestRunner::from_str`] for the enabled features. The
+    /// `RpcRunner` names are not included, as it can not be created from a name.
+    pub fn valid_names() -> Vec<&'static str> {
+        #[allow(unused_mut)]
+        let mut names = vec![];
+        #[cfg(feature = "osmosis-test-tube")]
+        names.extend(["osmosis-test-app", "osmosis-test-tube"]);
+        #[cfg(feature = "coreum-test-tube")]
+        names.extend(["coreum-test-app", "coreum-test-tube"]);
+        #[cfg(feature = "multi-test")]
+        names.extend(["multi-test"]);
+        names
+    }
+}
+
 impl FromStr for OwnedTestRunner {
     type Err = String;
 
     /// Returns a TestRunner from a string, which is the name of the runner. Useful for deciding
-    /// which runner to use base on an env var or similar.
+    /// which runner to use base on an env var or similar. Besides the names returned by `Display`,
+    /// the test-tube runners also accept the name of their feature, e.g. "osmosis-test-tube".
     ///
     /// NB: `MultiTestRunner` will use the "osmo" or "core" address prefix.
     /// `RpcRunner` is not supported in this function, as it requires a config file and optional
-    /// docker Cli instance.
+    /// docker Cli instance. "rpc-runner" and "rpc" return an error saying so.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         #[allow(unreachable_code)]
         Ok(match s {
             #[cfg(feature = "osmosis-test-tube")]
-            "osmosis-test-app" => Self::OsmosisTestApp(OsmosisTestApp::new()),
+            "osmosis-test-app" | "osmosis-test-tube" => Self::OsmosisTestApp(OsmosisTestApp::new()),
             #[cfg(feature = "coreum-test-tube")]
-            "coreum-test-app" => Self::CoreumTestApp(CoreumTestApp::new()),
+            "coreum-test-app" | "coreum-test-tube" => Self::CoreumTestApp(CoreumTestApp::new()),
             #[cfg(feature = "rpc-runner")]
-            "rpc-runner" => return Err("RpcRunner requires a config file".to_string()),
+            "rpc-runner" | "rpc" => return Err("RpcRunner requires a config file".to_string()),
             #[cfg(feature = "multi-test")]
             "multi-test" => Self::MultiTest(MultiTestRunner::new(DEFAULT_ADDRESS_PREFIX)),
-            _ => return Err(format!("Invalid TestRunner: {}", s)),
+            _ => {
+                return Err(format!(
+                    "Invalid TestRunner: {}. Valid values are: {}",
+                    s,
+                    Self::valid_names().join(", ")
+                ))
+            }
         })
     }
 }
@@ -391,6 +419,244 @@ where
             Self::MultiTest(runner) => runner.query_block_time_nanos(),
         }
     }
+
+    fn query_block_height(&self) -> Result<u64, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.query_block_height(),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.query_block_height(),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.query_block_height(),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.query_block_height(),
+        }
+    }
+
+    fn set_block_time(&self, unix_seconds: u64) -> Result<(), anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.set_block_time(unix_seconds),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.set_block_time(unix_seconds),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.set_block_time(unix_seconds),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.set_block_time(unix_seconds),
+        }
+    }
+
+    fn increase_blocks(&self, blocks: u64) -> Result<(), anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.increase_blocks(blocks),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.increase_blocks(blocks),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.increase_blocks(blocks),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.increase_blocks(blocks),
+        }
+    }
+
+    fn migrate_contract(&self, contract: &str, new_code_id: u64, msg: &[u8], signer: &SigningAccount) -> Result<CwItResponse, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.migrate_contract(contract, new_code_id, msg, signer),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.migrate_contract(contract, new_code_id, msg, signer),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.migrate_contract(contract, new_code_id, msg, signer),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.migrate_contract(contract, new_code_id, msg, signer),
+        }
+    }
+
+    fn query_code_hash(&self, code_id: u64) -> Result<Vec<u8>, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.query_code_hash(code_id),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.query_code_hash(code_id),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.query_code_hash(code_id),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.query_code_hash(code_id),
+        }
+    }
+
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.denom_creation_fee(),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.denom_creation_fee(),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.denom_creation_fee(),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.denom_creation_fee(),
+        }
+    }
+
+    fn is_contract(&self, address: &str) -> Result<bool, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.is_contract(address),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.is_contract(address),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.is_contract(address),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.is_contract(address),
+        }
+    }
+
+    fn account_info(&self, address: &str) -> Result<(u64, u64), anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.account_info(address),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.account_info(address),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.account_info(address),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.account_info(address),
+        }
+    }
+
+    fn execute_contract<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: &[cosmwasm_std::Coin],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.execute_contract(contract, msg, funds, signer),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.execute_contract(contract, msg, funds, signer),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.execute_contract(contract, msg, funds, signer),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.execute_contract(contract, msg, funds, signer),
+        }
+    }
+
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        funds: &[cosmwasm_std::Coin],
+        admin: Option<&str>,
+        label: &str,
+        signer: &SigningAccount,
+    ) -> Result<String, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.instantiate_contract(code_id, msg, funds, admin, label, signer),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.instantiate_contract(code_id, msg, funds, admin, label, signer),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.instantiate_contract(code_id, msg, funds, admin, label, signer),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.instantiate_contract(code_id, msg, funds, admin, label, signer),
+        }
+    }
+
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, contract: &str, query: &Q) -> Result<R, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => CwItRunner::query_wasm_smart(app, contract, query),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => CwItRunner::query_wasm_smart(app, contract, query),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => CwItRunner::query_wasm_smart(runner, contract, query),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => CwItRunner::query_wasm_smart(runner, contract, query),
+        }
+    }
+
+    fn query_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.query_denom_metadata(denom),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.query_denom_metadata(denom),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.query_denom_metadata(denom),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.query_denom_metadata(denom),
+        }
+    }
+
+    fn fund_account(&self, address: &str, coins: &[Coin]) -> Result<(), anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.fund_account(address, coins),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.fund_account(address, coins),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.fund_account(address, coins),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.fund_account(address, coins),
+        }
+    }
+
+    fn simulate(&self, msgs: Vec<cosmrs::Any>, signer: &SigningAccount) -> Result<GasInfo, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.simulate(msgs, signer),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.simulate(msgs, signer),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.simulate(msgs, signer),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.simulate(msgs, signer),
+        }
+    }
+
+    fn fee_denom(&self) -> Result<&str, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.fee_denom(),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.fee_denom(),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.fee_denom(),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.fee_denom(),
+        }
+    }
+
+    fn chain_id(&self) -> Result<String, anyhow::Error> {
+        match self {
+            Self::PhantomData(_) => unimplemented!(),
+            #[cfg(feature = "osmosis-test-tube")]
+            Self::OsmosisTestApp(app) => app.chain_id(),
+            #[cfg(feature = "coreum-test-tube")]
+            Self::CoreumTestApp(app) => app.chain_id(),
+            #[cfg(feature = "rpc-runner")]
+            Self::RpcRunner(runner) => runner.chain_id(),
+            #[cfg(feature = "multi-test")]
+            Self::MultiTest(runner) => runner.chain_id(),
+        }
+    }
 }
 impl<'a, S> CwItRunner<'a> for OwnedTestRunner<S>
 where
@@ -415,6 +681,84 @@ where
     fn query_block_time_nanos(&self) -> u64 {
         self.as_ref().query_block_time_nanos()
     }
+
+    fn query_block_height(&self) -> Result<u64, anyhow::Error> {
+        self.as_ref().query_block_height()
+    }
+
+    fn set_block_time(&self, unix_seconds: u64) -> Result<(), anyhow::Error> {
+        self.as_ref().set_block_time(unix_seconds)
+    }
+
+    fn increase_blocks(&self, blocks: u64) -> Result<(), anyhow::Error> {
+        self.as_ref().increase_blocks(blocks)
+    }
+
+    fn migrate_contract(&self, contract: &str, new_code_id: u64, msg: &[u8], signer: &SigningAccount) -> Result<CwItResponse, anyhow::Error> {
+        self.as_ref().migrate_contract(contract, new_code_id, msg, signer)
+    }
+
+    fn query_code_hash(&self, code_id: u64) -> Result<Vec<u8>, anyhow::Error> {
+        self.as_ref().query_code_hash(code_id)
+    }
+
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, anyhow::Error> {
+        self.as_ref().denom_creation_fee()
+    }
+
+    fn is_contract(&self, address: &str) -> Result<bool, anyhow::Error> {
+        self.as_ref().is_contract(address)
+    }
+
+    fn account_info(&self, address: &str) -> Result<(u64, u64), anyhow::Error> {
+        self.as_ref().account_info(address)
+    }
+
+    fn execute_contract<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: &[cosmwasm_std::Coin],
+        signer: &SigningAccount,
+    ) -> Result<CwItResponse, anyhow::Error> {
+        self.as_ref().execute_contract(contract, msg, funds, signer)
+    }
+
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        funds: &[cosmwasm_std::Coin],
+        admin: Option<&str>,
+        label: &str,
+        signer: &SigningAccount,
+    ) -> Result<String, anyhow::Error> {
+        self.as_ref().instantiate_contract(code_id, msg, funds, admin, label, signer)
+    }
+
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, contract: &str, query: &Q) -> Result<R, anyhow::Error> {
+        CwItRunner::query_wasm_smart(self.as_ref(), contract, query)
+    }
+
+    fn query_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, anyhow::Error> {
+        self.as_ref().query_denom_metadata(denom)
+    }
+
+    fn fund_account(&self, address: &str, coins: &[Coin]) -> Result<(), anyhow::Error> {
+        self.as_ref().fund_account(address, coins)
+    }
+
+    fn simulate(&self, msgs: Vec<cosmrs::Any>, signer: &SigningAccount) -> Result<GasInfo, anyhow::Error> {
+        self.as_ref().simulate(msgs, signer)
+    }
+
+    fn fee_denom(&self) -> Result<&str, anyhow::Error> {
+        self.as_ref().fee_denom()
+    }
+
+    fn chain_id(&self) -> Result<String, anyhow::Error> {
+        self.as_ref().chain_id()
+    }
 }
 
 impl<'a, S> TestRunner<'a, S>
@@ -484,4 +828,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[cfg(feature = "osmosis-test-tube")]
+    fn test_runner_from_str_osmosis_test_tube() {
+        let runner = OwnedTestRunner::from_str("osmosis-test-tube").unwrap();
+        assert_eq!(runner.to_string(), "osmosis-test-app");
+    }
+
+    #[test]
+    #[cfg(feature = "coreum-test-tube")]
+    fn test_runner_from_str_coreum_test_tube() {
+        let runner = OwnedTestRunner::from_str("coreum-test-tube").unwrap();
+        assert_eq!(runner.to_string(), "coreum-test-app");
+    }
+
+    #[test]
+    #[cfg(feature = "rpc-runner")]
+    fn test_runner_from_str_rpc() {
+        let err = OwnedTestRunner::from_str("rpc").err().unwrap();
+        assert_eq!(err, "RpcRunner requires a config file".to_string());
+    }
+
+    #[test]
+    fn test_runner_from_str_unknown() {
+        let err = OwnedTestRunner::from_str("unknown").err().unwrap();
+        assert_eq!(
+            err,
+            format!(
+                "Invalid TestRunner: unknown. Valid values are: {}",
+                OwnedTestRunner::valid_names().join(", ")
+            )
+        );
+        for name in OwnedTestRunner::valid_names() {
+            assert!(err.contains(name));
+        }
+    }
 }
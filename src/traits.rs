@@ -1,5 +1,8 @@
-use anyhow::Error;
-use cosmwasm_std::{coin, coins, Coin};
+use anyhow::{bail, Error};
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
+use cosmwasm_std::{coin, coins, Binary, Coin, Event};
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
+use serde::{de::DeserializeOwned, Serialize};
 use test_tube::Runner;
 use test_tube::SigningAccount;
 
@@ -52,6 +55,24 @@ pub fn initial_coins() -> Vec<cosmwasm_std::Coin> {
     ]
 }
 
+/// A runner agnostic response to a contract operation, such as a migration. Contains the events
+/// emitted during execution and the data returned by the contract, if any.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CwItResponse {
+    pub events: Vec<Event>,
+    pub data: Option<Binary>,
+}
+
+#[cfg(feature = "multi-test")]
+impl From<cw_multi_test::AppResponse> for CwItResponse {
+    fn from(res: cw_multi_test::AppResponse) -> Self {
+        Self {
+            events: res.events,
+            data: res.data,
+        }
+    }
+}
+
 pub trait CwItRunner<'a>: Runner<'a> {
     /// Store the code on the chain and return the code ID. Takes a ContractType to allow for
     /// both wasm artifacts and multi-test contracts.
@@ -81,6 +102,119 @@ pub trait CwItRunner<'a>: Runner<'a> {
     /// Increases the time of the blockchain by the given number of seconds.
     fn increase_time(&self, seconds: u64) -> Result<(), Error>;
 
+    /// Sets the time of the blockchain to the given unix timestamp in seconds. By default this
+    /// increases the time by the difference to the current block time, so it errors if
+    /// `unix_seconds` lies in the past.
+    fn set_block_time(&self, unix_seconds: u64) -> Result<(), Error> {
+        let current_seconds = self.query_block_time_nanos() / 1_000_000_000;
+        if unix_seconds < current_seconds {
+            bail!(
+                "Cannot set block time to {}, it is before the current block time {}",
+                unix_seconds,
+                current_seconds
+            );
+        }
+        self.increase_time(unix_seconds - current_seconds)
+    }
+
+    /// Advances the block height of the blockchain by the given number of blocks, without
+    /// changing the block time. Errors for runners that can't produce blocks on demand.
+    fn increase_blocks(&self, _blocks: u64) -> Result<(), Error> {
+        bail!("Increasing the block height is unsupported by this runner")
+    }
+
     /// Returns the current block time in nanoseconds.
     fn query_block_time_nanos(&self) -> u64;
+
+    /// Returns the current block height.
+    fn query_block_height(&self) -> Result<u64, Error> {
+        bail!("Querying the block height is unsupported by this runner")
+    }
+
+    /// Migrates the contract at `contract` to the code with ID `new_code_id`, passing the JSON
+    /// encoded `msg` to the migrate entry point. `signer` must be the admin of the contract.
+    fn migrate_contract(&self, _contract: &str, _new_code_id: u64, _msg: &[u8], _signer: &SigningAccount) -> Result<CwItResponse, Error> {
+        bail!("Migrating contracts is unsupported by this runner")
+    }
+
+    /// Returns the sha256 hash of the wasm code stored under `code_id`. This is the checksum
+    /// needed to predict `instantiate2` addresses.
+    fn query_code_hash(&self, _code_id: u64) -> Result<Vec<u8>, Error> {
+        bail!("Querying code hashes is unsupported by this runner")
+    }
+
+    /// Returns the fee charged by the token factory for creating a denom, or `None` if creating
+    /// denoms is free or there is no token factory.
+    fn denom_creation_fee(&self) -> Result<Option<Coin>, Error> {
+        bail!("Querying the denom creation fee is unsupported by this runner")
+    }
+
+    /// Returns true if `address` is the address of a contract, false for plain accounts and
+    /// invalid addresses.
+    fn is_contract(&self, _address: &str) -> Result<bool, Error> {
+        bail!("Checking for contracts is unsupported by this runner")
+    }
+
+    /// Returns the account number and sequence of the account at `address`, as needed to sign
+    /// transactions for it.
+    fn account_info(&self, _address: &str) -> Result<(u64, u64), Error> {
+        bail!("Querying account info is unsupported by this runner")
+    }
+
+    /// Executes the JSON encoded `msg` on `contract` with the given `funds`, returning the emitted
+    /// events and the data set by the contract.
+    fn execute_contract<M: Serialize>(
+        &self,
+        _contract: &str,
+        _msg: &M,
+        _funds: &[Coin],
+        _signer: &SigningAccount,
+    ) -> Result<CwItResponse, Error> {
+        bail!("Executing contracts is unsupported by this runner")
+    }
+
+    /// Instantiates the code with ID `code_id` with the JSON encoded `msg` and the given `funds`,
+    /// returning the address of the new contract. The contract's admin is set to `admin`, if any.
+    fn instantiate_contract<M: Serialize>(
+        &self,
+        _code_id: u64,
+        _msg: &M,
+        _funds: &[Coin],
+        _admin: Option<&str>,
+        _label: &str,
+        _signer: &SigningAccount,
+    ) -> Result<String, Error> {
+        bail!("Instantiating contracts is unsupported by this runner")
+    }
+
+    /// Queries `contract` with the JSON encoded `query` and deserializes the response.
+    fn query_wasm_smart<Q: Serialize, R: DeserializeOwned>(&self, _contract: &str, _query: &Q) -> Result<R, Error> {
+        bail!("Querying contracts is unsupported by this runner")
+    }
+
+    /// Returns the bank metadata of `denom`, or `None` if no metadata is set for it.
+    fn query_denom_metadata(&self, _denom: &str) -> Result<Option<Metadata>, Error> {
+        bail!("Querying denom metadata is unsupported by this runner")
+    }
+
+    /// Adds `coins` to the balance of the existing account `address`.
+    fn fund_account(&self, _address: &str, _coins: &[Coin]) -> Result<(), Error> {
+        bail!("Funding accounts is unsupported by this runner")
+    }
+
+    /// Simulates executing `msgs` signed by `signer` without changing any state, and returns the
+    /// gas the execution uses. Errors if the execution would fail.
+    fn simulate(&self, _msgs: Vec<cosmrs::Any>, _signer: &SigningAccount) -> Result<GasInfo, Error> {
+        bail!("Simulating transactions is unsupported by this runner")
+    }
+
+    /// Returns the denom transaction fees are paid in.
+    fn fee_denom(&self) -> Result<&str, Error> {
+        bail!("Querying the fee denom is unsupported by this runner")
+    }
+
+    /// Returns the chain id of the chain the runner executes on.
+    fn chain_id(&self) -> Result<String, Error> {
+        bail!("Querying the chain id is unsupported by this runner")
+    }
 }